@@ -3,11 +3,8 @@
 // They act as trait bounds in the generic type programming.
 //
 
-use crate::{Endian, include_doc};
-use core::{
-    mem::{self, MaybeUninit},
-    ptr,
-};
+use crate::{Endian, bulk_swap, include_doc};
+use core::{mem, ptr, slice};
 
 #[cfg(doc)]
 use crate::{BE, EncastMem, LE};
@@ -44,11 +41,42 @@ impl<T: Cast, const N: usize> Cast for [T; N] {}
 //
 #[doc = include_doc!("bound_flip.md")]
 pub trait Flip: Sized {
+    ///
+    /// Is the number of bytes in each unit whose byte order is
+    /// reversed when the endianness of a value of this type is
+    /// flipped, or `0` if the bytes of the value cannot be reversed
+    /// in units of the same size.
+    ///
+    /// If it is not `0`, the methods of this crate reverse the
+    /// endiannesses of consecutive values of this type in bulk.  It
+    /// is set only for the primitive numeric types and the arrays of
+    /// them.  It is used only if this type also implements trait
+    /// [`Cast`].  Therefore, a wrong value results in wrong bytes but
+    /// never in undefined behavior.
+    ///
+    #[doc(hidden)]
+    const BULK_SWAP_WIDTH: usize = 0;
+
     ///
     /// Returns the value of `self` with its endianness reversed.
     ///
     fn flip_val_swapped(&self) -> Self;
 
+    ///
+    /// Reverses the endiannesses of the values in `slice`.
+    ///
+    /// By default, the values are flipped one by one.  The primitive
+    /// numeric types and the arrays of them reverse the bytes of the
+    /// values in bulk.
+    ///
+    #[doc(hidden)]
+    #[inline]
+    fn flip_slice_swapped(slice: &mut [Self]) {
+        for elem in slice {
+            elem.flip_var_swapped();
+        }
+    }
+
     ///
     /// Reads the value of `self`, flips the endianness of the value
     /// if `endian` is not equivalent to the endianness of the target
@@ -56,7 +84,7 @@ pub trait Flip: Sized {
     ///
     #[inline]
     fn flip_val(&self, endian: Endian) -> Self {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             unsafe {
                 // SAFETY: The following function call to `ptr::read` is safe
                 // because those types that implement trait Flip can be
@@ -64,6 +92,8 @@ pub trait Flip: Sized {
                 // trait Flip.
                 ptr::read(self)
             }
+        } else if endian.is_mixed() {
+            self.flip_val_mixed(endian)
         } else {
             self.flip_val_swapped()
         }
@@ -83,20 +113,54 @@ pub trait Flip: Sized {
     ///
     #[inline]
     fn flip_var(&mut self, endian: Endian) {
-        if endian.need_swap() {
+        if endian.is_mixed() {
+            *self = self.flip_val_mixed(endian);
+        } else if endian.need_swap() {
             self.flip_var_swapped();
         }
     }
+
+    ///
+    /// Returns the value of `self` converted between native-endian and
+    /// mixed endianness `endian` ([`Endian::Pdp`] or
+    /// [`Endian::WordSwapped`]).
+    ///
+    /// The primitive numeric types and the arrays of them rearrange
+    /// their words, and the implementation by attribute
+    /// `#[derive(Flip)]` converts each field.  By default, i.e., if a
+    /// manual implementation does not override this method, the value
+    /// is converted as in little-endian.  A manual implementation
+    /// supporting the mixed endiannesses should convert each field by
+    /// calling method [`Flip::flip_val`] of the field.
+    ///
+    #[inline]
+    fn flip_val_mixed(&self, _endian: Endian) -> Self {
+        self.flip_val(Endian::Little)
+    }
 }
 
 
 macro_rules! impl_flip_for_int {
     ( $( $ty:ty ),* ) => { $(
         impl Flip for $ty {
+            const BULK_SWAP_WIDTH: usize = mem::size_of::<$ty>();
+
             #[inline]
             fn flip_val_swapped(&self) -> Self {
                 self.swap_bytes()
             }
+
+            #[inline]
+            fn flip_slice_swapped(slice: &mut [Self]) {
+                bulk_swap::swap_slice(slice);
+            }
+
+            #[inline]
+            fn flip_val_mixed(&self, endian: Endian) -> Self {
+                let mut value = *self;
+                bulk_swap::flip_slice(slice::from_mut(&mut value), endian);
+                value
+            }
         }
     )* }
 }
@@ -104,10 +168,24 @@ macro_rules! impl_flip_for_int {
 macro_rules! impl_flip_for_float {
     ( $( $ty:ty ),* ) => { $(
         impl Flip for $ty {
+            const BULK_SWAP_WIDTH: usize = mem::size_of::<$ty>();
+
             #[inline]
             fn flip_val_swapped(&self) -> Self {
                 <$ty>::from_bits(self.to_bits().swap_bytes())
             }
+
+            #[inline]
+            fn flip_slice_swapped(slice: &mut [Self]) {
+                bulk_swap::swap_slice(slice);
+            }
+
+            #[inline]
+            fn flip_val_mixed(&self, endian: Endian) -> Self {
+                let mut value = *self;
+                bulk_swap::flip_slice(slice::from_mut(&mut value), endian);
+                value
+            }
         }
     )* }
 }
@@ -118,35 +196,43 @@ impl_flip_for_float!(f32, f64);
 
 
 impl<T: Flip, const N: usize> Flip for [T; N] {
+    const BULK_SWAP_WIDTH: usize = T::BULK_SWAP_WIDTH;
+
     #[inline]
     fn flip_val_swapped(&self) -> Self {
-        let mut array = MaybeUninit::<[T; N]>::uninit();
-
-        if mem::size_of_val(&array) > 0 {
-            unsafe {
-                // SAFETY: The following function call to `mem::transmute`
-                // is safe because the memory layouts of [T; N] and
-                // MaybeUninit::<[T; N]> are exactly the same.
-                let array2: &mut [T; N] = mem::transmute(&mut array);
-
-                // NOTE: All elements in `array2` (=`array`) is initialized.
-                for i in 0 .. N {
-                    array2[i] = self[i].flip_val_swapped();
-                }
-            }
-        }
-
         unsafe {
-            // SAFETY: The following method call to `MaybeUninit::assume_init`
-            // is safe because all elements in `array` have been initialized.
-            array.assume_init()
+            // SAFETY: The following function call to `ptr::read` is safe
+            // because those types that implement trait Flip can be
+            // duplicated simply by copying bits by the definition of trait
+            // Flip.
+            let mut array = ptr::read(self);
+            T::flip_slice_swapped(&mut array);
+            array
         }
     }
 
     #[inline]
     fn flip_var_swapped(&mut self) {
-        for elem in self {
-            elem.flip_var_swapped();
+        T::flip_slice_swapped(self);
+    }
+
+    #[inline]
+    fn flip_slice_swapped(slice: &mut [Self]) {
+        T::flip_slice_swapped(slice.as_flattened_mut());
+    }
+
+    #[inline]
+    fn flip_val_mixed(&self, endian: Endian) -> Self {
+        unsafe {
+            // SAFETY: The following function call to `ptr::read` is safe
+            // because those types that implement trait Flip can be
+            // duplicated simply by copying bits by the definition of trait
+            // Flip.
+            let mut array = ptr::read(self);
+            for elem in &mut array {
+                *elem = elem.flip_val_mixed(endian);
+            }
+            array
         }
     }
 }
@@ -0,0 +1,407 @@
+//
+// This file defines internal functions to reverse the endiannesses of
+// consecutive values of primitive numeric types in bulk.
+//
+
+use crate::{Cast, DecastMem, Endian, Flip, experimental::AsifBytes};
+use core::{mem::{self, MaybeUninit}, slice};
+
+
+///
+/// Reverses the endiannesses of the values in `slice`.
+///
+/// If `T::BULK_SWAP_WIDTH` is not zero, the bytes of the values are
+/// reversed in bulk.  Otherwise, the values are flipped one by one.
+/// Type `T` must implement trait Cast so that its values are plain
+/// bytes whatever `T::BULK_SWAP_WIDTH` is.  It implements method
+/// `Flip::flip_slice_swapped` of the primitive numeric types.
+///
+#[inline]
+pub(crate) fn swap_slice<T: Cast + Flip>(slice: &mut [T]) {
+    if is_bulk_swappable::<T>() {
+        unsafe {
+            // SAFETY: The following function call to
+            // `slice::from_raw_parts_mut` is safe because the values of
+            // those types that implement trait Cast are plain bytes, and
+            // any permutation of their bytes is a valid value of the same
+            // type by the definition of trait Cast.
+            let bytes = slice::from_raw_parts_mut(
+                slice.as_mut_ptr() as *mut u8,
+                mem::size_of_val(slice),
+            );
+            swap_bytes(bytes, T::BULK_SWAP_WIDTH);
+        }
+    } else {
+        for elem in slice {
+            elem.flip_var_swapped();
+        }
+    }
+}
+
+///
+/// Flips the endiannesses of the values in `slice` if `endian` is not
+/// equivalent to the endianness of the target system.
+///
+/// The values are reversed by method `Flip::flip_slice_swapped`
+/// unless `endian` is a mixed endianness.
+///
+#[inline]
+pub(crate) fn flip_slice<T: Cast + Flip>(slice: &mut [T], endian: Endian) {
+    if !endian.need_flip() {
+        return;
+    }
+
+    let word = endian.word_size();
+    if word == 0 {
+        T::flip_slice_swapped(slice);
+    } else if is_bulk_swappable::<T>() {
+        unsafe {
+            // SAFETY: The following function call to
+            // `slice::from_raw_parts_mut` is safe because the values of
+            // those types that implement trait Cast are plain bytes, and
+            // any permutation of their bytes is a valid value of the same
+            // type by the definition of trait Cast.
+            let bytes = slice::from_raw_parts_mut(
+                slice.as_mut_ptr() as *mut u8,
+                mem::size_of_val(slice),
+            );
+            mix_bytes(bytes, T::BULK_SWAP_WIDTH, word);
+        }
+    } else {
+        for elem in slice {
+            *elem = elem.flip_val_mixed(endian);
+        }
+    }
+}
+
+///
+/// Flips the endiannesses of the byte representations of the values
+/// of type `T` in `bytes` for `endian`, which must not be equivalent
+/// to the endianness of the target system.  The bytes of the values
+/// of type `T` must be able to be reversed in bulk.
+///
+#[inline]
+pub(crate) fn flip_bytes<T: Cast + Flip>(bytes: &mut [u8], endian: Endian) {
+    let word = endian.word_size();
+    if word == 0 {
+        swap_bytes(bytes, T::BULK_SWAP_WIDTH);
+    } else {
+        mix_bytes(bytes, T::BULK_SWAP_WIDTH, word);
+    }
+}
+
+///
+/// Is the size of the buffer on the stack to which the byte
+/// representations of values are saved and flipped in bulk before
+/// they are written.
+///
+pub(crate) const BUF_SIZE: usize = 256;
+
+///
+/// Decasts the values in `slice` as byte representations in `endian`
+/// chunk by chunk, then calls closure `write` with each chunk of the
+/// resulting bytes.  If `write` returns an error, it is returned
+/// immediately.
+///
+pub(crate) fn decast_chunks<T: Cast + Flip, E>(
+    slice: &[T],
+    endian: Endian,
+    mut write: impl FnMut(&[u8]) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut chunks = FlipChunks::new(slice, endian);
+    while let Some(bytes) = chunks.next_bytes() {
+        write(bytes)?;
+    }
+    Ok(())
+}
+
+///
+/// Decasts the values in a slice as byte representations in an
+/// endianness chunk by chunk.  It is used in place of function
+/// `decast_chunks` where the bytes are written asynchronously.
+///
+pub(crate) struct FlipChunks<'a, T> {
+    slice: &'a [T],
+    endian: Endian,
+    buf: [u8; BUF_SIZE],
+    value: MaybeUninit<T>,
+}
+
+impl<'a, T: Cast + Flip> FlipChunks<'a, T> {
+    #[inline]
+    pub(crate) fn new(slice: &'a [T], endian: Endian) -> Self {
+        Self {
+            slice,
+            endian,
+            buf: [0_u8; BUF_SIZE],
+            value: MaybeUninit::uninit(),
+        }
+    }
+
+    ///
+    /// Returns the byte representations of the next chunk of the
+    /// values, or [`None`] if no byte is left.
+    ///
+    pub(crate) fn next_bytes(&mut self) -> Option<&[u8]> {
+        let elem_size = mem::size_of::<T>();
+        if self.slice.is_empty() || elem_size == 0 {
+            return None;
+        }
+
+        if elem_size <= BUF_SIZE {
+            // Save the byte representations of as many values as fit
+            // in `buf`, then flip their endiannesses in bulk.
+            let nelem = (BUF_SIZE / elem_size).min(self.slice.len());
+            let (chunk, rest) = self.slice.split_at(nelem);
+            self.slice = rest;
+
+            let bytes = &mut self.buf[.. mem::size_of_val(chunk)];
+            bytes.decastsf::<T>(chunk, self.endian);
+            Some(bytes)
+        } else {
+            // A value larger than `buf` is flipped by itself.
+            let (elem, rest) = self.slice.split_first()?;
+            self.slice = rest;
+
+            let value = self.value.write(elem.flip_val(self.endian));
+            unsafe {
+                // SAFETY: The following method call to
+                // `AsifBytes::asif_bytes_ref` is safe because those types
+                // that implement trait Cast can be duplicated simply by
+                // copying bits by the definition of trait Cast.
+                Some(value.asif_bytes_ref())
+            }
+        }
+    }
+}
+
+///
+/// Returns `true` if the bytes of the values of type `T` can be
+/// reversed in bulk.
+///
+#[inline]
+pub(crate) const fn is_bulk_swappable<T: Cast + Flip>() -> bool {
+    T::BULK_SWAP_WIDTH != 0
+        && mem::size_of::<T>() != 0
+        && mem::size_of::<T>() % T::BULK_SWAP_WIDTH == 0
+}
+
+///
+/// Reverses the byte order of every `width`-byte unit in `bytes`.
+///
+/// The trailing bytes shorter than `width`, if any, are unchanged.
+///
+#[inline]
+pub(crate) fn swap_bytes(bytes: &mut [u8], width: usize) {
+    if width > 1 {
+        let done = swap_bytes_simd(bytes, width);
+        swap_bytes_scalar(&mut bytes[done ..], width);
+    }
+}
+
+
+///
+/// Converts every `width`-byte unit in `bytes` between native-endian
+/// and the mixed endianness whose `word`-byte words are stored from
+/// the most significant word and in little-endian.
+///
+/// If `width` is not larger than `word` or not a multiple of it, each
+/// unit is converted between native-endian and little-endian.
+///
+#[inline]
+pub(crate) fn mix_bytes(bytes: &mut [u8], width: usize, word: usize) {
+    if width <= word || width % word != 0 {
+        if cfg!(target_endian = "big") {
+            swap_bytes(bytes, width);
+        }
+    } else if cfg!(target_endian = "little") {
+        // Reverse the order of the words in each unit.
+        swap_bytes(bytes, width);
+        swap_bytes(bytes, word);
+    } else {
+        // Reverse the order of the bytes in each word.
+        swap_bytes(bytes, word);
+    }
+}
+
+// The number of bytes processed at once by the SIMD instructions.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64",
+          all(target_arch = "aarch64", target_feature = "neon")))]
+const SIMD_SIZE: usize = 16;
+
+///
+/// Reverses the byte order of every `width`-byte unit in the head of
+/// `bytes` using SSSE3 instructions if available, then returns the
+/// number of the processed bytes.
+///
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+fn swap_bytes_simd(bytes: &mut [u8], width: usize) -> usize {
+    if matches!(width, 2 | 4 | 8 | 16) && has_ssse3() {
+        unsafe {
+            // SAFETY: The following function call to `x86::swap_bytes` is
+            // safe because SSSE3 is available on the target system.
+            x86::swap_bytes(bytes, width)
+        }
+    } else {
+        0
+    }
+}
+
+///
+/// Reverses the byte order of every `width`-byte unit in the head of
+/// `bytes` using NEON instructions, then returns the number of the
+/// processed bytes.
+///
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn swap_bytes_simd(bytes: &mut [u8], width: usize) -> usize {
+    if matches!(width, 2 | 4 | 8 | 16) {
+        unsafe {
+            // SAFETY: The following function call to `neon::swap_bytes` is
+            // safe because NEON is enabled on the target system.
+            neon::swap_bytes(bytes, width)
+        }
+    } else {
+        0
+    }
+}
+
+///
+/// Returns zero because no SIMD instructions are used on the target
+/// system.
+///
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64",
+              all(target_arch = "aarch64", target_feature = "neon"))))]
+#[inline]
+fn swap_bytes_simd(_bytes: &mut [u8], _width: usize) -> usize {
+    0
+}
+
+///
+/// Returns `true` if SSSE3 is available on the target system.
+///
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+fn has_ssse3() -> bool {
+    #[cfg(target_feature = "ssse3")]
+    {
+        true
+    }
+    #[cfg(all(not(target_feature = "ssse3"), feature = "std"))]
+    {
+        std::is_x86_feature_detected!("ssse3")
+    }
+    #[cfg(all(not(target_feature = "ssse3"), not(feature = "std")))]
+    {
+        false
+    }
+}
+
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use super::SIMD_SIZE;
+
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    ///
+    /// Reverses the byte order of every `width`-byte unit in the head
+    /// of `bytes` by shuffling 16 bytes at once, then returns the
+    /// number of the processed bytes.
+    ///
+    /// `width` must be 2, 4, 8 or 16.
+    ///
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn swap_bytes(bytes: &mut [u8], width: usize) -> usize {
+        unsafe {
+            let mask = match width {
+                2 => _mm_setr_epi8(1, 0, 3, 2, 5, 4, 7, 6,
+                                   9, 8, 11, 10, 13, 12, 15, 14),
+                4 => _mm_setr_epi8(3, 2, 1, 0, 7, 6, 5, 4,
+                                   11, 10, 9, 8, 15, 14, 13, 12),
+                8 => _mm_setr_epi8(7, 6, 5, 4, 3, 2, 1, 0,
+                                   15, 14, 13, 12, 11, 10, 9, 8),
+                _ => _mm_setr_epi8(15, 14, 13, 12, 11, 10, 9, 8,
+                                   7, 6, 5, 4, 3, 2, 1, 0),
+            };
+
+            for chunk in bytes.chunks_exact_mut(SIMD_SIZE) {
+                let ptr = chunk.as_mut_ptr() as *mut __m128i;
+                let value = _mm_loadu_si128(ptr);
+                _mm_storeu_si128(ptr, _mm_shuffle_epi8(value, mask));
+            }
+
+            bytes.len() / SIMD_SIZE * SIMD_SIZE
+        }
+    }
+}
+
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use super::SIMD_SIZE;
+    use core::arch::aarch64::*;
+
+    ///
+    /// Reverses the byte order of every `width`-byte unit in the head
+    /// of `bytes` by reversing 16 bytes at once, then returns the
+    /// number of the processed bytes.
+    ///
+    /// `width` must be 2, 4, 8 or 16.
+    ///
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn swap_bytes(bytes: &mut [u8], width: usize) -> usize {
+        unsafe {
+            for chunk in bytes.chunks_exact_mut(SIMD_SIZE) {
+                let ptr = chunk.as_mut_ptr();
+                let value = vld1q_u8(ptr);
+                let value = match width {
+                    2 => vrev16q_u8(value),
+                    4 => vrev32q_u8(value),
+                    8 => vrev64q_u8(value),
+                    _ => {
+                        let value = vrev64q_u8(value);
+                        vextq_u8::<8>(value, value)
+                    },
+                };
+                vst1q_u8(ptr, value);
+            }
+
+            bytes.len() / SIMD_SIZE * SIMD_SIZE
+        }
+    }
+}
+
+
+///
+/// Reverses the byte order of every `width`-byte unit in `bytes`
+/// without SIMD instructions.
+///
+fn swap_bytes_scalar(bytes: &mut [u8], width: usize) {
+    macro_rules! swap_each {
+        ( $ty:ty ) => {
+            for chunk in bytes.chunks_exact_mut(mem::size_of::<$ty>()) {
+                let mut array = [0_u8; mem::size_of::<$ty>()];
+                array.copy_from_slice(chunk);
+                let value = <$ty>::from_ne_bytes(array).swap_bytes();
+                chunk.copy_from_slice(&value.to_ne_bytes());
+            }
+        }
+    }
+
+    match width {
+        2 => swap_each!(u16),
+        4 => swap_each!(u32),
+        8 => swap_each!(u64),
+        16 => swap_each!(u128),
+        _ => {
+            for chunk in bytes.chunks_exact_mut(width) {
+                chunk.reverse();
+            }
+        },
+    }
+}
@@ -108,7 +108,7 @@ pub mod summary3 {
 }
 
 pub mod summary4 {
-    // 4. Crate Features: `alloc` and `std`
+    // 4. Crate Features: `alloc`, `std` and Optional Features
     #![doc = include_doc!("summary4_features.md")]
     use crate::*;
 }
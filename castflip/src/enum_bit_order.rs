@@ -0,0 +1,96 @@
+//
+// This file defines enum `BitOrder` and trait `BitInt`
+//
+
+#[cfg(doc)]
+use crate::{BitReader, BitWriter, varint};
+
+
+///
+/// Specifies the order in which the bits in each byte are read or
+/// written by struct [`BitReader`] and struct [`BitWriter`].
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitOrder {
+    ///
+    /// The most significant bit of each byte comes first.  A value
+    /// spanning multiple bytes is in big-endian.  It is used by most
+    /// network protocols and by video codecs.
+    ///
+    Msb,
+
+    ///
+    /// The least significant bit of each byte comes first.  A value
+    /// spanning multiple bytes is in little-endian.  It is used by
+    /// DEFLATE and by many serial protocols.
+    ///
+    Lsb,
+}
+
+
+///
+/// Is implemented for the primitive integer types whose values can be
+/// read and written in bits by struct [`BitReader`] and struct
+/// [`BitWriter`], and in variable-length integer encodings by the
+/// traits in module [`varint`].
+///
+pub trait BitInt: Copy {
+    ///
+    /// Is the number of bits of this type.
+    ///
+    const BITS: u32;
+
+    ///
+    /// Is `true` if this type is a signed integer type.
+    ///
+    #[doc(hidden)]
+    const SIGNED: bool;
+
+    ///
+    /// Is the smallest value of this type.
+    ///
+    #[doc(hidden)]
+    const MIN: Self;
+
+    ///
+    /// Is the largest value of this type.
+    ///
+    #[doc(hidden)]
+    const MAX: Self;
+
+    ///
+    /// Returns the value whose bits are the lowest bits of `bits`.
+    ///
+    #[doc(hidden)]
+    fn from_u128(bits: u128) -> Self;
+
+    ///
+    /// Returns the bits of `self` zero-extended to `u128`.
+    ///
+    #[doc(hidden)]
+    fn to_u128(self) -> u128;
+}
+
+macro_rules! impl_bit_int {
+    ( $signed:literal; $( $ty:ty : $uty:ty ),* ) => { $(
+        impl BitInt for $ty {
+            const BITS: u32 = <$ty>::BITS;
+            const SIGNED: bool = $signed;
+            const MIN: Self = <$ty>::MIN;
+            const MAX: Self = <$ty>::MAX;
+
+            #[inline]
+            fn from_u128(bits: u128) -> Self {
+                bits as $ty
+            }
+
+            #[inline]
+            fn to_u128(self) -> u128 {
+                self as $uty as u128
+            }
+        }
+    )* }
+}
+
+impl_bit_int!(false; u8: u8, u16: u16, u32: u32, u64: u64, u128: u128, usize: usize);
+impl_bit_int!(true; i8: u8, i16: u16, i32: u32, i64: u64, i128: u128, isize: usize);
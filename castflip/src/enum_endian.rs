@@ -4,12 +4,16 @@
 
 use crate::include_doc;
 
+#[cfg(doc)]
+use crate::Flip;
+
 
 //
 // Enum `Endian`
 //
 #[doc = include_doc!("enum_endian.md")]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Endian {
     ///
     /// Native endianness, which is the endianness of the target system.
@@ -41,12 +45,35 @@ pub enum Endian {
     /// - Antonym: Little endianness ([`Endian::Little`])
     ///
     Big,
+
+    ///
+    /// PDP-11 endianness, which stores the 16-bit words of a value from
+    /// the most significant word and the bytes in each word in
+    /// little-endian.  For example, `0x0A0B0C0D_u32` is stored as
+    /// `0B 0A 0D 0C`.
+    /// - Category: Mixed endianness
+    ///
+    Pdp,
+
+    ///
+    /// Word-swapped endianness, which stores the 32-bit words of a value
+    /// from the most significant word and the bytes in each word in
+    /// little-endian.  For example, the `f64` values of the FPA
+    /// floating-point architecture of ARM are stored in it.
+    /// - Category: Mixed endianness
+    ///
+    WordSwapped,
 }
 
 
 impl Endian {
     ///
-    /// Returns the relative endianness of `self`.
+    /// Returns the relative endianness of `self`, i.e.,
+    /// [`Endian::Native`] or [`Endian::Swapped`], if `self` is not a
+    /// mixed endianness.  Because a mixed endianness has no relative
+    /// counterpart, it is returned as it is.  Method
+    /// [`Endian::is_mixed`] tells whether `self` is a mixed
+    /// endianness.
     ///
     /// ```rust
     /// use castflip::Endian;
@@ -65,15 +92,20 @@ impl Endian {
     /// # } else {
     /// #   panic!();
     /// }
+    ///
+    /// // Mixed endianness
+    /// assert_eq!(Endian::Pdp.relative(), Endian::Pdp);
     /// ```
     ///
-    pub fn relative(&self) -> Self {
+    pub const fn relative(&self) -> Self {
         if cfg!(target_endian = "little") {
             match self {
                 Self::Native  => Self::Native,
                 Self::Swapped => Self::Swapped,
                 Self::Little  => Self::Native,
                 Self::Big     => Self::Swapped,
+                Self::Pdp     => Self::Pdp,
+                Self::WordSwapped => Self::WordSwapped,
             }
         } else if cfg!(target_endian = "big") {
             match self {
@@ -81,6 +113,8 @@ impl Endian {
                 Self::Swapped => Self::Swapped,
                 Self::Little  => Self::Swapped,
                 Self::Big     => Self::Native,
+                Self::Pdp     => Self::Pdp,
+                Self::WordSwapped => Self::WordSwapped,
             }
         } else {
             panic!("The endian of the target system is not supported.");
@@ -88,7 +122,11 @@ impl Endian {
     }
 
     ///
-    /// Returns the absolute endianness of `self`.
+    /// Returns the absolute endianness of `self`, i.e.,
+    /// [`Endian::Little`] or [`Endian::Big`], if `self` is not a mixed
+    /// endianness.  Because a mixed endianness is already absolute, it
+    /// is returned as it is.  Method [`Endian::is_mixed`] tells whether
+    /// `self` is a mixed endianness.
     ///
     /// ```rust
     /// use castflip::Endian;
@@ -107,15 +145,20 @@ impl Endian {
     /// // Absolute endianness
     /// assert_eq!(Endian::Little.absolute(), Endian::Little);
     /// assert_eq!(Endian::Big.absolute(), Endian::Big);
+    ///
+    /// // Mixed endianness
+    /// assert_eq!(Endian::Pdp.absolute(), Endian::Pdp);
     /// ```
     ///
-    pub fn absolute(&self) -> Self {
+    pub const fn absolute(&self) -> Self {
         if cfg!(target_endian = "little") {
             match self {
                 Self::Native  => Self::Little,
                 Self::Swapped => Self::Big,
                 Self::Little  => Self::Little,
                 Self::Big     => Self::Big,
+                Self::Pdp     => Self::Pdp,
+                Self::WordSwapped => Self::WordSwapped,
             }
         } else if cfg!(target_endian = "big") {
             match self {
@@ -123,6 +166,8 @@ impl Endian {
                 Self::Swapped => Self::Little,
                 Self::Little  => Self::Little,
                 Self::Big     => Self::Big,
+                Self::Pdp     => Self::Pdp,
+                Self::WordSwapped => Self::WordSwapped,
             }
         } else {
             panic!("The endian of the target system is not supported.");
@@ -130,8 +175,11 @@ impl Endian {
     }
 
     ///
-    /// Returns `true` if `self` is not equivalent to the endianness
-    /// of the target system.
+    /// Returns `true` if the bytes of a value in `self` are in the
+    /// reverse order of the endianness of the target system.  It is
+    /// always `false` for a mixed endianness, whose bytes are not
+    /// reversed as a whole.  Method [`Endian::need_flip`] tells
+    /// whether a value needs to be flipped in any way.
     ///
     /// ```rust
     /// use castflip::Endian;
@@ -150,24 +198,61 @@ impl Endian {
     /// # } else {
     /// #   panic!();
     /// }
+    ///
+    /// // Mixed endian
+    /// assert_eq!(Endian::Pdp.need_swap(), false);
+    /// assert_eq!(Endian::WordSwapped.need_swap(), false);
     /// ```
     ///
     #[inline]
-    pub fn need_swap(self) -> bool {
-        #[allow(clippy::collapsible_else_if)]
-        if self == Self::Swapped {
-            true
+    pub const fn need_swap(self) -> bool {
+        if cfg!(target_endian = "little") {
+            matches!(self, Self::Swapped | Self::Big)
+        } else if cfg!(target_endian = "big") {
+            matches!(self, Self::Swapped | Self::Little)
         } else {
-            if cfg!(target_endian = "little") {
-                self == Self::Big
-            } else if cfg!(target_endian = "big") {
-                self == Self::Little
-            } else {
-                panic!("The endian of the target system is not supported.");
-            }
+            panic!("The endian of the target system is not supported.");
         }
     }
 
+    ///
+    /// Returns `true` if `self` is not equivalent to the endianness
+    /// of the target system, i.e., if a value in `self` needs to be
+    /// flipped.  It is `true` if method [`Endian::need_swap`] or
+    /// method [`Endian::is_mixed`] returns `true`.
+    ///
+    /// ```rust
+    /// use castflip::{Endian, NE, SE};
+    ///
+    /// assert_eq!(NE.need_flip(), false);
+    /// assert_eq!(SE.need_flip(), true);
+    /// assert_eq!(Endian::Pdp.need_flip(), true);
+    /// assert_eq!(Endian::WordSwapped.need_flip(), true);
+    /// ```
+    ///
+    #[inline]
+    pub const fn need_flip(self) -> bool {
+        self.need_swap() || self.is_mixed()
+    }
+
+    ///
+    /// Returns `true` if `self` is a mixed endianness, i.e.,
+    /// [`Endian::Pdp`] or [`Endian::WordSwapped`].
+    ///
+    /// ```rust
+    /// use castflip::Endian;
+    ///
+    /// assert_eq!(Endian::Pdp.is_mixed(), true);
+    /// assert_eq!(Endian::WordSwapped.is_mixed(), true);
+    /// assert_eq!(Endian::Native.is_mixed(), false);
+    /// assert_eq!(Endian::Big.is_mixed(), false);
+    /// ```
+    ///
+    #[inline]
+    pub const fn is_mixed(self) -> bool {
+        self.word_size() != 0
+    }
+
     ///
     /// Returns the capitalized name of `self`.
     ///
@@ -178,14 +263,42 @@ impl Endian {
     /// assert_eq!(Endian::Swapped.name(), "Swapped");
     /// assert_eq!(Endian::Little.name(), "Little");
     /// assert_eq!(Endian::Big.name(), "Big");
+    /// assert_eq!(Endian::Pdp.name(), "Pdp");
+    /// assert_eq!(Endian::WordSwapped.name(), "WordSwapped");
     /// ```
     ///
-    pub fn name(self) -> &'static str {
+    pub const fn name(self) -> &'static str {
         match self {
             Self::Native  => "Native",
             Self::Swapped => "Swapped",
             Self::Little  => "Little",
             Self::Big     => "Big",
+            Self::Pdp     => "Pdp",
+            Self::WordSwapped => "WordSwapped",
+        }
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are equivalent on the
+    /// target system.
+    ///
+    #[cfg(feature = "std")]
+    #[inline]
+    pub(crate) const fn is_equivalent(self, other: Self) -> bool {
+        self.need_swap() == other.need_swap()
+            && self.word_size() == other.word_size()
+    }
+
+    ///
+    /// Returns the size of the words of a mixed endianness in bytes,
+    /// or zero if `self` is not a mixed endianness.
+    ///
+    #[inline]
+    pub(crate) const fn word_size(self) -> usize {
+        match self {
+            Self::Pdp         => 2,
+            Self::WordSwapped => 4,
+            _                 => 0,
         }
     }
 }
@@ -159,7 +159,7 @@ where
         unsafe {
             slice::from_raw_parts::<u8>(
                 self as *const [T] as *const u8,
-                mem::size_of::<T>() * self.len(),
+                mem::size_of_val(self),
             )
         }
     }
@@ -168,7 +168,7 @@ where
         unsafe {
             slice::from_raw_parts_mut::<u8>(
                 self as *mut [T] as *mut u8,
-                mem::size_of::<T>() * self.len(),
+                mem::size_of_val(self),
             )
         }
     }
@@ -113,7 +113,7 @@ where
     where
         U: Cast,
     {
-        if mem::size_of::<T>() * self.len() == mem::size_of::<U>() {
+        if mem::size_of_val(self) == mem::size_of::<U>() {
             let slice1 = unsafe { self.reslice::<U>()? };
             Some(&slice1[0])
         } else {
@@ -125,7 +125,7 @@ where
     where
         U: Cast,
     {
-        if mem::size_of::<T>() * self.len() == mem::size_of::<U>() {
+        if mem::size_of_val(self) == mem::size_of::<U>() {
             let slice1 = unsafe { self.reslice_mut::<U>()? };
             Some(&mut slice1[0])
         } else {
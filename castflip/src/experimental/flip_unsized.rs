@@ -59,8 +59,16 @@ where
     T: Flip
 {
     fn flip_var_swapped(&mut self) {
-        for elem in self {
-            elem.flip_var_swapped();
+        T::flip_slice_swapped(self);
+    }
+
+    fn flip_var(&mut self, endian: Endian) {
+        if endian.is_mixed() {
+            for elem in self {
+                elem.flip_var(endian);
+            }
+        } else if endian.need_swap() {
+            T::flip_slice_swapped(self);
         }
     }
 }
@@ -79,6 +87,10 @@ where
     fn flip_var_swapped(&mut self) {
         self.0.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+    }
 }
 
 impl<T0, T1> FlipUnsized for (T0, T1)
@@ -90,6 +102,11 @@ where
         self.0.flip_var_swapped();
         self.1.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+    }
 }
 
 impl<T0, T1, T2> FlipUnsized for (T0, T1, T2)
@@ -103,6 +120,12 @@ where
         self.1.flip_var_swapped();
         self.2.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+        self.2.flip_var(endian);
+    }
 }
 
 impl<T0, T1, T2, T3> FlipUnsized for (T0, T1, T2, T3)
@@ -118,6 +141,13 @@ where
         self.2.flip_var_swapped();
         self.3.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+        self.2.flip_var(endian);
+        self.3.flip_var(endian);
+    }
 }
 
 impl<T0, T1, T2, T3, T4> FlipUnsized for (T0, T1, T2, T3, T4)
@@ -135,6 +165,14 @@ where
         self.3.flip_var_swapped();
         self.4.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+        self.2.flip_var(endian);
+        self.3.flip_var(endian);
+        self.4.flip_var(endian);
+    }
 }
 
 impl<T0, T1, T2, T3, T4, T5> FlipUnsized for (T0, T1, T2, T3, T4, T5)
@@ -154,6 +192,15 @@ where
         self.4.flip_var_swapped();
         self.5.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+        self.2.flip_var(endian);
+        self.3.flip_var(endian);
+        self.4.flip_var(endian);
+        self.5.flip_var(endian);
+    }
 }
 
 impl<T0, T1, T2, T3, T4, T5, T6> FlipUnsized for (T0, T1, T2, T3, T4, T5, T6)
@@ -175,6 +222,16 @@ where
         self.5.flip_var_swapped();
         self.6.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+        self.2.flip_var(endian);
+        self.3.flip_var(endian);
+        self.4.flip_var(endian);
+        self.5.flip_var(endian);
+        self.6.flip_var(endian);
+    }
 }
 
 impl<T0, T1, T2, T3, T4, T5, T6, T7> FlipUnsized
@@ -199,4 +256,15 @@ where
         self.6.flip_var_swapped();
         self.7.flip_var_swapped();
     }
+
+    fn flip_var(&mut self, endian: Endian) {
+        self.0.flip_var(endian);
+        self.1.flip_var(endian);
+        self.2.flip_var(endian);
+        self.3.flip_var(endian);
+        self.4.flip_var(endian);
+        self.5.flip_var(endian);
+        self.6.flip_var(endian);
+        self.7.flip_var(endian);
+    }
 }
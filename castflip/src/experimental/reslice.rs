@@ -131,7 +131,7 @@ where
     where
         U: Cast,
     {
-        let slice_size = mem::size_of::<T>() * self.len();
+        let slice_size = mem::size_of_val(self);
         let new_len = slice_size / mem::size_of::<U>();
 
         #[allow(unused_parens)]
@@ -152,7 +152,7 @@ where
     where
         U: Cast,
     {
-        let slice_size = mem::size_of::<T>() * self.len();
+        let slice_size = mem::size_of_val(self);
         let new_len = slice_size / mem::size_of::<U>();
 
         #[allow(unused_parens)]
@@ -0,0 +1,202 @@
+//
+// This file defines function `encast_const` and function `to_bytes_const`
+//
+
+use crate::{Cast, Endian, Flip, bulk_swap};
+use core::{
+    mem::{self, MaybeUninit},
+    ptr, slice,
+};
+
+
+///
+/// Encasts a byte representation of type `T` at the head of `bytes`
+/// as a value of type `T` in `const` context.
+///
+/// If successful, the resulting value is returned in [`Some`]`(T)`.
+/// If `bytes` is shorter than the size of type `T`, [`None`] is
+/// returned.  The resulting value is in native-endian.  The
+/// endianness of the source bytes is specified by `endian`.
+///
+/// Because methods of traits cannot be called in `const` context,
+/// the endianness is flipped by reversing the bytes of each primitive
+/// value in bulk.  Therefore, if the endianness must be flipped, type
+/// `T` must be a primitive numeric type or an array of them.
+/// Otherwise, the evaluation panics, which results in a compile error
+/// in `const` context as follows.  A value of any type can be encasted
+/// if the endianness must not be flipped.
+///
+/// ```compile_fail
+/// use castflip::{Cast, Flip, SE, encast_const};
+///
+/// #[repr(C)]
+/// #[derive(Cast, Flip)]
+/// struct Pair { a: u16, b: u16 }
+///
+/// const PAIR: Option<Pair> = encast_const::<Pair>(&[1, 2, 3, 4], SE);
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, LE, encast_const};
+///
+/// // In practice, the bytes may be embedded by `include_bytes!`.
+/// const IMAGE: &[u8] = &[0x7F, 0x45, 0x4C, 0x46, 0x02, 0x01, 0x34, 0x12];
+///
+/// const MAGIC: u32 = encast_const::<u32>(IMAGE, BE).unwrap();
+/// const TABLE: [u16; 2] = encast_const::<[u16; 2]>(IMAGE, LE).unwrap();
+/// const _: () = assert!(MAGIC == 0x7F454C46);
+///
+/// assert_eq!(TABLE, [0x457F, 0x464C]);
+/// assert!(encast_const::<u64>(&IMAGE[1 ..], LE).is_none());
+/// ```
+///
+pub const fn encast_const<T: Cast + Flip>(
+    bytes: &[u8],
+    endian: Endian,
+) -> Option<T> {
+    let size = mem::size_of::<T>();
+
+    if bytes.len() < size {
+        return None;
+    }
+
+    let mut value = MaybeUninit::<T>::uninit();
+
+    unsafe {
+        // SAFETY: The following function call to `ptr::copy_nonoverlapping`
+        // is safe because those types that implement trait Cast can be
+        // duplicated simply by copying bits by the definition of trait
+        // Cast.
+        ptr::copy_nonoverlapping(
+            bytes.as_ptr(),
+            value.as_mut_ptr() as *mut u8,
+            size,
+        );
+
+        if endian.need_flip() {
+            // SAFETY: The following function call to
+            // `slice::from_raw_parts_mut` is safe because `value` has
+            // been initialized.
+            swap_bytes_const::<T>(
+                slice::from_raw_parts_mut(value.as_mut_ptr() as *mut u8, size),
+                endian,
+            );
+        }
+
+        // SAFETY: The following method call to `MaybeUninit::assume_init`
+        // is safe because `value` has been initialized.
+        Some(value.assume_init())
+    }
+}
+
+///
+/// Decasts a value of type `T` in `value` as a byte representation
+/// of type `T` in `const` context, then returns the resulting bytes.
+///
+/// The resulting bytes are in `endian` on the assumption that the
+/// value in `value` is in native-endian.  Parameter `N` must be the
+/// size of type `T`.  It is usually inferred from the type of the
+/// resulting array.  Otherwise, this function panics.
+///
+/// As with function [`encast_const`], if the endianness must be
+/// flipped, type `T` must be a primitive numeric type or an array of
+/// them.  Otherwise, the evaluation panics.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, LE, to_bytes_const};
+///
+/// const MAGIC: [u8; 4] = to_bytes_const(&0x7F454C46_u32, BE);
+/// const TABLE: [u8; 4] = to_bytes_const(&[0x457F_u16, 0x464C], LE);
+///
+/// assert_eq!(MAGIC, [0x7F, 0x45, 0x4C, 0x46]);
+/// assert_eq!(TABLE, [0x7F, 0x45, 0x4C, 0x46]);
+/// ```
+///
+pub const fn to_bytes_const<T: Cast + Flip, const N: usize>(
+    value: &T,
+    endian: Endian,
+) -> [u8; N] {
+    assert!(N == mem::size_of::<T>(), "N must be the size of type T");
+
+    let mut bytes = [0_u8; N];
+
+    unsafe {
+        // SAFETY: The following function call to `ptr::copy_nonoverlapping`
+        // is safe because those types that implement trait Cast can be
+        // duplicated simply by copying bits by the definition of trait
+        // Cast.
+        ptr::copy_nonoverlapping(
+            value as *const T as *const u8,
+            bytes.as_mut_ptr(),
+            N,
+        );
+    }
+
+    if endian.need_flip() {
+        swap_bytes_const::<T>(&mut bytes, endian);
+    }
+
+    bytes
+}
+
+
+///
+/// Flips the endianness of every `T::BULK_SWAP_WIDTH`-byte unit in
+/// `bytes` for `endian` in `const` context.  If the bytes of the
+/// values of type `T` cannot be reversed in bulk, it panics.
+///
+/// It is the `const` counterpart of the functions in module
+/// `bulk_swap`.
+///
+const fn swap_bytes_const<T: Cast + Flip>(bytes: &mut [u8], endian: Endian) {
+    let width = T::BULK_SWAP_WIDTH;
+
+    if mem::size_of::<T>() == 0 {
+        return;
+    }
+
+    assert!(bulk_swap::is_bulk_swappable::<T>(),
+            "the endianness of type T cannot be flipped in const context");
+
+    let word = endian.word_size();
+
+    if word == 0 {
+        reverse_units(bytes, width);
+    } else if width <= word || width % word != 0 {
+        // The unit is converted as in little-endian.
+        if cfg!(target_endian = "big") {
+            reverse_units(bytes, width);
+        }
+    } else if cfg!(target_endian = "little") {
+        // Reverse the order of the words in each unit.
+        reverse_units(bytes, width);
+        reverse_units(bytes, word);
+    } else {
+        // Reverse the order of the bytes in each word.
+        reverse_units(bytes, word);
+    }
+}
+
+///
+/// Reverses the byte order of every `width`-byte unit in `bytes` in
+/// `const` context.
+///
+const fn reverse_units(bytes: &mut [u8], width: usize) {
+    let mut head = 0;
+    while head + width <= bytes.len() {
+        let mut i = head;
+        let mut j = head + width - 1;
+        while i < j {
+            let tmp = bytes[i];
+            bytes[i] = bytes[j];
+            bytes[j] = tmp;
+            i += 1;
+            j -= 1;
+        }
+        head += width;
+    }
+}
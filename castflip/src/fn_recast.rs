@@ -0,0 +1,93 @@
+//
+// This file defines function `recast` and function `recastf`
+//
+
+use crate::{Cast, Endian, Flip};
+use core::mem::{self, ManuallyDrop};
+
+
+///
+/// Reinterprets the value in `value` of type `T` as a value of type
+/// `U` whose size is the same as the size of type `T`, then returns
+/// the resulting value.
+///
+/// The sizes of type `T` and type `U` are checked at compile time.
+/// If they differ, the program fails to compile.  The endianness of
+/// the resulting value is the same as the endianness of the value in
+/// `value`.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::recast;
+///
+/// let words: [u32; 4] = [0x11111111, 0x22222222, 0x33333333, 0x44444444];
+/// let value: u128 = recast(words);
+/// let words2: [u32; 4] = recast(value);
+/// assert_eq!(words2, words);
+///
+/// let bytes: [u8; 4] = recast(0x12345678_u32);
+/// assert_eq!(bytes, 0x12345678_u32.to_ne_bytes());
+/// ```
+///
+/// The following code fails to compile because the sizes differ.
+///
+/// ```compile_fail
+/// use castflip::recast;
+///
+/// let value: u64 = recast(0x12345678_u32);
+/// ```
+///
+#[inline]
+pub fn recast<T: Cast, U: Cast>(value: T) -> U {
+    const {
+        assert!(mem::size_of::<T>() == mem::size_of::<U>(),
+                "The sizes of the source type and the target type differ");
+    }
+
+    let value = ManuallyDrop::new(value);
+
+    unsafe {
+        // SAFETY: The following function call to `mem::transmute_copy` is
+        // safe because the sizes of both types are the same and those
+        // types that implement trait Cast can be duplicated simply by
+        // copying bits by the definition of trait Cast.
+        mem::transmute_copy::<T, U>(&value)
+    }
+}
+
+///
+/// Flips the endianness of the value in `value` of type `T` if
+/// `endian` is not equivalent to the endianness of the target system,
+/// reinterprets the resulting value as a value of type `U` whose size
+/// is the same as the size of type `T`, then returns the resulting
+/// value.
+///
+/// The endianness is flipped with trait [`Flip`] of the source type
+/// `T`.  That is, the resulting value is the value in `value` read
+/// from bytes in `endian`, reinterpreted as a value of type `U`.  The
+/// sizes of type `T` and type `U` are checked at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, Cast, recast, recastf};
+///
+/// #[repr(C)]
+/// #[derive(Cast, Debug, PartialEq)]
+/// struct Version { major: u16, minor: u16 }
+///
+/// // Words stored in big-endian.
+/// let raw: [u16; 2] = recast([0x00_u8, 0x02, 0x00, 0x01]);
+///
+/// // Flip them as two u16 values, then reinterpret them as `Version`.
+/// let version: Version = recastf(raw, BE);
+/// assert_eq!(version, Version { major: 2, minor: 1 });
+/// ```
+///
+#[inline]
+pub fn recastf<T: Cast + Flip, U: Cast>(value: T, endian: Endian) -> U {
+    let mut value = value;
+    value.flip_var(endian);
+    recast::<T, U>(value)
+}
@@ -26,20 +26,69 @@ extern crate alloc;
 
 
 //
-// Export the current API (version 0.1)
+// Export the current API (version 0.2)
 //
 pub use self::{
     bounds::{Cast, Flip, NopFlip},
+    enum_bit_order::{BitInt, BitOrder},
     enum_endian::{BE, Endian, LE, NE, SE},
+    fn_const::{encast_const, to_bytes_const},
+    fn_recast::{recast, recastf},
+    struct_bit_reader::BitReader,
+    struct_fixed::{
+        Fixed, I1F15, I1F31, I2F14, I8F8, I16F16, U8F8, U16F16,
+    },
+    struct_guid::{Guid, ParseGuidError},
+    struct_odd_int::{I24, I48, U24, U48},
+    struct_offset::{Offset, ResolveError},
+    trait_cast_enum::CastEnum,
     trait_decast_mem::DecastMem,
+    trait_decast_split::DecastSplit,
+    trait_encast_detect::{DetectError, EncastDetect},
     trait_encast_mem::EncastMem,
+    trait_encast_split::EncastSplit,
+    trait_fixed_bytes::FixedBytes,
+};
+pub use castflip_derive::{
+    BitFields, Cast, CastEnum, EncastDetect, FixedBytes, Flip, NopFlip,
+};
+
+#[cfg(feature = "alloc")]
+pub use self::{
+    struct_bit_writer::BitWriter,
 };
-pub use castflip_derive::{Cast, Flip, NopFlip};
 
 #[cfg(feature = "std")]
 pub use self::{
+    struct_encast_records::{EncastRecords, TruncatedRecord},
+    struct_flip_reader::FlipReader,
+    struct_flip_writer::FlipWriter,
+    struct_io_bit_reader::IoBitReader,
+    trait_encast_buf::EncastBufRead,
     trait_encast_io::EncastIO,
+    trait_encast_seek::{EncastSeek, OffsetError},
     trait_decast_io::DecastIO,
+    trait_decast_part::DecastPart,
+    trait_decast_seek::{DecastSeek, Placeholder},
+};
+
+#[cfg(feature = "embedded-io")]
+pub use self::{
+    trait_embedded_decast_io::EmbeddedDecastIO,
+    trait_embedded_encast_io::EmbeddedEncastIO,
+};
+
+#[cfg(feature = "futures-io")]
+pub use self::{
+    trait_async_decast_io::AsyncDecastIO,
+    trait_async_encast_io::AsyncEncastIO,
+};
+
+#[cfg(feature = "rayon")]
+pub use self::{
+    trait_par_decast_mem::ParDecastMem,
+    trait_par_encast_mem::ParEncastMem,
+    trait_par_flip::{PAR_CHUNK_SIZE, PAR_THRESHOLD, ParFlip},
 };
 
 
@@ -47,16 +96,67 @@ pub use self::{
 // Import local modules.
 //
 pub mod experimental;
+pub mod varint;
 
 mod bounds;
+mod bulk_swap;
+mod enum_bit_order;
 mod enum_endian;
+mod fn_const;
+mod fn_recast;
+mod struct_bit_reader;
+mod struct_fixed;
+mod struct_guid;
+mod struct_odd_int;
+mod struct_offset;
+mod trait_cast_enum;
 mod trait_decast_mem;
+mod trait_decast_split;
+mod trait_encast_detect;
 mod trait_encast_mem;
+mod trait_encast_split;
+mod trait_fixed_bytes;
 
+#[cfg(feature = "alloc")]
+mod struct_bit_writer;
+
+#[cfg(feature = "std")]
+mod struct_encast_records;
+#[cfg(feature = "std")]
+mod struct_flip_reader;
+#[cfg(feature = "std")]
+mod struct_flip_writer;
+#[cfg(feature = "std")]
+mod struct_io_bit_reader;
 #[cfg(feature = "std")]
 mod trait_decast_io;
 #[cfg(feature = "std")]
+mod trait_decast_part;
+#[cfg(feature = "std")]
+mod trait_decast_seek;
+#[cfg(feature = "std")]
+mod trait_encast_buf;
+#[cfg(feature = "std")]
 mod trait_encast_io;
+#[cfg(feature = "std")]
+mod trait_encast_seek;
+
+#[cfg(feature = "embedded-io")]
+mod trait_embedded_decast_io;
+#[cfg(feature = "embedded-io")]
+mod trait_embedded_encast_io;
+
+#[cfg(feature = "futures-io")]
+mod trait_async_decast_io;
+#[cfg(feature = "futures-io")]
+mod trait_async_encast_io;
+
+#[cfg(feature = "rayon")]
+mod trait_par_decast_mem;
+#[cfg(feature = "rayon")]
+mod trait_par_encast_mem;
+#[cfg(feature = "rayon")]
+mod trait_par_flip;
 
 #[cfg(doc)]
 pub mod documents;
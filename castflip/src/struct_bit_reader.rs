@@ -0,0 +1,261 @@
+//
+// This file defines struct `BitReader`
+//
+
+use crate::{BitInt, BitOrder, Cast, EncastMem, Endian, Flip};
+use core::{convert::Infallible, mem};
+
+
+///
+/// Reads values of arbitrary bit widths from a byte slice in the
+/// specified bit order.
+///
+/// Method [`BitReader::read_bits`] reads the specified number of bits
+/// as an unsigned value, and method [`BitReader::read_signed`] reads
+/// them as a signed value with sign extension.  The bits in each byte
+/// are consumed in the order specified by [`BitOrder`].  If there are
+/// not enough bits, [`None`] is returned and the position is
+/// unchanged.
+///
+/// Method [`BitReader::align`] skips the rest of the current byte.
+/// Method [`BitReader::encastf`] aligns the position and then encasts
+/// a byte representation at the position so that the byte-aligned
+/// structs following the bit fields can be read with endianness
+/// handling.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, BitOrder, BitReader};
+///
+/// let bytes: [u8; 5] = [0b1011_0010, 0b1111_0000, 0x00, 0x12, 0x34];
+/// let mut reader = BitReader::new(&bytes, BitOrder::Msb);
+///
+/// assert_eq!(reader.read_bits::<u8>(3), Some(0b101));
+/// assert_eq!(reader.read_bits::<u16>(9), Some(0b1_0010_1111));
+/// assert_eq!(reader.read_signed::<i8>(4), Some(0));
+///
+/// // Read a big-endian u16 value at the next byte boundary.
+/// reader.align();
+/// assert_eq!(reader.read_bits::<u8>(8), Some(0x00));
+/// assert_eq!(reader.encastf::<u16>(BE), Some(0x1234));
+/// assert_eq!(reader.read_bits::<u8>(1), None);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,         // The position of the next unread byte.
+    cursor: BitCursor,
+}
+
+impl<'a> BitReader<'a> {
+    ///
+    /// Creates a new `BitReader` which reads bits from `bytes` in bit
+    /// order `order`.
+    ///
+    pub fn new(bytes: &'a [u8], order: BitOrder) -> Self {
+        Self { bytes, pos: 0, cursor: BitCursor::new(order) }
+    }
+
+    ///
+    /// Reads `nbits` bits and returns them as an unsigned value of
+    /// type `T` in [`Some`]`(T)`.  If there are not enough bits,
+    /// [`None`] is returned and the position is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than the number of bits of type `T`.
+    ///
+    pub fn read_bits<T: BitInt>(&mut self, nbits: u32) -> Option<T> {
+        check_nbits::<T>(nbits);
+
+        if nbits as usize > self.bits_left() {
+            return None;
+        }
+
+        let Ok(bits) = self.cursor.take(nbits, || -> Result<u8, Infallible> {
+            let byte = self.bytes[self.pos];
+            self.pos += 1;
+            Ok(byte)
+        });
+
+        Some(T::from_u128(bits))
+    }
+
+    ///
+    /// Reads `nbits` bits and returns them as a signed value of type
+    /// `T` in [`Some`]`(T)`.  The most significant bit of the bits is
+    /// extended as the sign bit.  If there are not enough bits,
+    /// [`None`] is returned and the position is unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than the number of bits of type `T`.
+    ///
+    pub fn read_signed<T: BitInt>(&mut self, nbits: u32) -> Option<T> {
+        check_nbits::<T>(nbits);
+        let bits = self.read_bits::<u128>(nbits)?;
+        Some(T::from_u128(sign_extend(bits, nbits)))
+    }
+
+    ///
+    /// Reads a bit and returns it as a `bool` value in
+    /// [`Some`]`(bool)`.  If there is no bit, [`None`] is returned.
+    ///
+    #[inline]
+    pub fn read_bool(&mut self) -> Option<bool> {
+        Some(self.read_bits::<u8>(1)? != 0)
+    }
+
+    ///
+    /// Skips the rest of the current byte so that the position is at a
+    /// byte boundary.
+    ///
+    #[inline]
+    pub fn align(&mut self) {
+        self.cursor.align();
+    }
+
+    ///
+    /// Returns `true` if the position is at a byte boundary.
+    ///
+    #[inline]
+    pub fn is_aligned(&self) -> bool {
+        self.cursor.is_aligned()
+    }
+
+    ///
+    /// Returns the position in bits from the start of the bytes.
+    ///
+    #[inline]
+    pub fn bit_position(&self) -> usize {
+        self.pos * 8 - self.cursor.avail as usize
+    }
+
+    ///
+    /// Returns the number of the bits that have not been read.
+    ///
+    #[inline]
+    pub fn bits_left(&self) -> usize {
+        (self.bytes.len() - self.pos) * 8 + self.cursor.avail as usize
+    }
+
+    ///
+    /// Returns the bytes after the current byte.  If the position is
+    /// at a byte boundary, they are the bytes that have not been read.
+    ///
+    #[inline]
+    pub fn remaining_bytes(&self) -> &'a [u8] {
+        &self.bytes[self.pos ..]
+    }
+
+    ///
+    /// Aligns the position, then encasts a byte representation of type
+    /// `T` at the position as a value of type `T`.
+    ///
+    /// If successful, the position is advanced past the source bytes
+    /// and the resulting value is returned in [`Some`]`(T)`.  On
+    /// failure, [`None`] is returned and only the alignment takes
+    /// effect.
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    pub fn encastf<T: Cast + Flip>(&mut self, endian: Endian) -> Option<T> {
+        self.align();
+        let value = self.remaining_bytes().encastf::<T>(endian)?;
+        self.pos += mem::size_of::<T>();
+        Some(value)
+    }
+}
+
+
+///
+/// Holds the bits of the current byte that have not been read.
+///
+#[derive(Clone, Debug)]
+pub(crate) struct BitCursor {
+    order: BitOrder,
+    byte: u8,       // The current byte.
+    avail: u32,     // The number of the unread bits in `byte`.
+}
+
+impl BitCursor {
+    pub(crate) fn new(order: BitOrder) -> Self {
+        Self { order, byte: 0, avail: 0 }
+    }
+
+    ///
+    /// Takes `nbits` bits, fetching bytes by `next_byte` as necessary,
+    /// then returns them in the lowest bits of the resulting value.
+    ///
+    pub(crate) fn take<E>(
+        &mut self,
+        nbits: u32,
+        mut next_byte: impl FnMut() -> Result<u8, E>,
+    ) -> Result<u128, E> {
+        let mut bits: u128 = 0;
+        let mut done = 0;
+
+        while done < nbits {
+            if self.avail == 0 {
+                self.byte = next_byte()?;
+                self.avail = 8;
+            }
+
+            let n = (nbits - done).min(self.avail);
+            let mask = ((1_u16 << n) - 1) as u8;
+
+            match self.order {
+                BitOrder::Msb => {
+                    let chunk = (self.byte >> (self.avail - n)) & mask;
+                    bits = (bits << n) | chunk as u128;
+                },
+                BitOrder::Lsb => {
+                    let chunk = (self.byte >> (8 - self.avail)) & mask;
+                    bits |= (chunk as u128) << done;
+                },
+            }
+
+            self.avail -= n;
+            done += n;
+        }
+
+        Ok(bits)
+    }
+
+    #[inline]
+    pub(crate) fn align(&mut self) {
+        self.avail = 0;
+    }
+
+    #[inline]
+    pub(crate) fn is_aligned(&self) -> bool {
+        self.avail == 0
+    }
+}
+
+
+///
+/// Panics if `nbits` is larger than the number of bits of type `T`.
+///
+#[inline]
+pub(crate) fn check_nbits<T: BitInt>(nbits: u32) {
+    assert!(nbits <= T::BITS,
+            "The number of bits {} exceeds the width of the type {}",
+            nbits, T::BITS);
+}
+
+///
+/// Extends bit `nbits - 1` of `bits` as the sign bit.
+///
+#[inline]
+pub(crate) fn sign_extend(bits: u128, nbits: u32) -> u128 {
+    if nbits == 0 || nbits >= 128 {
+        bits
+    } else {
+        let shift = 128 - nbits;
+        (((bits << shift) as i128) >> shift) as u128
+    }
+}
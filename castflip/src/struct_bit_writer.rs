@@ -0,0 +1,170 @@
+//
+// This file defines struct `BitWriter`
+//
+
+use crate::{
+    BitInt, BitOrder, Cast, DecastMem, Endian, Flip,
+    struct_bit_reader::check_nbits,
+};
+use alloc::vec::Vec;
+use core::mem;
+
+
+///
+/// Writes values of arbitrary bit widths to a byte vector in the
+/// specified bit order.
+///
+/// Method [`BitWriter::write_bits`] writes the lowest bits of a value
+/// of the specified number.  Signed values are written in two's
+/// complement, so that they can be read back by method
+/// [`BitReader::read_signed`](crate::BitReader::read_signed).  The
+/// bits in each byte are filled in the order specified by
+/// [`BitOrder`].
+///
+/// Method [`BitWriter::align`] pads the rest of the current byte with
+/// zeros.  Method [`BitWriter::decastf`] aligns the position and then
+/// decasts a value as a byte representation so that the byte-aligned
+/// structs following the bit fields can be written with endianness
+/// handling.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, BitOrder, BitWriter};
+///
+/// let mut writer = BitWriter::new(BitOrder::Msb);
+///
+/// writer.write_bits(0b101_u8, 3);
+/// writer.write_bits(0b1_0010_1111_u16, 9);
+/// writer.write_bits(-1_i8, 2);
+/// writer.decastf(&0x1234_u16, BE);
+///
+/// assert_eq!(writer.into_bytes(), [0b1011_0010, 0b1111_1100, 0x12, 0x34]);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct BitWriter {
+    bytes: Vec<u8>,
+    order: BitOrder,
+    used: u32,      // The number of the written bits in the last byte.
+}
+
+impl BitWriter {
+    ///
+    /// Creates a new empty `BitWriter` which writes bits in bit order
+    /// `order`.
+    ///
+    pub fn new(order: BitOrder) -> Self {
+        Self { bytes: Vec::new(), order, used: 0 }
+    }
+
+    ///
+    /// Writes the lowest `nbits` bits of `value`.  The other bits of
+    /// `value` are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than the number of bits of type `T`.
+    ///
+    pub fn write_bits<T: BitInt>(&mut self, value: T, nbits: u32) {
+        check_nbits::<T>(nbits);
+
+        let bits = value.to_u128();
+        let mut done = 0;
+
+        while done < nbits {
+            if self.used == 0 {
+                self.bytes.push(0);
+            }
+
+            let n = (nbits - done).min(8 - self.used);
+            let mask = ((1_u16 << n) - 1) as u8;
+            let last = self.bytes.last_mut().expect("a byte has been pushed");
+
+            match self.order {
+                BitOrder::Msb => {
+                    let chunk = (bits >> (nbits - done - n)) as u8 & mask;
+                    *last |= chunk << (8 - self.used - n);
+                },
+                BitOrder::Lsb => {
+                    let chunk = (bits >> done) as u8 & mask;
+                    *last |= chunk << self.used;
+                },
+            }
+
+            self.used = (self.used + n) % 8;
+            done += n;
+        }
+    }
+
+    ///
+    /// Writes a bit whose value is `1` if `value` is `true`, or `0`
+    /// otherwise.
+    ///
+    #[inline]
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_bits(value as u8, 1);
+    }
+
+    ///
+    /// Pads the rest of the current byte with zeros so that the
+    /// position is at a byte boundary.
+    ///
+    #[inline]
+    pub fn align(&mut self) {
+        self.used = 0;
+    }
+
+    ///
+    /// Returns `true` if the position is at a byte boundary.
+    ///
+    #[inline]
+    pub fn is_aligned(&self) -> bool {
+        self.used == 0
+    }
+
+    ///
+    /// Returns the number of the written bits.
+    ///
+    #[inline]
+    pub fn bit_len(&self) -> usize {
+        if self.used == 0 {
+            self.bytes.len() * 8
+        } else {
+            (self.bytes.len() - 1) * 8 + self.used as usize
+        }
+    }
+
+    ///
+    /// Aligns the position, then decasts a value of type `T` in
+    /// `value` as a byte representation of type `T` and appends it.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value in `value` is in native-endian.
+    ///
+    pub fn decastf<T: Cast + Flip>(&mut self, value: &T, endian: Endian) {
+        self.align();
+
+        let start = self.bytes.len();
+        self.bytes.resize(start + mem::size_of::<T>(), 0);
+        self.bytes[start ..].decastf::<T>(value, endian);
+    }
+
+    ///
+    /// Returns the written bytes.  The unwritten bits of the last byte
+    /// are zeros.
+    ///
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    ///
+    /// Consumes `self`, then returns the written bytes.  The unwritten
+    /// bits of the last byte are zeros.
+    ///
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
@@ -0,0 +1,223 @@
+//
+// This file defines struct `EncastRecords` and struct `TruncatedRecord`
+//
+
+use crate::{Cast, Endian, Flip, experimental::AsifBytes};
+use core::{
+    fmt,
+    marker::PhantomData,
+    mem::{self, MaybeUninit},
+};
+use std::{error, io};
+
+#[cfg(doc)]
+use crate::EncastIO;
+
+
+///
+/// Is an iterator which encasts byte representations of type `T` read
+/// from a reader as values of type `T` until the end of file.
+///
+/// It is returned by method [`EncastIO::encast_records`].  Each item
+/// is a value of type `T` in native-endian in [`Ok`]`(T)` or an error
+/// value of struct [`std::io::Error`] in [`Err`].
+///
+/// - If the reader reaches the end of file at a record boundary, the
+///   iteration ends.
+///
+/// - If the reader reaches the end of file in the middle of a record,
+///   an error of kind [`std::io::ErrorKind::UnexpectedEof`] is
+///   returned.  Its inner error is a value of struct
+///   [`TruncatedRecord`], which carries the number of the bytes read
+///   for the record.
+///
+/// - If the reader returns an error of kind
+///   [`std::io::ErrorKind::Interrupted`], the read is retried.  Other
+///   errors are returned as they are.
+///
+/// After an error is returned, the iteration ends.  If the size of
+/// type `T` is zero, no record is returned because the number of
+/// records cannot be determined by reading the reader.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, EncastIO, TruncatedRecord};
+///
+/// let mut input: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9A];
+/// let mut records = input.encast_records::<u16>(BE);
+///
+/// assert_eq!(records.next().unwrap().unwrap(), 0x1234);
+/// assert_eq!(records.next().unwrap().unwrap(), 0x5678);
+///
+/// // The last record is truncated.
+/// let err = records.next().unwrap().unwrap_err();
+/// let truncated = TruncatedRecord::from_io_error(&err).unwrap();
+/// assert_eq!(truncated.nbytes(), 1);
+/// assert_eq!(truncated.size(), 2);
+///
+/// // The iteration ends after an error.
+/// assert!(records.next().is_none());
+/// ```
+///
+pub struct EncastRecords<'a, R: ?Sized, T> {
+    reader: &'a mut R,
+    endian: Endian,
+    done: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, R: ?Sized + io::Read, T: Cast + Flip> EncastRecords<'a, R, T> {
+    pub(crate) fn new(reader: &'a mut R, endian: Endian) -> Self {
+        Self {
+            reader,
+            endian,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Reads a byte representation of type `T` from the reader.
+    ///
+    /// If a whole record is read, the resulting value is returned in
+    /// `Ok(Some(T))`.  If the reader reaches the end of file at a
+    /// record boundary, `Ok(None)` is returned.
+    ///
+    fn read_record(&mut self) -> io::Result<Option<T>> {
+        if mem::size_of::<T>() == 0 {
+            // Otherwise, the iteration never ends.
+            return Ok(None);
+        }
+
+        let mut value = MaybeUninit::<T>::uninit();
+        let mut nbytes = 0;
+
+        let bytes = unsafe {
+            // SAFETY: The following method call to `AsifBytes::asif_bytes_mut`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            value.asif_bytes_mut()
+        };
+
+        while nbytes < bytes.len() {
+            match self.reader.read(&mut bytes[nbytes ..]) {
+                Ok(0) if nbytes == 0 => return Ok(None),
+                Ok(0) => return Err(TruncatedRecord::new::<T>(nbytes).into()),
+                Ok(n) => nbytes += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        let mut value = unsafe {
+            // SAFETY: The following method call to `MaybeUninit::assume_init`
+            // is safe because `value` has been initialized.
+            value.assume_init()
+        };
+
+        // Flips the endianness of the value in `value` if `endian` is
+        // not equivalent to the endianness of the target system.
+        value.flip_var(self.endian);
+
+        Ok(Some(value))
+    }
+}
+
+impl<R: ?Sized + io::Read, T: Cast + Flip> Iterator for EncastRecords<'_, R, T> {
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<io::Result<T>> {
+        if self.done {
+            return None;
+        }
+
+        match self.read_record() {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            },
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            },
+        }
+    }
+}
+
+impl<R: ?Sized + io::Read, T: Cast + Flip> core::iter::FusedIterator
+    for EncastRecords<'_, R, T>
+{}
+
+impl<R: ?Sized, T> fmt::Debug for EncastRecords<'_, R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncastRecords")
+            .field("endian", &self.endian)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+
+///
+/// Is the error returned when a reader reaches the end of file in the
+/// middle of a record.
+///
+/// It is wrapped in an error value of struct [`std::io::Error`] whose
+/// kind is [`std::io::ErrorKind::UnexpectedEof`].  It can be
+/// retrieved by function [`TruncatedRecord::from_io_error`].
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TruncatedRecord {
+    nbytes: usize,
+    size: usize,
+}
+
+impl TruncatedRecord {
+    ///
+    /// Creates a new `TruncatedRecord` for a record of type `T` of
+    /// which `nbytes` bytes have been read.
+    ///
+    pub(crate) fn new<T>(nbytes: usize) -> Self {
+        Self { nbytes, size: mem::size_of::<T>() }
+    }
+
+    ///
+    /// Returns the number of the bytes read for the truncated record.
+    ///
+    pub fn nbytes(&self) -> usize {
+        self.nbytes
+    }
+
+    ///
+    /// Returns the number of the bytes in a whole record.
+    ///
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    ///
+    /// Returns a reference to the `TruncatedRecord` wrapped in `err`
+    /// in [`Some`].  If `err` does not wrap it, [`None`] is returned.
+    ///
+    pub fn from_io_error(err: &io::Error) -> Option<&Self> {
+        err.get_ref()?.downcast_ref::<Self>()
+    }
+}
+
+impl fmt::Display for TruncatedRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "stream ended in the middle of a record ({} of {} bytes)",
+               self.nbytes, self.size)
+    }
+}
+
+impl error::Error for TruncatedRecord {}
+
+impl From<TruncatedRecord> for io::Error {
+    fn from(err: TruncatedRecord) -> Self {
+        io::Error::new(io::ErrorKind::UnexpectedEof, err)
+    }
+}
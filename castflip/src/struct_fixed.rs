@@ -0,0 +1,305 @@
+//
+// This file defines struct `Fixed` and its type aliases
+//
+
+use crate::{BitInt, Cast, Endian, Flip};
+use core::fmt;
+
+
+///
+/// Is a fixed-point number whose bits are a value of integer type `I`
+/// and whose lowest `FRAC` bits are the fractional part.
+///
+/// Struct `Fixed<I, FRAC>` has the same memory layout as type `I`, and
+/// it implements trait [`Cast`] and trait [`Flip`] if type `I`
+/// implements them.  Therefore, it can be used as the type of a field
+/// of a struct in place of a raw integer.  The value of a fixed-point
+/// number is its bits divided by 2<sup>`FRAC`</sup>.  For example,
+/// `Fixed<i32, 16>` is a Q16.16 number such as the `Fixed` type of
+/// TrueType, and `Fixed<i16, 15>` is a Q15 number such as a sample of
+/// PCM audio.  The type aliases of the common layouts (e.g.
+/// [`I16F16`] and [`I2F14`]) are also defined.
+///
+/// Method [`Fixed::to_f64`] and method [`Fixed::from_f64`] convert it
+/// to and from `f64`.  Method [`Fixed::saturating_add`], method
+/// [`Fixed::saturating_sub`], method [`Fixed::saturating_mul`] and
+/// method [`Fixed::saturating_div`] calculate a result which is
+/// clamped to the range of this type.  These methods are available
+/// when type `I` is one of `u8`, `u16`, `u32`, `u64`, `i8`, `i16`,
+/// `i32`, `i64` and `i128`.  `FRAC` must not be larger than the number
+/// of bits of type `I`, and it must be smaller than 127.  Otherwise,
+/// they fail to compile.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, Cast, EncastMem, Flip, I2F14, I16F16};
+///
+/// #[repr(C)]
+/// #[derive(Cast, Flip)]
+/// struct Header {
+///     version:    I16F16,     // The Fixed type of TrueType
+///     scale:      I2F14,      // The F2Dot14 type of TrueType
+///     flags:      u16,
+/// }
+///
+/// let bytes: [u8; 8] = [0x00, 0x01, 0x80, 0x00, 0xE0, 0x00, 0x00, 0x01];
+///
+/// let hdr: Header = bytes.encastf(BE).unwrap();
+/// assert_eq!(hdr.version.to_f64(), 1.5);
+/// assert_eq!(hdr.scale.to_f64(), -0.5);
+/// assert_eq!(hdr.flags, 1);
+///
+/// let sum = hdr.version.saturating_add(I16F16::from_f64(0.25));
+/// assert_eq!(sum.to_bits(), 0x0001_C000);
+/// assert_eq!(I2F14::from_f64(100.0), I2F14::MAX);
+/// ```
+///
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fixed<I, const FRAC: u32>(I);
+
+impl<I, const FRAC: u32> Fixed<I, FRAC> {
+    ///
+    /// Is the number of the bits of the fractional part.
+    ///
+    pub const FRAC_BITS: u32 = FRAC;
+
+    ///
+    /// Creates a new `Fixed` whose bits are `bits`.
+    ///
+    #[inline]
+    pub const fn from_bits(bits: I) -> Self {
+        Self(bits)
+    }
+
+    ///
+    /// Returns the bits of `self`.
+    ///
+    #[inline]
+    pub fn to_bits(self) -> I {
+        self.0
+    }
+}
+
+impl<I: BitInt + Into<i128>, const FRAC: u32> Fixed<I, FRAC> {
+    ///
+    /// Is the smallest value of this type.
+    ///
+    pub const MIN: Self = Self(I::MIN);
+
+    ///
+    /// Is the largest value of this type.
+    ///
+    pub const MAX: Self = Self(I::MAX);
+
+    // The value of `self` when its bits are one.  `FRAC` must be
+    // smaller than 127 so that the scale fits in `i128`.
+    const SCALE: i128 = {
+        assert!(FRAC <= I::BITS, "FRAC is larger than the number of bits");
+        assert!(FRAC < 127, "FRAC is too large to scale in i128");
+        1 << FRAC
+    };
+
+    ///
+    /// Returns the value of `self` as a value of type `f64`.
+    ///
+    /// The result is exact if the number of bits of type `I` is not
+    /// larger than 53.  Otherwise, it is rounded to the nearest value
+    /// of type `f64`.
+    ///
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0.into() as f64 / Self::SCALE as f64
+    }
+
+    ///
+    /// Creates a new `Fixed` from `value` rounded to the nearest value
+    /// of this type.  A tie is rounded away from zero.
+    ///
+    /// If `value` is out of the range of this type, the result is
+    /// [`Fixed::MIN`] or [`Fixed::MAX`].  If `value` is NaN, the
+    /// result is zero.
+    ///
+    pub fn from_f64(value: f64) -> Self {
+        let scaled = value * Self::SCALE as f64;
+
+        // Casts saturate and convert NaN to zero.  The difference is
+        // exact because a value whose magnitude is 2^53 or larger has
+        // no fractional part.
+        let bits = scaled as i128;
+        let frac = scaled - bits as f64;
+        let bits = if frac >= 0.5 {
+            bits.saturating_add(1)
+        } else if frac <= -0.5 {
+            bits.saturating_sub(1)
+        } else {
+            bits
+        };
+
+        Self::saturate(bits)
+    }
+
+    ///
+    /// Returns `self + rhs` clamped to the range of this type.
+    ///
+    #[inline]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.wide(), rhs.wide());
+        match lhs.checked_add(rhs) {
+            Some(sum) => Self::saturate(sum),
+            None => if rhs < 0 { Self::MIN } else { Self::MAX },
+        }
+    }
+
+    ///
+    /// Returns `self - rhs` clamped to the range of this type.
+    ///
+    #[inline]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.wide(), rhs.wide());
+        match lhs.checked_sub(rhs) {
+            Some(difference) => Self::saturate(difference),
+            None => if rhs < 0 { Self::MAX } else { Self::MIN },
+        }
+    }
+
+    ///
+    /// Returns `self * rhs` clamped to the range of this type.  The
+    /// result is rounded toward zero.
+    ///
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.wide(), rhs.wide());
+        match lhs.checked_mul(rhs) {
+            Some(product) => Self::saturate(product / Self::SCALE),
+            None => Self::saturate_sign(lhs, rhs),
+        }
+    }
+
+    ///
+    /// Returns `self / rhs` clamped to the range of this type.  The
+    /// result is rounded toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        let (lhs, rhs) = (self.wide(), rhs.wide());
+        assert!(rhs != 0, "attempt to divide by zero");
+        match lhs.checked_mul(Self::SCALE)
+            .and_then(|dividend| dividend.checked_div(rhs))
+        {
+            Some(quotient) => Self::saturate(quotient),
+            None => Self::saturate_sign(lhs, rhs),
+        }
+    }
+
+    ///
+    /// Returns `-self` clamped to the range of this type.
+    ///
+    #[inline]
+    pub fn saturating_neg(self) -> Self {
+        match self.wide().checked_neg() {
+            Some(negated) => Self::saturate(negated),
+            None => Self::MAX,
+        }
+    }
+
+    #[inline]
+    fn wide(self) -> i128 {
+        self.0.into()
+    }
+
+    // Returns the value whose bits are `bits` clamped to the range of
+    // type `I`.
+    fn saturate(bits: i128) -> Self {
+        let bits = bits.clamp(I::MIN.into(), I::MAX.into());
+        Self(I::from_u128(bits as u128))
+    }
+
+    // Returns the bound of this type whose sign is the sign of the
+    // product or the quotient of `lhs` and `rhs`.
+    fn saturate_sign(lhs: i128, rhs: i128) -> Self {
+        if (lhs < 0) != (rhs < 0) { Self::MIN } else { Self::MAX }
+    }
+}
+
+impl<I: Cast, const FRAC: u32> Cast for Fixed<I, FRAC> {}
+
+impl<I: Flip, const FRAC: u32> Flip for Fixed<I, FRAC> {
+    const BULK_SWAP_WIDTH: usize = I::BULK_SWAP_WIDTH;
+
+    #[inline]
+    fn flip_val_swapped(&self) -> Self {
+        Self(self.0.flip_val_swapped())
+    }
+
+    #[inline]
+    fn flip_var_swapped(&mut self) {
+        self.0.flip_var_swapped();
+    }
+
+    #[inline]
+    fn flip_val_mixed(&self, endian: Endian) -> Self {
+        Self(self.0.flip_val_mixed(endian))
+    }
+}
+
+impl<I: BitInt + Into<i128>, const FRAC: u32> From<Fixed<I, FRAC>> for f64 {
+    #[inline]
+    fn from(value: Fixed<I, FRAC>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<I: BitInt + Into<i128>, const FRAC: u32> fmt::Debug for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<I: BitInt + Into<i128>, const FRAC: u32> fmt::Display for Fixed<I, FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+
+///
+/// Is a signed Q8.8 fixed-point number in 16 bits.
+///
+pub type I8F8 = Fixed<i16, 8>;
+
+///
+/// Is a signed Q1.15 (Q15) fixed-point number in 16 bits, e.g. a
+/// sample of PCM audio in the range of -1.0 to 1.0.
+///
+pub type I1F15 = Fixed<i16, 15>;
+
+///
+/// Is a signed Q2.14 fixed-point number in 16 bits, e.g. the
+/// `F2Dot14` type of TrueType.
+///
+pub type I2F14 = Fixed<i16, 14>;
+
+///
+/// Is a signed Q16.16 fixed-point number in 32 bits, e.g. the `Fixed`
+/// type of TrueType.
+///
+pub type I16F16 = Fixed<i32, 16>;
+
+///
+/// Is a signed Q1.31 (Q31) fixed-point number in 32 bits.
+///
+pub type I1F31 = Fixed<i32, 31>;
+
+///
+/// Is an unsigned Q8.8 fixed-point number in 16 bits.
+///
+pub type U8F8 = Fixed<u16, 8>;
+
+///
+/// Is an unsigned Q16.16 fixed-point number in 32 bits.
+///
+pub type U16F16 = Fixed<u32, 16>;
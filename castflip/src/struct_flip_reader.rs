@@ -0,0 +1,235 @@
+//
+// This file defines struct `FlipReader`
+//
+
+use crate::{Cast, DecastMem, EncastMem, Endian, Flip, TruncatedRecord};
+use core::{fmt, marker::PhantomData, mem};
+use std::io;
+
+
+///
+/// Wraps a reader and converts the byte representations of type `T`
+/// read from it from one endianness to another.
+///
+/// Struct `FlipReader` implements trait [`std::io::Read`] and trait
+/// [`std::io::BufRead`].  It reads byte representations of type `T`
+/// in the endianness specified by parameter `from` from the inner
+/// reader, and produces the byte representations of type `T` in the
+/// endianness specified by parameter `to`.  Internally, each record
+/// is encasted by method [`EncastMem::encastf`] and decasted by
+/// method [`DecastMem::decastf`].  Therefore, it can be placed in a
+/// pipeline of [`std::io`] so that, for example, function
+/// [`std::io::copy`] converts a whole file between little-endian and
+/// big-endian.
+///
+/// # Partial Reads
+///
+/// The inner reader may return fewer bytes than requested.  Struct
+/// `FlipReader` buffers the bytes until a whole record is available,
+/// and produces only the bytes of whole records.  The caller may also
+/// read fewer bytes than a record at a time.  The remaining bytes of
+/// the converted record are returned by the subsequent reads.
+///
+/// # Short Final Record
+///
+/// If the inner reader reaches the end of file at a record boundary,
+/// method `read` returns `Ok(0)` as usual.  If the inner reader
+/// reaches the end of file in the middle of a record, method `read`
+/// returns an error of kind [`std::io::ErrorKind::UnexpectedEof`]
+/// wrapping a value of struct [`TruncatedRecord`] after all whole
+/// records have been produced.  The trailing bytes of the short final
+/// record are not produced, but they can be retrieved by method
+/// [`FlipReader::remainder`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::io;
+/// use castflip::{BE, FlipReader, LE};
+///
+/// let input: &[u8] = &[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+/// let mut output = Vec::new();
+///
+/// // Convert u32 values from little-endian to big-endian.
+/// let mut reader = FlipReader::<_, u32>::new(input, LE, BE);
+/// io::copy(&mut reader, &mut output).unwrap();
+///
+/// assert_eq!(output, [0x04, 0x03, 0x02, 0x01, 0x08, 0x07, 0x06, 0x05]);
+/// ```
+///
+pub struct FlipReader<R, T> {
+    inner: R,
+    from: Endian,
+    to: Endian,
+    buf: Vec<u8>,
+    pos: usize,     // The position of the next byte to be produced.
+    end: usize,     // The end of the converted records in `buf`.
+    filled: usize,  // The end of the bytes read from `inner` in `buf`.
+    eof: bool,      // Whether `inner` has reached the end of file.
+    _marker: PhantomData<fn() -> T>,
+}
+
+
+// The default size of the internal buffer in bytes.
+const BUF_SIZE: usize = 8 * 1024;
+
+impl<R: io::Read, T: Cast + Flip> FlipReader<R, T> {
+    ///
+    /// Creates a new `FlipReader` which reads byte representations of
+    /// type `T` in endianness `from` from `inner`, and produces their
+    /// byte representations in endianness `to`.
+    ///
+    pub fn new(inner: R, from: Endian, to: Endian) -> Self {
+        Self::with_capacity(BUF_SIZE, inner, from, to)
+    }
+
+    ///
+    /// Creates a new `FlipReader` with the internal buffer of at
+    /// least `capacity` bytes.  The size of the buffer is rounded to
+    /// a multiple of the size of type `T`.
+    ///
+    pub fn with_capacity(
+        capacity: usize,
+        inner: R,
+        from: Endian,
+        to: Endian,
+    ) -> Self {
+        let elem_size = mem::size_of::<T>().max(1);
+        let capacity = capacity.max(elem_size) / elem_size * elem_size;
+
+        Self {
+            inner,
+            from,
+            to,
+            buf: vec![0_u8; capacity],
+            pos: 0,
+            end: 0,
+            filled: 0,
+            eof: false,
+            _marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Returns the trailing bytes of a short final record which have
+    /// been read from the inner reader but cannot be converted.
+    ///
+    /// It returns an empty slice unless method `read` has returned an
+    /// error of kind [`std::io::ErrorKind::UnexpectedEof`].
+    ///
+    pub fn remainder(&self) -> &[u8] {
+        if self.eof && self.pos == self.end {
+            &self.buf[self.end .. self.filled]
+        } else {
+            &[]
+        }
+    }
+
+    ///
+    /// Reads bytes from the inner reader until at least one whole
+    /// record is available or the end of file is reached, then
+    /// converts all whole records in the internal buffer.
+    ///
+    fn fill_records(&mut self) -> io::Result<()> {
+        let elem_size = mem::size_of::<T>();
+
+        // Move the bytes of an incomplete record to the head.
+        self.buf.copy_within(self.end .. self.filled, 0);
+        self.filled -= self.end;
+        self.pos = 0;
+        self.end = 0;
+
+        while !self.eof && self.filled < elem_size {
+            match self.inner.read(&mut self.buf[self.filled ..]) {
+                Ok(0) => self.eof = true,
+                Ok(nbytes) => self.filled += nbytes,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+                Err(e) => return Err(e),
+            }
+        }
+
+        // Convert whole records from `from` to `to`.
+        let end = self.filled / elem_size * elem_size;
+        if !self.from.is_equivalent(self.to) {
+            for record in self.buf[.. end].chunks_exact_mut(elem_size) {
+                if let Some(value) = record.encastf::<T>(self.from) {
+                    record.decastf::<T>(&value, self.to);
+                }
+            }
+        }
+        self.end = end;
+
+        if self.end == 0 && self.filled > 0 {
+            Err(TruncatedRecord::new::<T>(self.filled).into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<R, T> FlipReader<R, T> {
+    ///
+    /// Returns a reference to the inner reader.
+    ///
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    ///
+    /// Returns a mutable reference to the inner reader.
+    ///
+    /// Note that reading from the inner reader directly may corrupt
+    /// the record boundaries.
+    ///
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    ///
+    /// Unwraps this `FlipReader`, returning the inner reader.
+    ///
+    /// Note that any buffered bytes are lost.
+    ///
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+
+impl<R: io::Read, T: Cast + Flip> io::Read for FlipReader<R, T> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let bytes = io::BufRead::fill_buf(self)?;
+        let nbytes = bytes.len().min(out.len());
+
+        out[.. nbytes].copy_from_slice(&bytes[.. nbytes]);
+        io::BufRead::consume(self, nbytes);
+
+        Ok(nbytes)
+    }
+}
+
+impl<R: io::Read, T: Cast + Flip> io::BufRead for FlipReader<R, T> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.end && mem::size_of::<T>() > 0 {
+            self.fill_records()?;
+        }
+
+        Ok(&self.buf[self.pos .. self.end])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.end);
+    }
+}
+
+impl<R: fmt::Debug, T> fmt::Debug for FlipReader<R, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlipReader")
+            .field("inner", &self.inner)
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("buffered", &(self.filled - self.pos))
+            .finish()
+    }
+}
+
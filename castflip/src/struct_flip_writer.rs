@@ -0,0 +1,282 @@
+//
+// This file defines struct `FlipWriter`
+//
+
+use crate::{Cast, DecastMem, EncastMem, Endian, Flip};
+use core::{fmt, marker::PhantomData, mem};
+use std::io;
+
+
+///
+/// Wraps a writer and converts the byte representations of type `T`
+/// written to it from one endianness to another.
+///
+/// Struct `FlipWriter` implements trait [`std::io::Write`].  It takes
+/// byte representations of type `T` in the endianness specified by
+/// parameter `from`, and writes the byte representations of type `T`
+/// in the endianness specified by parameter `to` to the inner writer.
+/// Internally, each record is encasted by method
+/// [`EncastMem::encastf`] and decasted by method
+/// [`DecastMem::decastf`].  Therefore, for example, values in
+/// native-endian can be written by method [`DecastIO::decasts`] while
+/// the inner writer receives them in big-endian.
+///
+/// [`DecastIO::decasts`]: crate::DecastIO::decasts
+///
+/// # Partial Records
+///
+/// The bytes of a record may be split across arbitrary calls to
+/// method `write`.  Struct `FlipWriter` writes whole records to the
+/// inner writer as soon as they are complete, and keeps the bytes of
+/// an incomplete record in its internal buffer until the rest of the
+/// record is written.
+///
+/// If the inner writer fails after some bytes are accepted, method
+/// `write` returns the number of the accepted bytes in [`Ok`] and
+/// keeps the converted records which have not been written in its
+/// internal buffer.  They are written by the next call to method
+/// `write` or method `flush`.  Therefore, no byte is written twice or
+/// lost when a failed call is retried.
+///
+/// Method [`FlipWriter::finish`] flushes the inner writer and returns
+/// the number of the leftover bytes of an incomplete record, which
+/// are never written to the inner writer.  They can be retrieved by
+/// method [`FlipWriter::remainder`].
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, DecastIO, FlipWriter, NE};
+///
+/// let mut writer = FlipWriter::<_, u32>::new(Vec::new(), NE, BE);
+///
+/// // Write u32 values in native-endian.
+/// writer.decasts(&[0x01020304_u32, 0x05060708]).unwrap();
+///
+/// // There is no leftover byte.
+/// assert_eq!(writer.finish().unwrap(), 0);
+///
+/// // The inner writer receives the u32 values in big-endian.
+/// let output = writer.into_inner();
+/// assert_eq!(output, [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]);
+/// ```
+///
+pub struct FlipWriter<W, T> {
+    inner: W,
+    from: Endian,
+    to: Endian,
+    partial: Vec<u8>,   // The bytes of an incomplete record.
+    pending: Vec<u8>,   // The converted records to be written.
+    written: usize,     // The number of the bytes written in `pending`.
+    _marker: PhantomData<fn(T)>,
+}
+
+
+// The default size of the internal buffer in bytes.
+const BUF_SIZE: usize = 8 * 1024;
+
+impl<W: io::Write, T: Cast + Flip> FlipWriter<W, T> {
+    ///
+    /// Creates a new `FlipWriter` which takes byte representations of
+    /// type `T` in endianness `from`, and writes their byte
+    /// representations in endianness `to` to `inner`.
+    ///
+    pub fn new(inner: W, from: Endian, to: Endian) -> Self {
+        Self::with_capacity(BUF_SIZE, inner, from, to)
+    }
+
+    ///
+    /// Creates a new `FlipWriter` with the internal buffer of at
+    /// least `capacity` bytes.  The size of the buffer is rounded to
+    /// a multiple of the size of type `T`.
+    ///
+    pub fn with_capacity(
+        capacity: usize,
+        inner: W,
+        from: Endian,
+        to: Endian,
+    ) -> Self {
+        let elem_size = mem::size_of::<T>().max(1);
+        let capacity = capacity.max(elem_size) / elem_size * elem_size;
+
+        Self {
+            inner,
+            from,
+            to,
+            partial: Vec::with_capacity(elem_size),
+            pending: Vec::with_capacity(capacity),
+            written: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    ///
+    /// Writes the converted records in the internal buffer and
+    /// flushes the inner writer, then returns the number of the
+    /// leftover bytes of an incomplete record in [`Ok`]`(usize)`.
+    ///
+    /// The leftover bytes are not written to the inner writer.  If
+    /// all records written to `self` are complete, `Ok(0)` is
+    /// returned.
+    ///
+    pub fn finish(&mut self) -> io::Result<usize> {
+        io::Write::flush(self)?;
+        Ok(self.partial.len())
+    }
+
+    ///
+    /// Converts whole records in `bytes` and appends the resulting
+    /// bytes to the internal buffer.
+    ///
+    fn push_records(&mut self, bytes: &[u8]) {
+        let elem_size = mem::size_of::<T>();
+        let start = self.pending.len();
+        self.pending.extend_from_slice(bytes);
+
+        if !self.from.is_equivalent(self.to) {
+            for record in self.pending[start ..].chunks_exact_mut(elem_size) {
+                if let Some(value) = record.encastf::<T>(self.from) {
+                    record.decastf::<T>(&value, self.to);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Writes the converted records in the internal buffer to the
+    /// inner writer.
+    ///
+    /// If it fails, the bytes which have not been written are kept so
+    /// that they are written by the next call.
+    ///
+    fn write_pending(&mut self) -> io::Result<()> {
+        while self.written < self.pending.len() {
+            match self.inner.write(&self.pending[self.written ..]) {
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::WriteZero,
+                        "failed to write the converted records",
+                    ));
+                }
+                Ok(n) => self.written += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.pending.clear();
+        self.written = 0;
+        Ok(())
+    }
+}
+
+impl<W, T> FlipWriter<W, T> {
+    ///
+    /// Returns the leftover bytes of an incomplete record which have
+    /// not been written to the inner writer.
+    ///
+    pub fn remainder(&self) -> &[u8] {
+        &self.partial
+    }
+
+    ///
+    /// Returns a reference to the inner writer.
+    ///
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    ///
+    /// Returns a mutable reference to the inner writer.
+    ///
+    /// Note that writing to the inner writer directly may corrupt the
+    /// record boundaries.
+    ///
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    ///
+    /// Unwraps this `FlipWriter`, returning the inner writer.
+    ///
+    /// Note that the leftover bytes of an incomplete record are lost.
+    /// So are the converted records which have not been written
+    /// because the inner writer failed.  Call method `flush` to write
+    /// them before calling this method.
+    ///
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+
+impl<W: io::Write, T: Cast + Flip> io::Write for FlipWriter<W, T> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        let elem_size = mem::size_of::<T>();
+
+        if elem_size == 0 {
+            // No record can be made from bytes.
+            return Ok(bytes.len());
+        }
+
+        // Write the records converted by the previous calls first so
+        // that no byte is accepted if it fails.
+        self.write_pending()?;
+
+        // Complete the incomplete record first.
+        let mut accepted = 0;
+        if !self.partial.is_empty() {
+            accepted = (elem_size - self.partial.len()).min(bytes.len());
+            self.partial.extend_from_slice(&bytes[.. accepted]);
+
+            if self.partial.len() == elem_size {
+                let record = mem::take(&mut self.partial);
+                self.push_records(&record);
+                self.partial = record;
+                self.partial.clear();
+            }
+        }
+
+        // Convert and write whole records as many as the internal
+        // buffer can hold at a time.  Once some bytes are accepted, an
+        // error is not returned because the accepted bytes are kept in
+        // the internal buffer.  The error is likely to be returned by
+        // the next call.
+        loop {
+            if let Err(error) = self.write_pending() {
+                return if accepted == 0 { Err(error) } else { Ok(accepted) };
+            }
+
+            let rest = &bytes[accepted ..];
+            let len = rest.len().min(self.pending.capacity())
+                / elem_size * elem_size;
+            if len == 0 {
+                break;
+            }
+
+            self.push_records(&rest[.. len]);
+            accepted += len;
+        }
+
+        // Keep the bytes of an incomplete record.
+        self.partial.extend_from_slice(&bytes[accepted ..]);
+
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_pending()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: fmt::Debug, T> fmt::Debug for FlipWriter<W, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlipWriter")
+            .field("inner", &self.inner)
+            .field("from", &self.from)
+            .field("to", &self.to)
+            .field("remainder", &self.partial.len())
+            .finish()
+    }
+}
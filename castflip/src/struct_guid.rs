@@ -0,0 +1,264 @@
+//
+// This file defines struct `Guid` and struct `ParseGuidError`
+//
+
+use crate::{Cast, Endian, Flip};
+use core::{error, fmt, str::FromStr};
+
+#[cfg(doc)]
+use crate::{BE, LE};
+
+
+///
+/// Is a GUID (Globally Unique Identifier), which is also known as a
+/// UUID (Universally Unique Identifier).
+///
+/// Struct `Guid` has the same memory layout as the `GUID` struct of
+/// Microsoft Windows.  Its first three fields are integers and the
+/// last field is a byte array.  It implements trait [`Cast`] and trait
+/// [`Flip`].  When its endianness is flipped, the bytes of the first
+/// three fields are reversed while the last field is unchanged.  In a
+/// mixed endianness (e.g. [`Endian::Pdp`]), each of the first three
+/// fields is converted in it while the last field is unchanged.
+///
+/// Therefore, a GUID stored in the mixed-endian form of Microsoft
+/// (e.g. in GPT partition tables and COFF files), whose first three
+/// fields are in little-endian, can be encasted with [`LE`].  A UUID
+/// stored in the byte order of RFC 4122 (RFC 9562), which is entirely
+/// big-endian, can be encasted with [`BE`].  Method
+/// [`Guid::from_uuid_bytes`] and method [`Guid::to_uuid_bytes`]
+/// convert it from and to the byte order of RFC 4122, and method
+/// [`Guid::from_guid_bytes`] and method [`Guid::to_guid_bytes`]
+/// convert it from and to the mixed-endian form of Microsoft.
+///
+/// Trait [`fmt::Display`] formats it in the canonical 8-4-4-4-12 form
+/// in lowercase.  Trait [`FromStr`] parses the canonical form in
+/// lowercase or uppercase, optionally enclosed in braces.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{EncastMem, Guid, LE};
+///
+/// // The partition type GUID of the EFI System Partition in GPT.
+/// let bytes: [u8; 16] = [
+///     0x28, 0x73, 0x2A, 0xC1, 0x1F, 0xF8, 0xD2, 0x11,
+///     0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B,
+/// ];
+///
+/// let guid: Guid = bytes.encastf(LE).unwrap();
+/// assert_eq!(guid.to_string(), "c12a7328-f81f-11d2-ba4b-00a0c93ec93b");
+/// assert_eq!(guid.data1, 0xC12A7328);
+/// assert_eq!(guid.to_guid_bytes(), bytes);
+///
+/// let parsed: Guid = "{C12A7328-F81F-11D2-BA4B-00A0C93EC93B}".parse().unwrap();
+/// assert_eq!(parsed, guid);
+/// assert_eq!(guid.to_uuid_bytes()[.. 4], [0xC1, 0x2A, 0x73, 0x28]);
+/// ```
+///
+#[repr(C)]
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct Guid {
+    ///
+    /// Is the first 8 hexadecimal digits.
+    ///
+    pub data1: u32,
+
+    ///
+    /// Is the next 4 hexadecimal digits.
+    ///
+    pub data2: u16,
+
+    ///
+    /// Is the next 4 hexadecimal digits.
+    ///
+    pub data3: u16,
+
+    ///
+    /// Is the last 16 hexadecimal digits in the order of appearance.
+    ///
+    pub data4: [u8; 8],
+}
+
+impl Guid {
+    ///
+    /// Is the nil GUID whose bits are all zeros.
+    ///
+    pub const NIL: Self = Self::new(0, 0, 0, [0; 8]);
+
+    ///
+    /// Creates a new `Guid` from its fields.
+    ///
+    #[inline]
+    pub const fn new(
+        data1: u32,
+        data2: u16,
+        data3: u16,
+        data4: [u8; 8],
+    ) -> Self {
+        Self { data1, data2, data3, data4 }
+    }
+
+    ///
+    /// Creates a new `Guid` from its representation in the byte order
+    /// of RFC 4122, in which all fields are in big-endian.
+    ///
+    pub const fn from_uuid_bytes(bytes: [u8; 16]) -> Self {
+        let [a0, a1, a2, a3, b0, b1, c0, c1, d @ ..] = bytes;
+        Self::new(
+            u32::from_be_bytes([a0, a1, a2, a3]),
+            u16::from_be_bytes([b0, b1]),
+            u16::from_be_bytes([c0, c1]),
+            d,
+        )
+    }
+
+    ///
+    /// Creates a new `Guid` from its representation in the
+    /// mixed-endian form of Microsoft, in which the first three fields
+    /// are in little-endian.
+    ///
+    pub const fn from_guid_bytes(bytes: [u8; 16]) -> Self {
+        let [a0, a1, a2, a3, b0, b1, c0, c1, d @ ..] = bytes;
+        Self::new(
+            u32::from_le_bytes([a0, a1, a2, a3]),
+            u16::from_le_bytes([b0, b1]),
+            u16::from_le_bytes([c0, c1]),
+            d,
+        )
+    }
+
+    ///
+    /// Returns the representation of `self` in the byte order of RFC
+    /// 4122, in which all fields are in big-endian.
+    ///
+    pub const fn to_uuid_bytes(&self) -> [u8; 16] {
+        let [a0, a1, a2, a3] = self.data1.to_be_bytes();
+        let [b0, b1] = self.data2.to_be_bytes();
+        let [c0, c1] = self.data3.to_be_bytes();
+        let [d0, d1, d2, d3, d4, d5, d6, d7] = self.data4;
+        [a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, d2, d3, d4, d5, d6, d7]
+    }
+
+    ///
+    /// Returns the representation of `self` in the mixed-endian form of
+    /// Microsoft, in which the first three fields are in
+    /// little-endian.
+    ///
+    pub const fn to_guid_bytes(&self) -> [u8; 16] {
+        let [a0, a1, a2, a3] = self.data1.to_le_bytes();
+        let [b0, b1] = self.data2.to_le_bytes();
+        let [c0, c1] = self.data3.to_le_bytes();
+        let [d0, d1, d2, d3, d4, d5, d6, d7] = self.data4;
+        [a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, d2, d3, d4, d5, d6, d7]
+    }
+
+    ///
+    /// Returns `true` if `self` is the nil GUID.
+    ///
+    #[inline]
+    pub const fn is_nil(&self) -> bool {
+        u128::from_ne_bytes(self.to_uuid_bytes()) == 0
+    }
+}
+
+impl Cast for Guid {}
+
+impl Flip for Guid {
+    #[inline]
+    fn flip_val_swapped(&self) -> Self {
+        Self {
+            data1: self.data1.swap_bytes(),
+            data2: self.data2.swap_bytes(),
+            data3: self.data3.swap_bytes(),
+            data4: self.data4,
+        }
+    }
+
+    #[inline]
+    fn flip_val_mixed(&self, endian: Endian) -> Self {
+        Self {
+            data1: self.data1.flip_val_mixed(endian),
+            data2: self.data2.flip_val_mixed(endian),
+            data3: self.data3.flip_val_mixed(endian),
+            data4: self.data4,
+        }
+    }
+}
+
+impl fmt::Debug for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Guid({self})")
+    }
+}
+
+impl fmt::Display for Guid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = &self.data4;
+        write!(f, "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-", self.data1,
+               self.data2, self.data3, d[0], d[1])?;
+        for byte in &d[2 ..] {
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Guid {
+    type Err = ParseGuidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix('{')
+            .map_or(Some(s), |s| s.strip_suffix('}'))
+            .ok_or(ParseGuidError(()))?;
+
+        let s = s.as_bytes();
+        if s.len() != 36 {
+            return Err(ParseGuidError(()));
+        }
+
+        let mut bytes = [0_u8; 16];
+        let mut pos = 0;
+
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            // Hyphens precede bytes 4, 6, 8 and 10.
+            if matches!(i, 4 | 6 | 8 | 10) {
+                if s[pos] != b'-' {
+                    return Err(ParseGuidError(()));
+                }
+                pos += 1;
+            }
+
+            *byte = hex_digit(s[pos])? << 4 | hex_digit(s[pos + 1])?;
+            pos += 2;
+        }
+
+        Ok(Self::from_uuid_bytes(bytes))
+    }
+}
+
+#[inline]
+fn hex_digit(c: u8) -> Result<u8, ParseGuidError> {
+    match c {
+        b'0' ..= b'9' => Ok(c - b'0'),
+        b'a' ..= b'f' => Ok(c - b'a' + 10),
+        b'A' ..= b'F' => Ok(c - b'A' + 10),
+        _ => Err(ParseGuidError(())),
+    }
+}
+
+
+///
+/// Is the error returned when a string cannot be parsed as a
+/// [`Guid`].
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParseGuidError(());
+
+impl fmt::Display for ParseGuidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid GUID syntax")
+    }
+}
+
+impl error::Error for ParseGuidError {}
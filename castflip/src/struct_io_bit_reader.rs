@@ -0,0 +1,153 @@
+//
+// This file defines struct `IoBitReader`
+//
+
+use crate::{
+    BitInt, BitOrder, Cast, EncastIO, Endian, Flip,
+    struct_bit_reader::{BitCursor, check_nbits, sign_extend},
+};
+use std::io;
+
+#[cfg(doc)]
+use crate::BitReader;
+
+
+///
+/// Reads values of arbitrary bit widths from a reader using trait
+/// [`std::io::Read`] in the specified bit order.
+///
+/// Struct `IoBitReader` is the counterpart of struct [`BitReader`]
+/// for a reader.  Its methods return [`std::io::Result`].  If the
+/// reader reaches the end of file before enough bits are read, an
+/// error of kind [`std::io::ErrorKind::UnexpectedEof`] is returned.
+/// In that case, and if the reader fails, the bits that have been
+/// read are lost.
+///
+/// Bytes are read from the inner reader one by one only when their
+/// bits are needed.  Therefore, wrap the inner reader in
+/// [`std::io::BufReader`] if reading a byte is expensive.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, BitOrder, IoBitReader};
+///
+/// let input: &[u8] = &[0b0100_1011, 0x12, 0x34];
+/// let mut reader = IoBitReader::new(input, BitOrder::Lsb);
+///
+/// assert_eq!(reader.read_bits::<u8>(3).unwrap(), 0b011);
+/// assert_eq!(reader.read_signed::<i8>(3).unwrap(), 0b001);
+/// assert_eq!(reader.read_bool().unwrap(), true);
+///
+/// // Read a big-endian u16 value at the next byte boundary.
+/// assert_eq!(reader.encastf::<u16>(BE).unwrap(), 0x1234);
+/// assert!(reader.read_bool().is_err());
+/// ```
+///
+#[derive(Debug)]
+pub struct IoBitReader<R> {
+    inner: R,
+    cursor: BitCursor,
+}
+
+impl<R: io::Read> IoBitReader<R> {
+    ///
+    /// Creates a new `IoBitReader` which reads bits from `inner` in
+    /// bit order `order`.
+    ///
+    pub fn new(inner: R, order: BitOrder) -> Self {
+        Self { inner, cursor: BitCursor::new(order) }
+    }
+
+    ///
+    /// Reads `nbits` bits and returns them as an unsigned value of
+    /// type `T` in [`Ok`]`(T)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than the number of bits of type `T`.
+    ///
+    pub fn read_bits<T: BitInt>(&mut self, nbits: u32) -> io::Result<T> {
+        check_nbits::<T>(nbits);
+
+        let inner = &mut self.inner;
+        let bits = self.cursor.take(nbits, || {
+            let mut byte = [0_u8; 1];
+            inner.read_exact(&mut byte)?;
+            Ok::<u8, io::Error>(byte[0])
+        })?;
+
+        Ok(T::from_u128(bits))
+    }
+
+    ///
+    /// Reads `nbits` bits and returns them as a signed value of type
+    /// `T` in [`Ok`]`(T)`.  The most significant bit of the bits is
+    /// extended as the sign bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nbits` is larger than the number of bits of type `T`.
+    ///
+    pub fn read_signed<T: BitInt>(&mut self, nbits: u32) -> io::Result<T> {
+        check_nbits::<T>(nbits);
+        let bits = self.read_bits::<u128>(nbits)?;
+        Ok(T::from_u128(sign_extend(bits, nbits)))
+    }
+
+    ///
+    /// Reads a bit and returns it as a `bool` value in
+    /// [`Ok`]`(bool)`.
+    ///
+    #[inline]
+    pub fn read_bool(&mut self) -> io::Result<bool> {
+        Ok(self.read_bits::<u8>(1)? != 0)
+    }
+
+    ///
+    /// Skips the rest of the current byte so that the position is at a
+    /// byte boundary.
+    ///
+    #[inline]
+    pub fn align(&mut self) {
+        self.cursor.align();
+    }
+
+    ///
+    /// Returns `true` if the position is at a byte boundary.
+    ///
+    #[inline]
+    pub fn is_aligned(&self) -> bool {
+        self.cursor.is_aligned()
+    }
+
+    ///
+    /// Aligns the position, then encasts a byte representation of type
+    /// `T` read from the inner reader as a value of type `T`.
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    pub fn encastf<T: Cast + Flip>(&mut self, endian: Endian) -> io::Result<T> {
+        self.align();
+        self.inner.encastf::<T>(endian)
+    }
+
+    ///
+    /// Returns a mutable reference to the inner reader.  The bytes
+    /// read from it are the bytes after the current byte.
+    ///
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    ///
+    /// Consumes `self`, then returns the inner reader.  The rest of the
+    /// current byte is discarded.
+    ///
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
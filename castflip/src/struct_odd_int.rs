@@ -0,0 +1,377 @@
+//
+// This file defines struct `U24`, struct `I24`, struct `U48` and
+// struct `I48`
+//
+
+use crate::{Cast, Endian, Flip, bulk_swap};
+use core::{cmp, fmt, mem, slice};
+
+
+macro_rules! define_odd_int {
+    (
+        $( #[$attr:meta] )*
+        $name:ident, $nbytes:literal, $prim:ty,
+        $from:ident, $to:ident, $from_slice:ident, $to_slice:ident
+        $(,)?
+    ) => {
+        $( #[$attr] )*
+        #[repr(C)]
+        #[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+        pub struct $name([u8; $nbytes]);
+
+        impl $name {
+            ///
+            /// Is the number of bits of this type.
+            ///
+            pub const BITS: u32 = $nbytes * 8;
+
+            ///
+            /// Is the smallest value of this type.
+            ///
+            pub const MIN: Self = Self::$from(<$prim>::MIN >> Self::SHIFT);
+
+            ///
+            /// Is the largest value of this type.
+            ///
+            pub const MAX: Self = Self::$from(<$prim>::MAX >> Self::SHIFT);
+
+            // The number of the bits of the primitive type that do not
+            // fit in this type.
+            const SHIFT: u32 = <$prim>::BITS - Self::BITS;
+
+            #[doc = concat!(
+                "\n",
+                " Creates a new `", stringify!($name), "` from the lowest ",
+                stringify!($nbytes), " bytes of\n",
+                " `value`.  The other bits of `value` are discarded.\n",
+                "\n",
+            )]
+            #[inline]
+            pub const fn $from(value: $prim) -> Self {
+                let src = value.to_le_bytes();
+                let mut bytes = [0_u8; $nbytes];
+                let mut i = 0;
+                while i < $nbytes {
+                    bytes[i] = src[i];
+                    i += 1;
+                }
+                Self::from_le_bytes(bytes)
+            }
+
+            #[doc = concat!(
+                "\n",
+                " Returns the value of `self` as a value of type `",
+                stringify!($prim), "`.\n",
+                "\n",
+            )]
+            #[inline]
+            pub const fn $to(self) -> $prim {
+                // Places the bytes at the highest bytes, then shifts
+                // them down so that the sign bit is extended if the
+                // type is signed.
+                let src = self.to_le_bytes();
+                let mut bytes = [0_u8; mem::size_of::<$prim>()];
+                let mut i = 0;
+                while i < $nbytes {
+                    bytes[mem::size_of::<$prim>() - $nbytes + i] = src[i];
+                    i += 1;
+                }
+                <$prim>::from_le_bytes(bytes) >> Self::SHIFT
+            }
+
+            ///
+            /// Creates a new value from its representation as a byte
+            /// array in little-endian.
+            ///
+            #[inline]
+            pub const fn from_le_bytes(bytes: [u8; $nbytes]) -> Self {
+                if cfg!(target_endian = "little") {
+                    Self(bytes)
+                } else {
+                    Self(reverse_bytes(bytes))
+                }
+            }
+
+            ///
+            /// Creates a new value from its representation as a byte
+            /// array in big-endian.
+            ///
+            #[inline]
+            pub const fn from_be_bytes(bytes: [u8; $nbytes]) -> Self {
+                if cfg!(target_endian = "big") {
+                    Self(bytes)
+                } else {
+                    Self(reverse_bytes(bytes))
+                }
+            }
+
+            ///
+            /// Creates a new value from its representation as a byte
+            /// array in native-endian.
+            ///
+            #[inline]
+            pub const fn from_ne_bytes(bytes: [u8; $nbytes]) -> Self {
+                Self(bytes)
+            }
+
+            ///
+            /// Returns the memory representation of `self` as a byte
+            /// array in little-endian.
+            ///
+            #[inline]
+            pub const fn to_le_bytes(self) -> [u8; $nbytes] {
+                if cfg!(target_endian = "little") {
+                    self.0
+                } else {
+                    reverse_bytes(self.0)
+                }
+            }
+
+            ///
+            /// Returns the memory representation of `self` as a byte
+            /// array in big-endian.
+            ///
+            #[inline]
+            pub const fn to_be_bytes(self) -> [u8; $nbytes] {
+                if cfg!(target_endian = "big") {
+                    self.0
+                } else {
+                    reverse_bytes(self.0)
+                }
+            }
+
+            ///
+            /// Returns the memory representation of `self` as a byte
+            /// array in native-endian.
+            ///
+            #[inline]
+            pub const fn to_ne_bytes(self) -> [u8; $nbytes] {
+                self.0
+            }
+
+            #[doc = concat!(
+                "\n",
+                " Converts the values in `src` to values of type `",
+                stringify!($prim), "`\n",
+                " and saves them in `dst`.\n",
+                "\n",
+                " # Panics\n",
+                "\n",
+                " Panics if `src` and `dst` have different lengths.\n",
+                "\n",
+            )]
+            pub fn $to_slice(src: &[Self], dst: &mut [$prim]) {
+                assert_eq!(src.len(), dst.len(),
+                           "source and destination lengths differ");
+
+                // The loop has no branches so that it can be
+                // vectorized.
+                for (d, s) in dst.iter_mut().zip(src) {
+                    *d = s.$to();
+                }
+            }
+
+            #[doc = concat!(
+                "\n",
+                " Converts the values of type `", stringify!($prim),
+                "` in `src` to values of\n",
+                " this type, then saves them in `dst`.  The bits that do ",
+                "not fit\n",
+                " in this type are discarded.\n",
+                "\n",
+                " # Panics\n",
+                "\n",
+                " Panics if `src` and `dst` have different lengths.\n",
+                "\n",
+            )]
+            pub fn $from_slice(src: &[$prim], dst: &mut [Self]) {
+                assert_eq!(src.len(), dst.len(),
+                           "source and destination lengths differ");
+
+                for (d, s) in dst.iter_mut().zip(src) {
+                    *d = Self::$from(*s);
+                }
+            }
+        }
+
+        impl Cast for $name {}
+
+        impl Flip for $name {
+            const BULK_SWAP_WIDTH: usize = $nbytes;
+
+            #[inline]
+            fn flip_val_swapped(&self) -> Self {
+                Self(reverse_bytes(self.0))
+            }
+
+            #[inline]
+            fn flip_val_mixed(&self, endian: Endian) -> Self {
+                let mut value = *self;
+                bulk_swap::flip_slice(slice::from_mut(&mut value), endian);
+                value
+            }
+        }
+
+        impl From<$name> for $prim {
+            #[inline]
+            fn from(value: $name) -> Self {
+                value.$to()
+            }
+        }
+
+        impl PartialOrd for $name {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            #[inline]
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.$to().cmp(&other.$to())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.$to(), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.$to(), f)
+            }
+        }
+    }
+}
+
+
+define_odd_int! {
+    ///
+    /// Is a 24-bit unsigned integer type.
+    ///
+    /// Struct `U24` is a 3-byte array holding a value in native-endian.
+    /// Its alignment is 1.  It implements trait [`Cast`] and trait
+    /// [`Flip`] so that it can be used as the type of a field of a
+    /// struct, e.g. a sample of 24-bit PCM audio or a field of a
+    /// network packet.  Method [`U24::to_u32`] and method
+    /// [`U24::from_u32`] convert it to and from `u32`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use castflip::{BE, EncastMem, U24};
+    ///
+    /// let bytes: [u8; 3] = [0x12, 0x34, 0x56];
+    /// let value: U24 = bytes.encastf(BE).unwrap();
+    ///
+    /// assert_eq!(value.to_u32(), 0x123456);
+    /// assert_eq!(value, U24::from_be_bytes(bytes));
+    /// assert_eq!(U24::from_u32(0xFF_123456), value);
+    /// assert_eq!(U24::MAX.to_u32(), 0xFF_FFFF);
+    /// ```
+    ///
+    U24, 3, u32, from_u32, to_u32, from_u32_slice, to_u32_slice,
+}
+
+define_odd_int! {
+    ///
+    /// Is a 24-bit signed integer type.
+    ///
+    /// Struct `I24` is a 3-byte array holding a value in native-endian
+    /// in two's complement.  Its alignment is 1.  It implements trait
+    /// [`Cast`] and trait [`Flip`] so that it can be used as the type of
+    /// a field of a struct, e.g. a sample of 24-bit PCM audio.  Method
+    /// [`I24::to_i32`] converts it to `i32` with sign extension, and
+    /// method [`I24::from_i32`] converts `i32` to it.  Method
+    /// [`I24::to_i32_slice`] and method [`I24::from_i32_slice`]
+    /// convert a slice of samples at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use castflip::{EncastMem, I24, LE};
+    ///
+    /// // Three 24-bit PCM samples in little-endian.
+    /// let bytes: [u8; 9] = [
+    ///     0x00, 0x00, 0x80, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F,
+    /// ];
+    ///
+    /// let mut samples = [I24::default(); 3];
+    /// bytes.encastsf(&mut samples, LE).unwrap();
+    ///
+    /// let mut buffer = [0_i32; 3];
+    /// I24::to_i32_slice(&samples, &mut buffer);
+    /// assert_eq!(buffer, [-0x80_0000, -1, 0x7F_FFFF]);
+    /// ```
+    ///
+    I24, 3, i32, from_i32, to_i32, from_i32_slice, to_i32_slice,
+}
+
+define_odd_int! {
+    ///
+    /// Is a 48-bit unsigned integer type.
+    ///
+    /// Struct `U48` is a 6-byte array holding a value in native-endian.
+    /// Its alignment is 1.  It implements trait [`Cast`] and trait
+    /// [`Flip`] so that it can be used as the type of a field of a
+    /// struct, e.g. a MAC address or a 48-bit timestamp.  Method
+    /// [`U48::to_u64`] and method [`U48::from_u64`] convert it to and
+    /// from `u64`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use castflip::{BE, EncastMem, U48};
+    ///
+    /// let bytes: [u8; 6] = [0x00, 0x1B, 0x63, 0x84, 0x45, 0xE6];
+    /// let mac: U48 = bytes.encastf(BE).unwrap();
+    ///
+    /// assert_eq!(mac.to_u64(), 0x001B_6384_45E6);
+    /// assert_eq!(mac.to_be_bytes(), bytes);
+    /// ```
+    ///
+    U48, 6, u64, from_u64, to_u64, from_u64_slice, to_u64_slice,
+}
+
+define_odd_int! {
+    ///
+    /// Is a 48-bit signed integer type.
+    ///
+    /// Struct `I48` is a 6-byte array holding a value in native-endian
+    /// in two's complement.  Its alignment is 1.  It implements trait
+    /// [`Cast`] and trait [`Flip`].  Method [`I48::to_i64`] converts
+    /// it to `i64` with sign extension, and method [`I48::from_i64`]
+    /// converts `i64` to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use castflip::{EncastMem, I48, LE};
+    ///
+    /// let bytes: [u8; 6] = [0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+    /// let value: I48 = bytes.encastf(LE).unwrap();
+    ///
+    /// assert_eq!(value.to_i64(), -2);
+    /// assert_eq!(I48::MIN.to_i64(), -0x8000_0000_0000);
+    /// ```
+    ///
+    I48, 6, i64, from_i64, to_i64, from_i64_slice, to_i64_slice,
+}
+
+
+///
+/// Returns `bytes` in the reverse order.
+///
+#[inline]
+const fn reverse_bytes<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    let mut reversed = [0_u8; N];
+    let mut i = 0;
+    while i < N {
+        reversed[i] = bytes[N - 1 - i];
+        i += 1;
+    }
+    reversed
+}
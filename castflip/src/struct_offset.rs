@@ -0,0 +1,311 @@
+//
+// This file defines struct `Offset` and struct `ResolveError`
+//
+
+use crate::{Cast, EncastMem, Endian, Flip};
+use core::{error, fmt, hash, marker::PhantomData, mem, ops::Range};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+///
+/// Is an offset in a byte sequence that records the type of the value
+/// it points to.
+///
+/// Struct `Offset<T, W>` has the same memory layout as its raw offset
+/// of type `W` (`u64` by default), and it implements trait [`Cast`]
+/// and trait [`Flip`] if type `W` implements them.  Therefore, it can
+/// be used as the type of a field of a header in place of a raw
+/// offset.  Type `T` is the type of the value (or the element type of
+/// the values) that the offset points to.
+///
+/// Method [`Offset::resolve`] and method [`Offset::resolve_slice`]
+/// treat the raw offset as an offset from the start of the byte
+/// sequence.  Method [`Offset::resolve_relative`] and method
+/// [`Offset::resolve_slice_relative`] treat the raw offset as an
+/// offset from the specified origin, which is usually the position of
+/// the offset itself (i.e. a self-relative pointer).  If the pointee
+/// bytes are not within the byte sequence, a value of struct
+/// [`ResolveError`] is returned in [`Err`].
+///
+/// The methods resolving an offset are available when type `W`
+/// implements `Into<i128>`, i.e. when it is one of `u8`, `u16`,
+/// `u32`, `u64`, `i8`, `i16`, `i32` and `i64`.  A negative offset is
+/// meaningful only in relative variants.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{Cast, EncastMem, Flip, LE, Offset};
+///
+/// #[repr(C)]
+/// #[derive(Cast, Flip)]
+/// struct Header {
+///     table:  Offset<u16, u32>,   // The offset of the table
+///     count:  u32,                // The number of entries in the table
+/// }
+///
+/// let bytes: [u8; 12] = [
+///     0x08, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00,
+///     0x34, 0x12, 0x78, 0x56,
+/// ];
+///
+/// let hdr: Header = bytes.encastf(LE).unwrap();
+/// let table = hdr.table.resolve_slice(&bytes, hdr.count as usize, LE);
+/// assert_eq!(table, Ok(vec![0x1234, 0x5678]));
+///
+/// // The first entry is pointed to by the offset.
+/// assert_eq!(hdr.table.get(), 8);
+/// assert_eq!(hdr.table.resolve(&bytes, LE), Ok(0x1234));
+///
+/// // The offset is out of the bytes.
+/// assert!(Offset::<u16, u32>::new(11).resolve(&bytes, LE).is_err());
+/// ```
+///
+#[repr(transparent)]
+pub struct Offset<T, W = u64> {
+    raw: W,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, W> Offset<T, W> {
+    ///
+    /// Creates a new `Offset` whose raw offset is `raw`.
+    ///
+    #[inline]
+    pub const fn new(raw: W) -> Self {
+        Self { raw, _marker: PhantomData }
+    }
+
+    ///
+    /// Returns the raw offset.
+    ///
+    #[inline]
+    pub fn get(&self) -> W
+    where
+        W: Copy,
+    {
+        self.raw
+    }
+}
+
+impl<T: Cast + Flip, W: Copy + Into<i128>> Offset<T, W> {
+    ///
+    /// Encasts a byte representation of type `T` at the offset from
+    /// the start of `buf` as a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in [`Ok`]`(T)`.
+    /// If the source bytes are not within `buf`, a value of struct
+    /// [`ResolveError`] is returned in [`Err`].
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    #[inline]
+    pub fn resolve(&self, buf: &[u8], endian: Endian) -> Result<T, ResolveError> {
+        self.resolve_relative(buf, 0, endian)
+    }
+
+    ///
+    /// Encasts a byte representation of type `T` at the offset from
+    /// `origin` in `buf` as a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in [`Ok`]`(T)`.
+    /// If the source bytes are not within `buf`, a value of struct
+    /// [`ResolveError`] is returned in [`Err`].
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    pub fn resolve_relative(
+        &self,
+        buf: &[u8],
+        origin: usize,
+        endian: Endian,
+    ) -> Result<T, ResolveError> {
+        let range = self.range(buf, origin, 1)?;
+        Ok(buf[range].encastf::<T>(endian)
+           .expect("the range has been checked"))
+    }
+
+    ///
+    /// Encasts byte representations of type `T` at the offset from
+    /// the start of `buf` as values of type `T`.  The number of values
+    /// in the source bytes is specified by `count`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Ok`]`(Vec<T>)`.  If the source bytes are not within `buf`, a
+    /// value of struct [`ResolveError`] is returned in [`Err`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn resolve_slice(
+        &self,
+        buf: &[u8],
+        count: usize,
+        endian: Endian,
+    ) -> Result<Vec<T>, ResolveError> {
+        self.resolve_slice_relative(buf, 0, count, endian)
+    }
+
+    ///
+    /// Encasts byte representations of type `T` at the offset from
+    /// `origin` in `buf` as values of type `T`.  The number of values
+    /// in the source bytes is specified by `count`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Ok`]`(Vec<T>)`.  If the source bytes are not within `buf`, a
+    /// value of struct [`ResolveError`] is returned in [`Err`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    #[cfg(feature = "alloc")]
+    pub fn resolve_slice_relative(
+        &self,
+        buf: &[u8],
+        origin: usize,
+        count: usize,
+        endian: Endian,
+    ) -> Result<Vec<T>, ResolveError> {
+        let range = self.range(buf, origin, count)?;
+        Ok(buf[range].encastvf::<T>(count, endian)
+           .expect("the range has been checked"))
+    }
+
+    ///
+    /// Returns the range of `count` values of type `T` at the offset
+    /// from `origin` in `buf` in [`Ok`].  If it is not within `buf`,
+    /// a value of struct [`ResolveError`] is returned in [`Err`].
+    ///
+    fn range(
+        &self,
+        buf: &[u8],
+        origin: usize,
+        count: usize,
+    ) -> Result<Range<usize>, ResolveError> {
+        let offset = (origin as i128).saturating_add(self.raw.into());
+        let size = mem::size_of::<T>().saturating_mul(count);
+        let error = ResolveError { offset, size, buf_len: buf.len() };
+
+        let start = usize::try_from(offset).map_err(|_| error)?;
+        let end = start.checked_add(size)
+            .filter(|&end| end <= buf.len())
+            .ok_or(error)?;
+
+        Ok(start .. end)
+    }
+}
+
+impl<T, W: Cast> Cast for Offset<T, W> {}
+
+impl<T, W: Flip> Flip for Offset<T, W> {
+    const BULK_SWAP_WIDTH: usize = W::BULK_SWAP_WIDTH;
+
+    #[inline]
+    fn flip_val_swapped(&self) -> Self {
+        Self::new(self.raw.flip_val_swapped())
+    }
+
+    #[inline]
+    fn flip_var_swapped(&mut self) {
+        self.raw.flip_var_swapped();
+    }
+
+    #[inline]
+    fn flip_val_mixed(&self, endian: Endian) -> Self {
+        Self::new(self.raw.flip_val_mixed(endian))
+    }
+}
+
+// The following traits are implemented manually so that they do not
+// require type `T` to implement them.
+
+impl<T, W: Clone> Clone for Offset<T, W> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.raw.clone())
+    }
+}
+
+impl<T, W: Copy> Copy for Offset<T, W> {}
+
+impl<T, W: fmt::Debug> fmt::Debug for Offset<T, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Offset").field(&self.raw).finish()
+    }
+}
+
+impl<T, W: Default> Default for Offset<T, W> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(W::default())
+    }
+}
+
+impl<T, W: PartialEq> PartialEq for Offset<T, W> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T, W: Eq> Eq for Offset<T, W> {}
+
+impl<T, W: hash::Hash> hash::Hash for Offset<T, W> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+
+///
+/// Is the error returned when the methods of struct [`Offset`] fail
+/// to resolve an offset because the pointee bytes are not within the
+/// byte sequence.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ResolveError {
+    offset: i128,
+    size: usize,
+    buf_len: usize,
+}
+
+impl ResolveError {
+    ///
+    /// Returns the offset of the pointee bytes from the start of the
+    /// byte sequence.  It may be negative if the offset is relative.
+    ///
+    pub fn offset(&self) -> i128 {
+        self.offset
+    }
+
+    ///
+    /// Returns the number of the pointee bytes.  It is [`usize::MAX`]
+    /// if the number overflows.
+    ///
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    ///
+    /// Returns the length of the byte sequence.
+    ///
+    pub fn buf_len(&self) -> usize {
+        self.buf_len
+    }
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} bytes at offset {} are out of {} bytes",
+               self.size, self.offset, self.buf_len)
+    }
+}
+
+impl error::Error for ResolveError {}
@@ -0,0 +1,258 @@
+//
+// This file defines trait `AsyncDecastIO`
+//
+
+use crate::{Cast, Endian, Flip, bulk_swap, experimental::AsifBytes};
+use core::{
+    future::{Future, poll_fn},
+    mem,
+    pin::Pin,
+};
+use futures_io::AsyncWrite;
+use std::io;
+
+#[cfg(doc)]
+use crate::DecastIO;
+
+
+///
+/// Provides methods that asynchronously decast values of a type as
+/// byte representations of the type with endianness handling, and
+/// write them to writer `self` using trait [`futures_io::AsyncWrite`].
+///
+/// This trait is blanketly implemented for those types that implement
+/// trait [`futures_io::AsyncWrite`] and trait [`Unpin`].  It is
+/// available when feature `futures-io` is enabled.
+///
+/// The methods of this trait are the asynchronous counterparts of the
+/// methods of trait [`DecastIO`] with the same names.  Their semantics
+/// and errors are the same.  For example, if the writer cannot accept
+/// any more bytes, an error of kind
+/// [`std::io::ErrorKind::WriteZero`] is returned.
+///
+/// The futures returned by the methods of this trait implement trait
+/// [`Send`] if `self` and the arguments implement it, so that they
+/// can be spawned on multi-threaded executors.  The methods are
+/// also available for those writers that do not implement trait
+/// [`Send`].
+///
+/// Because the names of the methods are the same as those of trait
+/// [`DecastIO`], a method call is ambiguous if both traits are in
+/// scope and `self` implements both trait [`std::io::Write`] and
+/// trait [`futures_io::AsyncWrite`] (e.g., `Vec<u8>`).  In that case,
+/// import only one of them in the scope, or call the method in the
+/// fully qualified syntax, e.g., `AsyncDecastIO::decastf(&mut output,
+/// &value, BE)`.
+///
+/// For writers of `tokio`, the compatibility layer of `tokio-util`
+/// (`tokio_util::compat`) converts them to writers implementing trait
+/// [`futures_io::AsyncWrite`].
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use castflip::{AsyncDecastIO, BE};
+///
+/// let mut output = Vec::new();
+///
+/// output.decastf(&0x1234_u16, BE).await.unwrap();
+/// output.decastsf(&[0x5678_u16, 0x9ABC], BE).await.unwrap();
+///
+/// assert_eq!(output, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+/// # });
+/// ```
+///
+pub trait AsyncDecastIO {
+    ///
+    /// Decasts a value of type `T` in `value` as a byte representation
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the value in `value`.
+    ///
+    fn decast<T: Cast>(
+        &mut self,
+        value: &T,
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Decasts a value of type `T` in `value` as a byte representation
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value in `value` is in native-endian.
+    ///
+    fn decastf<T: Cast + Flip>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the values in `slice`.
+    ///
+    fn decasts<T: Cast>(
+        &mut self,
+        slice: &[T],
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// values in `slice` are in native-endian.
+    ///
+    fn decastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Is equivalent to [`AsyncDecastIO::decasts`].
+    ///
+    /// This method will be deprecated in a future release.
+    ///
+    fn decastv<T: Cast>(
+        &mut self,
+        slice: &[T],
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Is equivalent to [`AsyncDecastIO::decastsf`].
+    ///
+    /// This method will be deprecated in a future release.
+    ///
+    fn decastvf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> impl Future<Output = io::Result<usize>>;
+}
+
+
+impl<W: ?Sized + AsyncWrite + Unpin> AsyncDecastIO for W {
+    async fn decast<T: Cast>(
+        &mut self,
+        value: &T,
+    ) -> io::Result<usize> {
+        unsafe {
+            // SAFETY: The following function call to `write_all` is safe
+            // because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            write_all(self, value.asif_bytes_ref()).await?;
+        }
+
+        Ok(mem::size_of_val(value))
+    }
+
+    async fn decastf<T: Cast + Flip>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> io::Result<usize> {
+        if !endian.need_flip() {
+            // The endianness must not be reversed.
+            self.decast::<T>(value).await
+        } else {
+            // The endianness must be flipped.
+            self.decast::<T>(&value.flip_val(endian)).await
+        }
+    }
+
+    async fn decasts<T: Cast>(
+        &mut self,
+        slice: &[T],
+    ) -> io::Result<usize> {
+        unsafe {
+            // SAFETY: The following function call to `write_all` is safe
+            // because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            write_all(self, slice.asif_bytes_ref()).await?;
+        }
+
+        Ok(mem::size_of_val(slice))
+    }
+
+    async fn decastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> io::Result<usize> {
+        if !endian.need_flip() {
+            // The endianness must not be reversed.
+            return self.decasts::<T>(slice).await;
+        }
+
+        let mut chunks = bulk_swap::FlipChunks::new(slice, endian);
+        while let Some(bytes) = chunks.next_bytes() {
+            write_all(self, bytes).await?;
+        }
+
+        Ok(mem::size_of_val(slice))
+    }
+
+    #[inline]
+    async fn decastv<T: Cast>(
+        &mut self,
+        slice: &[T],
+    ) -> io::Result<usize> {
+        // `decastv` is equivalent to `decasts`.
+        self.decasts(slice).await
+    }
+
+    #[inline]
+    async fn decastvf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> io::Result<usize> {
+        // `decastvf` is equivalent to `decastsf`.
+        self.decastsf(slice, endian).await
+    }
+}
+
+
+///
+/// Writes all bytes in `buf` to `writer`.  It is the asynchronous
+/// counterpart of method [`std::io::Write::write_all`].
+///
+async fn write_all<W>(writer: &mut W, mut buf: &[u8]) -> io::Result<()>
+where
+    W: ?Sized + AsyncWrite + Unpin,
+{
+    while !buf.is_empty() {
+        match poll_fn(|cx| Pin::new(&mut *writer).poll_write(cx, buf)).await {
+            Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+            Ok(n) => buf = &buf[n ..],
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
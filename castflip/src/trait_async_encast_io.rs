@@ -0,0 +1,309 @@
+//
+// This file defines trait `AsyncEncastIO`
+//
+
+use crate::{
+    Cast, EncastMem, Endian, Flip, bulk_swap,
+    experimental::{AsifBytes, PushBulk},
+};
+use core::{
+    future::{Future, poll_fn},
+    mem::{self, MaybeUninit},
+    pin::Pin,
+};
+use futures_io::AsyncRead;
+use std::io;
+
+#[cfg(doc)]
+use crate::EncastIO;
+
+
+///
+/// Provides methods that asynchronously encast byte representations
+/// of a type read from reader `self` using trait
+/// [`futures_io::AsyncRead`] as values of the type with endianness
+/// handling.
+///
+/// This trait is blanketly implemented for those types that implement
+/// trait [`futures_io::AsyncRead`] and trait [`Unpin`].  It is
+/// available when feature `futures-io` is enabled.
+///
+/// The methods of this trait are the asynchronous counterparts of the
+/// methods of trait [`EncastIO`] with the same names.  Their semantics
+/// and errors are the same.  For example, if the reader reaches the
+/// end of file before the whole source bytes are read, an error of
+/// kind [`std::io::ErrorKind::UnexpectedEof`] is returned.
+///
+/// The futures returned by the methods of this trait implement trait
+/// [`Send`] if `self` and the arguments implement it, so that they
+/// can be spawned on multi-threaded executors.  The methods are
+/// also available for those readers that do not implement trait
+/// [`Send`].
+///
+/// Because the names of the methods are the same as those of trait
+/// [`EncastIO`], a method call is ambiguous if both traits are in
+/// scope and `self` implements both trait [`std::io::Read`] and trait
+/// [`futures_io::AsyncRead`] (e.g., `&[u8]`).  In that case, import
+/// only one of them in the scope, or call the method in the fully
+/// qualified syntax, e.g., `AsyncEncastIO::encastf::<u16>(&mut input,
+/// BE)`.
+///
+/// For readers of `tokio`, the compatibility layer of `tokio-util`
+/// (`tokio_util::compat`) converts them to readers implementing trait
+/// [`futures_io::AsyncRead`].
+///
+/// # Example
+///
+/// ```rust
+/// # futures::executor::block_on(async {
+/// use castflip::{AsyncEncastIO, BE};
+///
+/// let mut input: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+///
+/// let value = input.encastf::<u16>(BE).await.unwrap();
+/// assert_eq!(value, 0x1234);
+///
+/// let vec = input.encastvf::<u16>(2, BE).await.unwrap();
+/// assert_eq!(vec, [0x5678, 0x9ABC]);
+/// # });
+/// ```
+///
+pub trait AsyncEncastIO {
+    ///
+    /// Encasts a byte representation of type `T` read from `self` as
+    /// a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Ok`]`(T)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    fn encast<T: Cast>(
+        &mut self,
+    ) -> impl Future<Output = io::Result<T>>;
+
+    ///
+    /// Encasts a byte representation of type `T` read from `self` as
+    /// a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Ok`]`(T)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastf<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> impl Future<Output = io::Result<T>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Ok`]`(usize)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    fn encasts<T: Cast>(
+        &mut self,
+        slice: &mut [T],
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Ok`]`(usize)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> impl Future<Output = io::Result<usize>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T`.  The number of values in the source bytes
+    /// is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Ok`]`(Vec<T>)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    fn encastv<T: Cast>(
+        &mut self,
+        len: usize,
+    ) -> impl Future<Output = io::Result<Vec<T>>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T`.  The number of values in the source bytes
+    /// is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Ok`]`(Vec<T>)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastvf<T: Cast + Flip>(
+        &mut self,
+        len: usize,
+        endian: Endian,
+    ) -> impl Future<Output = io::Result<Vec<T>>>;
+}
+
+
+// The size of the buffer to read the byte representations of values
+// in bulk.
+const BUF_SIZE: usize = 1024;
+
+impl<R: ?Sized + AsyncRead + Unpin> AsyncEncastIO for R {
+    async fn encast<T: Cast>(&mut self) -> io::Result<T> {
+        let mut value = MaybeUninit::<T>::uninit();
+
+        unsafe {
+            // SAFETY: The following function call to `read_exact` is safe
+            // because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            read_exact(self, value.asif_bytes_mut()).await?;
+
+            // SAFETY: The following method call to `MaybeUninit::assume_init`
+            // is safe because `value` has been initialized.
+            Ok(value.assume_init())
+        }
+    }
+
+    async fn encastf<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> io::Result<T> {
+        let mut value = self.encast::<T>().await?;
+
+        // Flips the endianness of the value in `value` if `endian` is
+        // not equivalent to the endianness of the target system.
+        value.flip_var(endian);
+
+        Ok(value)
+    }
+
+    async fn encasts<T: Cast>(
+        &mut self,
+        slice: &mut [T],
+    ) -> io::Result<usize> {
+        unsafe {
+            // SAFETY: The following function call to `read_exact` is safe
+            // because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            read_exact(self, slice.asif_bytes_mut()).await?;
+        }
+
+        Ok(mem::size_of_val(slice))
+    }
+
+    async fn encastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> io::Result<usize> {
+        let nbytes = self.encasts::<T>(slice).await?;
+
+        if endian.need_flip() {
+            // The endianness must be flipped.
+            bulk_swap::flip_slice(slice, endian);
+        }
+
+        Ok(nbytes)
+    }
+
+    async fn encastv<T: Cast>(
+        &mut self,
+        len: usize,
+    ) -> io::Result<Vec<T>> {
+        let elem_size = mem::size_of::<T>();
+        let mut vec: Vec<T> = Vec::with_capacity(len);
+
+        if elem_size == 0 || elem_size > BUF_SIZE {
+            for _ in 0 .. len {
+                vec.push(self.encast::<T>().await?);
+            }
+        } else {
+            let mut buf = [0_u8; BUF_SIZE];
+
+            while vec.len() < len {
+                // Read the byte representations of as many values as
+                // fit in `buf`, then append the values to `vec`.
+                let nelem = (BUF_SIZE / elem_size).min(len - vec.len());
+                let bytes = &mut buf[.. elem_size * nelem];
+                read_exact(self, bytes).await?;
+
+                unsafe {
+                    // SAFETY: The following method call to
+                    // `PushBulk::push_bulk` is safe because the closure
+                    // fills whole elements in `new_slice`.
+                    vec.push_bulk(nelem, |new_slice| {
+                        bytes.encasts::<T>(new_slice)
+                            .ok_or(io::ErrorKind::UnexpectedEof)
+                    })?;
+                }
+            }
+        }
+
+        Ok(vec)
+    }
+
+    async fn encastvf<T: Cast + Flip>(
+        &mut self,
+        len: usize,
+        endian: Endian,
+    ) -> io::Result<Vec<T>> {
+        let mut vec = self.encastv::<T>(len).await?;
+
+        if endian.need_flip() {
+            // The endianness must be flipped.
+            bulk_swap::flip_slice(&mut vec, endian);
+        }
+
+        Ok(vec)
+    }
+}
+
+
+///
+/// Reads the exact number of bytes required to fill `buf` from
+/// `reader`.  It is the asynchronous counterpart of method
+/// [`std::io::Read::read_exact`].
+///
+async fn read_exact<R>(reader: &mut R, mut buf: &mut [u8]) -> io::Result<()>
+where
+    R: ?Sized + AsyncRead + Unpin,
+{
+    while !buf.is_empty() {
+        match poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, buf)).await {
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => buf = &mut mem::take(&mut buf)[n ..],
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//
+// This file defines trait `CastEnum`
+//
+
+use crate::Endian;
+
+#[cfg(doc)]
+use crate::{Cast, DecastMem, EncastMem, Flip};
+
+
+///
+/// Provides functions that encast and decast the payload of a tagged
+/// union whose layout is selected by a tag with endianness handling.
+///
+/// Many file formats and protocols have a header with a type field
+/// followed by a payload whose layout depends on the type field, such
+/// as Mach-O load commands, ICMP messages and TLV options.  This trait
+/// represents such payload as an `enum` type whose variants wrap the
+/// types of the payloads.
+///
+/// This trait is implemented for an `enum` type by applying attribute
+/// `#[derive(CastEnum)]` to the type definition.  The type of the tag
+/// is specified by attribute `#[castflip(tag_type = TYPE)]` on the
+/// `enum` type (`u32` by default).  Each variant must be one of the
+/// following.
+///
+/// - A variant wrapping a single value of a type implementing trait
+///   [`Cast`] and trait [`Flip`], such as `Name(Type)`, with attribute
+///   `#[castflip(tag = VALUE)]`.  Its payload is encasted and decasted
+///   by the methods of trait [`EncastMem`] and trait [`DecastMem`].
+/// - A unit variant, such as `Name`, with attribute
+///   `#[castflip(tag = VALUE)]`.  Its payload is empty.
+/// - At most one variant wrapping the tag and the raw bytes of the
+///   payload, such as `Name(TYPE, Vec<u8>)`, with attribute
+///   `#[castflip(raw)]`.  It is selected if no other variant matches
+///   the tag.
+///
+/// If no variant matches the tag and there is no raw variant, or if
+/// the bytes are too short, function [`CastEnum::decode_tagged`]
+/// returns [`None`].
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, Cast, CastEnum, EncastMem, Flip};
+///
+/// #[repr(C)]
+/// #[derive(Cast, Debug, Flip, PartialEq)]
+/// struct Echo { id: u16, seq: u16 }
+///
+/// #[repr(C)]
+/// #[derive(Cast, Debug, Flip, PartialEq)]
+/// struct Unreachable { unused: u32 }
+///
+/// #[derive(CastEnum, Debug, PartialEq)]
+/// #[castflip(tag_type = u8)]
+/// enum IcmpBody {
+///     #[castflip(tag = 0)]
+///     EchoReply(Echo),
+///     #[castflip(tag = 3)]
+///     Unreachable(Unreachable),
+///     #[castflip(tag = 8)]
+///     EchoRequest(Echo),
+///     #[castflip(raw)]
+///     Other(u8, Vec<u8>),
+/// }
+///
+/// let bytes: [u8; 8] = [0x08, 0x00, 0xF7, 0xFD, 0x00, 0x01, 0x00, 0x01];
+/// let tag: u8 = bytes.encast().unwrap();
+///
+/// let body = IcmpBody::decode_tagged(tag, &bytes[4 ..], BE).unwrap();
+/// assert_eq!(body, IcmpBody::EchoRequest(Echo { id: 1, seq: 1 }));
+/// assert_eq!(body.tag(), 8);
+///
+/// let mut output = [0_u8; 4];
+/// assert_eq!(body.encode(&mut output, BE), Some(4));
+/// assert_eq!(output, bytes[4 ..]);
+///
+/// let body = IcmpBody::decode_tagged(5, &[0xC0, 0xA8], BE).unwrap();
+/// assert_eq!(body, IcmpBody::Other(5, vec![0xC0, 0xA8]));
+/// ```
+///
+pub trait CastEnum: Sized {
+    ///
+    /// Is the type of the tag that selects a variant.
+    ///
+    type Tag: Copy + PartialEq;
+
+    ///
+    /// Encasts the payload at the head of `bytes` as a value of the
+    /// variant selected by `tag`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Some`]`(Self)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the payload is specified by `endian`.
+    ///
+    fn decode_tagged(tag: Self::Tag, bytes: &[u8], endian: Endian)
+                     -> Option<Self>;
+
+    ///
+    /// Returns the tag of the variant of `self`.
+    ///
+    fn tag(&self) -> Self::Tag;
+
+    ///
+    /// Decasts the payload of `self` and saves it at the head of
+    /// `bytes`.
+    ///
+    /// If successful, the number of the resulting bytes is returned
+    /// in [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// payload of `self` is in native-endian.  The tag is not saved.
+    /// It can be retrieved by method [`CastEnum::tag`].
+    ///
+    fn encode(&self, bytes: &mut [u8], endian: Endian) -> Option<usize>;
+}
@@ -3,8 +3,8 @@
 //
 
 use crate::{
-    Cast, Endian, Flip, include_doc,
-    experimental::AsifBytes,
+    Cast, DecastPart, Endian, Flip, bulk_swap, experimental::AsifBytes,
+    include_doc, trait_decast_part,
 };
 use core::mem;
 use std::io;
@@ -98,6 +98,45 @@ pub trait DecastIO {
         slice: &[T],
         endian: Endian,
     ) -> io::Result<usize>;
+
+    ///
+    /// Decasts the parts of a multi-part record in `parts` as byte
+    /// representations of their types.
+    ///
+    /// If successful, the resulting bytes are written to `self` using
+    /// method [`std::io::Write::write_vectored`] and the number of
+    /// the bytes is returned in [`Ok`]`(usize)`.  On failure, an
+    /// error value is returned in [`Err`].
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// values in `parts` are in native-endian.  If the endianness
+    /// must not be reversed, the bytes of the parts are written as
+    /// they are without being copied.  Otherwise, they are decasted
+    /// to a fixed-size scratch buffer on the stack in chunks, and the
+    /// parts are written in rounds each of which fills the scratch
+    /// buffer at most.  Either way, the writer can write multiple
+    /// parts by a single system call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use castflip::{BE, DecastIO};
+    ///
+    /// let header: [u16; 2] = [0x0001, 0x0004];
+    /// let payload: &[u16] = &[0x1234, 0x5678];
+    ///
+    /// let mut output = Vec::new();
+    /// let size = output.decast_vectored(&[&header, &payload], BE).unwrap();
+    ///
+    /// assert_eq!(size, 8);
+    /// assert_eq!(output, [0x00, 0x01, 0x00, 0x04, 0x12, 0x34, 0x56, 0x78]);
+    /// ```
+    ///
+    fn decast_vectored(
+        &mut self,
+        parts: &[&dyn DecastPart],
+        endian: Endian,
+    ) -> io::Result<usize>;
 }
 
 
@@ -120,12 +159,12 @@ impl<W: ?Sized + io::Write> DecastIO for W {
         value: &T,
         endian: Endian,
     ) -> io::Result<usize> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.decast::<T>(value)
         } else {
-            // The endianness must be reversed.
-            self.decast::<T>(&value.flip_val_swapped())
+            // The endianness must be flipped.
+            self.decast::<T>(&value.flip_val(endian))
         }
     }
 
@@ -147,12 +186,12 @@ impl<W: ?Sized + io::Write> DecastIO for W {
         slice: &[T],
         endian: Endian,
     ) -> io::Result<usize> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.decasts::<T>(slice)
         } else {
             // The endianness must be reversed.
-            self.decastsf_swapped(slice)
+            self.decastsf_swapped(slice, endian)
         }
     }
 
@@ -171,6 +210,15 @@ impl<W: ?Sized + io::Write> DecastIO for W {
         // `decastvf` is equivalent to `decastsf`.
         self.decastsf(slice, endian)
     }
+
+    #[inline]
+    fn decast_vectored(
+        &mut self,
+        parts: &[&dyn DecastPart],
+        endian: Endian,
+    ) -> io::Result<usize> {
+        trait_decast_part::decast_vectored(self, parts, endian)
+    }
 }
 
 
@@ -182,13 +230,14 @@ trait DecastIOInternal {
     /// writer `self` and returns the number of output bytes in
     /// `Ok(usize)`.
     ///
-    /// The endianness of the output bytes is the swapped one of the
-    /// endianness of `slice`.
+    /// The endianness of the output bytes is specified by `endian`,
+    /// which is not equivalent to the endianness of the target system.
     ///
     /// If an error is detected, `Err(io::Error)` is returned.
     fn decastsf_swapped<T: Cast + Flip>(
         &mut self,
         slice: &[T],
+        endian: Endian,
     ) -> io::Result<usize>;
 }
 
@@ -196,12 +245,9 @@ impl<W: ?Sized + io::Write> DecastIOInternal for W {
     fn decastsf_swapped<T: Cast + Flip>(
         &mut self,
         slice: &[T],
+        endian: Endian,
     ) -> io::Result<usize> {
-        for elem in slice {
-            // Read values from `slice`, reverse their endiannesses, then
-            // write the resulting byte representations to `self`.
-            self.decast::<T>(&elem.flip_val_swapped())?;
-        }
+        bulk_swap::decast_chunks(slice, endian, |bytes| self.write_all(bytes))?;
 
         Ok(mem::size_of_val(slice))
     }
@@ -2,7 +2,7 @@
 // This file defines trait `DecastMem`
 //
 
-use crate::{Cast, Endian, Flip, include_doc};
+use crate::{Cast, Endian, Flip, bulk_swap, include_doc};
 use core::{mem, ptr};
 
 #[cfg(doc)]
@@ -124,12 +124,12 @@ impl DecastMem for [u8] {
         value: &T,
         endian: Endian,
     ) -> Option<usize> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.decast::<T>(value)
         } else {
-            // The endianness must be reversed.
-            self.decast::<T>(&value.flip_val_swapped())
+            // The endianness must be flipped.
+            self.decast::<T>(&value.flip_val(endian))
         }
     }
 
@@ -161,12 +161,12 @@ impl DecastMem for [u8] {
         slice: &[T],
         endian: Endian,
     ) -> Option<usize> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.decasts::<T>(slice)
         } else {
             // The endianness must be reversed.
-            self.decastsf_swapped(slice)
+            self.decastsf_swapped(slice, endian)
         }
     }
 
@@ -201,12 +201,13 @@ trait DecastMemInternal {
     /// `self` and the number of the bytes is returned in
     /// [`Some`]`(usize)`.  On failure, [`None`] is returned.
     ///
-    /// The endianness of the resulting bytes is the swapped
-    /// endianness of the values in `self`.
+    /// The endianness of the resulting bytes is specified by `endian`,
+    /// which is not equivalent to the endianness of the target system.
     ///
     fn decastsf_swapped<T: Cast + Flip>(
         &mut self,
         slice: &[T],
+        endian: Endian,
     ) -> Option<usize>;
 }
 
@@ -214,15 +215,23 @@ impl DecastMemInternal for [u8] {
     fn decastsf_swapped<T: Cast + Flip>(
         &mut self,
         slice: &[T],
+        endian: Endian,
     ) -> Option<usize> {
-        if self.len() >= mem::size_of_val(slice) {
+        if bulk_swap::is_bulk_swappable::<T>() {
+            // Save the byte representations of the values in `slice`
+            // to `self`, then reverse their endiannesses in bulk.
+            let nbytes = self.decasts::<T>(slice)?;
+            bulk_swap::flip_bytes::<T>(&mut self[.. nbytes], endian);
+
+            Some(nbytes)
+        } else if self.len() >= mem::size_of_val(slice) {
             let mut off = 0;
 
             for elem in slice {
                 // Read values from `elem` in `slice`, reverse
                 // their endiannesses, then save the resulting
                 // byte representations to `self`.
-                self[off ..].decast::<T>(&elem.flip_val_swapped())?;
+                self[off ..].decast::<T>(&elem.flip_val(endian))?;
                 off += mem::size_of_val(elem);
             }
 
@@ -0,0 +1,254 @@
+//
+// This file defines trait `DecastPart`
+//
+
+use crate::{Cast, DecastMem, Endian, Flip, experimental::AsifBytes};
+use core::{mem, slice};
+use std::io::{self, IoSlice};
+
+#[cfg(doc)]
+use crate::DecastIO;
+
+
+///
+/// Provides methods that decast a part of a multi-part record for
+/// method [`DecastIO::decast_vectored`].
+///
+/// This trait is implemented for those types that implement both
+/// trait [`Cast`] and trait [`Flip`], and for slices and vectors of
+/// them.  Because this trait is dyn-compatible, parts of different
+/// types can be passed together as `&[&dyn DecastPart]`.
+///
+pub trait DecastPart {
+    ///
+    /// Returns the byte representation of `self` as it is in memory,
+    /// i.e., in native-endian.
+    ///
+    fn part_bytes(&self) -> &[u8];
+
+    ///
+    /// Decasts `self` as a byte representation in `endian`, then saves
+    /// as many bytes of it from byte offset `offset` as fit in
+    /// `bytes`.  The number of the saved bytes is returned.  `offset`
+    /// must be smaller than the length of the slice returned by method
+    /// [`DecastPart::part_bytes`], and `bytes` must not be empty.
+    ///
+    fn decast_part(&self, offset: usize, bytes: &mut [u8], endian: Endian)
+                   -> usize;
+
+    ///
+    /// Returns `true` if the byte representation of `self` in
+    /// `endian` may differ from the one returned by method
+    /// [`DecastPart::part_bytes`].  Otherwise, method
+    /// [`DecastPart::decast_part`] need not be called.
+    ///
+    #[inline]
+    fn need_flip_part(&self, endian: Endian) -> bool {
+        endian.need_flip()
+    }
+}
+
+
+impl<T: Cast + Flip> DecastPart for T {
+    #[inline]
+    fn part_bytes(&self) -> &[u8] {
+        unsafe {
+            // SAFETY: The following method call to `AsifBytes::asif_bytes_ref`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            self.asif_bytes_ref()
+        }
+    }
+
+    #[inline]
+    fn decast_part(&self, offset: usize, bytes: &mut [u8], endian: Endian)
+                   -> usize {
+        slice::from_ref(self).decast_part(offset, bytes, endian)
+    }
+
+    #[inline]
+    fn need_flip_part(&self, endian: Endian) -> bool {
+        need_flip::<T>(endian)
+    }
+}
+
+impl<T: Cast + Flip> DecastPart for [T] {
+    #[inline]
+    fn part_bytes(&self) -> &[u8] {
+        unsafe {
+            // SAFETY: The following method call to `AsifBytes::asif_bytes_ref`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            self.asif_bytes_ref()
+        }
+    }
+
+    fn decast_part(&self, offset: usize, bytes: &mut [u8], endian: Endian)
+                   -> usize {
+        let elem_size = mem::size_of::<T>();
+        let index = offset / elem_size;
+        let skip = offset % elem_size;
+
+        if skip == 0 && elem_size <= bytes.len() {
+            // Decast as many whole values as fit in `bytes`.
+            let nelem = (bytes.len() / elem_size).min(self.len() - index);
+            let size = elem_size * nelem;
+            bytes[.. size].decastsf::<T>(&self[index .. index + nelem], endian);
+            size
+        } else {
+            // Decast a value which does not fit in `bytes` and save
+            // as many bytes of it as fit in `bytes`.
+            let value = self[index].flip_val(endian);
+            let rest = &value.part_bytes()[skip ..];
+            let size = rest.len().min(bytes.len());
+            bytes[.. size].copy_from_slice(&rest[.. size]);
+            size
+        }
+    }
+
+    #[inline]
+    fn need_flip_part(&self, endian: Endian) -> bool {
+        need_flip::<T>(endian)
+    }
+}
+
+impl<T: Cast + Flip> DecastPart for &[T] {
+    #[inline]
+    fn part_bytes(&self) -> &[u8] {
+        (**self).part_bytes()
+    }
+
+    #[inline]
+    fn decast_part(&self, offset: usize, bytes: &mut [u8], endian: Endian)
+                   -> usize {
+        (**self).decast_part(offset, bytes, endian)
+    }
+
+    #[inline]
+    fn need_flip_part(&self, endian: Endian) -> bool {
+        (**self).need_flip_part(endian)
+    }
+}
+
+impl<T: Cast + Flip> DecastPart for Vec<T> {
+    #[inline]
+    fn part_bytes(&self) -> &[u8] {
+        self[..].part_bytes()
+    }
+
+    #[inline]
+    fn decast_part(&self, offset: usize, bytes: &mut [u8], endian: Endian)
+                   -> usize {
+        self[..].decast_part(offset, bytes, endian)
+    }
+
+    #[inline]
+    fn need_flip_part(&self, endian: Endian) -> bool {
+        self[..].need_flip_part(endian)
+    }
+}
+
+
+// The size of the scratch buffer for the parts whose endiannesses
+// must be flipped.
+const BUF_SIZE: usize = 1024;
+
+// The maximum number of slices written by a single call to method
+// `std::io::Write::write_vectored`.
+const MAX_SLICES: usize = 64;
+
+///
+/// Decasts `parts` as byte representations in `endian`, then writes
+/// them to `writer` by method [`std::io::Write::write_vectored`].
+/// If successful, the number of the written bytes is returned in
+/// `Ok(usize)`.
+///
+/// The parts are written in rounds.  In each round, those parts whose
+/// endiannesses must be flipped are decasted to a scratch buffer on
+/// the stack until it is full, and the other parts are referred to as
+/// they are.
+///
+pub(crate) fn decast_vectored<W: ?Sized + io::Write>(
+    writer: &mut W,
+    parts: &[&dyn DecastPart],
+    endian: Endian,
+) -> io::Result<usize> {
+    let nbytes = parts.iter().map(|part| part.part_bytes().len()).sum();
+
+    let mut scratch = [0_u8; BUF_SIZE];
+    let mut index = 0;
+    let mut offset = 0;
+
+    while index < parts.len() {
+        let mut slices = [IoSlice::new(&[]); MAX_SLICES];
+        let mut nslices = 0;
+        let mut free = &mut scratch[..];
+
+        while index < parts.len() && nslices < MAX_SLICES {
+            let part = parts[index];
+            let bytes = part.part_bytes();
+
+            if offset == bytes.len() {
+                // Go to the next part.
+                index += 1;
+                offset = 0;
+                continue;
+            }
+
+            if part.need_flip_part(endian) {
+                if free.is_empty() {
+                    break;
+                }
+                let size = part.decast_part(offset, free, endian);
+                let (chunk, rest) = mem::take(&mut free).split_at_mut(size);
+                slices[nslices] = IoSlice::new(chunk);
+                free = rest;
+                offset += size;
+            } else {
+                slices[nslices] = IoSlice::new(&bytes[offset ..]);
+                offset = bytes.len();
+            }
+            nslices += 1;
+        }
+
+        write_all_vectored(writer, &mut slices[.. nslices])?;
+    }
+
+    Ok(nbytes)
+}
+
+///
+/// Returns `true` if the endiannesses of the values of type `T` must
+/// be flipped for `endian`.  The values of those types whose bytes
+/// are reversed in units of one byte, such as `u8` and the arrays of
+/// them, are never changed.
+///
+#[inline]
+fn need_flip<T: Cast + Flip>(endian: Endian) -> bool {
+    endian.need_flip() && T::BULK_SWAP_WIDTH != 1
+}
+
+///
+/// Writes all bytes in `slices` to `writer` by calling method
+/// [`std::io::Write::write_vectored`] repeatedly.
+///
+fn write_all_vectored<W: ?Sized + io::Write>(
+    writer: &mut W,
+    mut slices: &mut [IoSlice<'_>],
+) -> io::Result<()> {
+    // Skip empty slices to detect that nothing is left.
+    IoSlice::advance_slices(&mut slices, 0);
+
+    while !slices.is_empty() {
+        match writer.write_vectored(slices) {
+            Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+            Ok(n) => IoSlice::advance_slices(&mut slices, n),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,202 @@
+//
+// This file defines trait `DecastSeek` and struct `Placeholder`
+//
+
+use crate::{Cast, DecastIO, DecastMem, Endian, Flip};
+use core::{fmt, marker::PhantomData, mem};
+use std::io::{self, SeekFrom};
+
+
+///
+/// Provides methods that reserve space for a value of a type in
+/// writer `self` using trait [`std::io::Write`] and trait
+/// [`std::io::Seek`], and later decast the value into the reserved
+/// space with endianness handling.
+///
+/// This trait is blanketly implemented for those types that implement
+/// both trait [`std::io::Write`] and trait [`std::io::Seek`].  It is
+/// designed for container formats whose headers contain fields such
+/// as sizes and offsets which are only known after their bodies are
+/// written.
+///
+/// Method [`DecastSeek::reserve`] writes zeros in place of a value of
+/// type `T` and returns a value of struct [`Placeholder`]`<T>` which
+/// records the offset.  Method [`DecastSeek::patch`] seeks back to the
+/// offset, decasts the specified value there, then seeks to the
+/// position before the call.
+///
+/// For in-memory buffers of type `Vec<u8>`, which do not implement
+/// trait [`std::io::Seek`], see method [`Placeholder::reserve_in`] and
+/// method [`Placeholder::patch_in`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use castflip::{BE, DecastIO, DecastSeek};
+///
+/// let mut output = Cursor::new(Vec::new());
+///
+/// // Reserve space for the size of the body.
+/// let size = output.reserve::<u32>().unwrap();
+///
+/// // Write the body.
+/// let body_size = output.decastsf(&[0x1234_u16, 0x5678], BE).unwrap();
+///
+/// // Fill the size of the body.
+/// output.patch(size, &(body_size as u32), BE).unwrap();
+///
+/// assert_eq!(output.into_inner(),
+///            [0x00, 0x00, 0x00, 0x04, 0x12, 0x34, 0x56, 0x78]);
+/// ```
+///
+pub trait DecastSeek: DecastIO + io::Seek {
+    ///
+    /// Writes zeros in place of a value of type `T` to `self`, then
+    /// returns a placeholder recording the offset in
+    /// [`Ok`]`(Placeholder<T>)`.
+    ///
+    fn reserve<T: Cast>(&mut self) -> io::Result<Placeholder<T>>;
+
+    ///
+    /// Decasts the value in `value` into the space reserved by
+    /// `placeholder`, then restores the position of `self`.
+    ///
+    /// If successful, the number of the written bytes is returned in
+    /// [`Ok`]`(usize)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].  Even on failure,
+    /// the position of `self` is restored if possible.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value in `value` is in native-endian.
+    ///
+    fn patch<T: Cast + Flip>(
+        &mut self,
+        placeholder: Placeholder<T>,
+        value: &T,
+        endian: Endian,
+    ) -> io::Result<usize>;
+}
+
+
+// The zeros written in place of a value.
+const ZEROS: [u8; 64] = [0_u8; 64];
+
+impl<W: ?Sized + io::Write + io::Seek> DecastSeek for W {
+    fn reserve<T: Cast>(&mut self) -> io::Result<Placeholder<T>> {
+        let offset = self.stream_position()?;
+        let mut nbytes = mem::size_of::<T>();
+        while nbytes > 0 {
+            let len = nbytes.min(ZEROS.len());
+            self.write_all(&ZEROS[.. len])?;
+            nbytes -= len;
+        }
+        Ok(Placeholder::new(offset))
+    }
+
+    fn patch<T: Cast + Flip>(
+        &mut self,
+        placeholder: Placeholder<T>,
+        value: &T,
+        endian: Endian,
+    ) -> io::Result<usize> {
+        let pos = self.stream_position()?;
+
+        self.seek(SeekFrom::Start(placeholder.offset))?;
+        let result = self.decastf::<T>(value, endian);
+        self.seek(SeekFrom::Start(pos))?;
+
+        result
+    }
+}
+
+
+///
+/// Is a handle to the space reserved for a value of type `T`.
+///
+/// It is returned by method [`DecastSeek::reserve`] and method
+/// [`Placeholder::reserve_in`], and is consumed by method
+/// [`DecastSeek::patch`] and method [`Placeholder::patch_in`].
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{LE, Placeholder};
+///
+/// let mut output = Vec::new();
+///
+/// // Reserve space for the offset of the table.
+/// let table_off = Placeholder::<u16>::reserve_in(&mut output);
+///
+/// // Write some data, then the table.
+/// output.extend_from_slice(b"DATA");
+/// let off = output.len();
+/// output.extend_from_slice(&[0xAA, 0xBB]);
+///
+/// // Fill the offset of the table.
+/// table_off.patch_in(&mut output, &(off as u16), LE).unwrap();
+///
+/// assert_eq!(output, [0x06, 0x00, b'D', b'A', b'T', b'A', 0xAA, 0xBB]);
+/// ```
+///
+pub struct Placeholder<T> {
+    offset: u64,
+    _marker: PhantomData<fn(T)>,
+}
+
+impl<T> Placeholder<T> {
+    fn new(offset: u64) -> Self {
+        Self { offset, _marker: PhantomData }
+    }
+
+    ///
+    /// Returns the offset of the reserved space.
+    ///
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+}
+
+impl<T: Cast> Placeholder<T> {
+    ///
+    /// Appends zeros in place of a value of type `T` to `vec`, then
+    /// returns a placeholder recording the offset.
+    ///
+    pub fn reserve_in(vec: &mut Vec<u8>) -> Self {
+        let offset = vec.len() as u64;
+        vec.resize(vec.len() + mem::size_of::<T>(), 0);
+        Self::new(offset)
+    }
+}
+
+impl<T: Cast + Flip> Placeholder<T> {
+    ///
+    /// Decasts the value in `value` into the space reserved in
+    /// `bytes`.
+    ///
+    /// If successful, the number of the written bytes is returned in
+    /// [`Some`]`(usize)`.  If the reserved space is out of `bytes`,
+    /// [`None`] is returned.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value in `value` is in native-endian.
+    ///
+    pub fn patch_in(
+        self,
+        bytes: &mut [u8],
+        value: &T,
+        endian: Endian,
+    ) -> Option<usize> {
+        let offset = usize::try_from(self.offset).ok()?;
+        bytes.get_mut(offset ..)?.decastf::<T>(value, endian)
+    }
+}
+
+impl<T> fmt::Debug for Placeholder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Placeholder")
+            .field("offset", &self.offset)
+            .field("size", &mem::size_of::<T>())
+            .finish()
+    }
+}
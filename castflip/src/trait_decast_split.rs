@@ -0,0 +1,196 @@
+//
+// This file defines trait `DecastSplit`
+//
+
+use crate::{Cast, Endian, Flip, bulk_swap, experimental::AsifBytes};
+use core::mem;
+
+#[cfg(doc)]
+use crate::DecastMem;
+
+
+///
+/// Provides methods that decast values of a type as byte
+/// representations of the type and save them at the head of a byte
+/// sequence split into two parts with endianness handling.
+///
+/// This trait is implemented for `(&mut [u8], &mut [u8])`, which is a
+/// byte sequence consisting of the first part and the second part,
+/// such as the one returned by method `VecDeque::as_mut_slices`.
+///
+/// The methods of this trait are the counterparts of the methods of
+/// trait [`DecastMem`] with the same names.  The resulting bytes are
+/// saved in the first part, and the rest of them are saved in the
+/// second part.  If the byte sequence is shorter than the resulting
+/// bytes, [`None`] is returned and the byte sequence is unchanged.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, DecastSplit};
+///
+/// let mut first = [0_u8; 3];
+/// let mut second = [0_u8; 3];
+///
+/// let size = (&mut first[..], &mut second[..])
+///     .decastf(&0x12345678_u32, BE).unwrap();
+///
+/// assert_eq!(size, 4);
+/// assert_eq!(first, [0x12, 0x34, 0x56]);
+/// assert_eq!(second, [0x78, 0x00, 0x00]);
+/// ```
+///
+pub trait DecastSplit {
+    ///
+    /// Decasts a value of type `T` in `value` as a byte representation
+    /// of type `T` and saves it at the head of `self`.
+    ///
+    /// If successful, the number of the resulting bytes is returned
+    /// in [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the value in `value`.
+    ///
+    fn decast<T: Cast>(&mut self, value: &T) -> Option<usize>;
+
+    ///
+    /// Decasts a value of type `T` in `value` as a byte representation
+    /// of type `T` and saves it at the head of `self`.
+    ///
+    /// If successful, the number of the resulting bytes is returned
+    /// in [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value in `value` is in native-endian.
+    ///
+    fn decastf<T: Cast + Flip>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> Option<usize>;
+
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T` and saves them at the head of `self`.
+    ///
+    /// If successful, the number of the resulting bytes is returned
+    /// in [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the values in `slice`.
+    ///
+    fn decasts<T: Cast>(&mut self, slice: &[T]) -> Option<usize>;
+
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T` and saves them at the head of `self`.
+    ///
+    /// If successful, the number of the resulting bytes is returned
+    /// in [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// values in `slice` are in native-endian.
+    ///
+    fn decastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> Option<usize>;
+}
+
+
+impl DecastSplit for (&mut [u8], &mut [u8]) {
+    #[inline]
+    fn decast<T: Cast>(&mut self, value: &T) -> Option<usize> {
+        unsafe {
+            // SAFETY: The following method call to `AsifBytes::asif_bytes_ref`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            write_split(self, 0, value.asif_bytes_ref())
+        }
+    }
+
+    #[inline]
+    fn decastf<T: Cast + Flip>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> Option<usize> {
+        if !endian.need_flip() {
+            // The endianness must not be reversed.
+            self.decast::<T>(value)
+        } else {
+            // The endianness must be flipped.
+            self.decast::<T>(&value.flip_val(endian))
+        }
+    }
+
+    #[inline]
+    fn decasts<T: Cast>(&mut self, slice: &[T]) -> Option<usize> {
+        unsafe {
+            // SAFETY: The following method call to `AsifBytes::asif_bytes_ref`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            write_split(self, 0, slice.asif_bytes_ref())
+        }
+    }
+
+    fn decastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> Option<usize> {
+        let nbytes = mem::size_of_val(slice);
+
+        if !endian.need_flip() {
+            // The endianness must not be reversed.
+            return self.decasts::<T>(slice);
+        }
+
+        if self.0.len() + self.1.len() < nbytes {
+            return None;
+        }
+
+        let mut pos = 0;
+        bulk_swap::decast_chunks(slice, endian, |bytes| {
+            write_split(self, pos, bytes).map(|size| pos += size).ok_or(())
+        }).ok()?;
+
+        Some(nbytes)
+    }
+}
+
+
+///
+/// Saves `bytes` at offset `pos` of the byte sequence consisting of
+/// `dest.0` and `dest.1`, then returns the number of the saved bytes
+/// in [`Some`]`(usize)`.  If the byte sequence is too short,
+/// [`None`] is returned and the byte sequence is unchanged.
+///
+fn write_split(
+    dest: &mut (&mut [u8], &mut [u8]),
+    pos: usize,
+    bytes: &[u8],
+) -> Option<usize> {
+    let (first, second) = dest;
+    let end = pos.checked_add(bytes.len())?;
+
+    if first.len() + second.len() < end {
+        return None;
+    }
+
+    // The range of `bytes` to be saved in the first part.
+    let len = first.len().saturating_sub(pos).min(bytes.len());
+    let (bytes_first, bytes_second) = bytes.split_at(len);
+
+    if len > 0 {
+        first[pos .. pos + len].copy_from_slice(bytes_first);
+    }
+    let pos_second = (pos + len).saturating_sub(first.len());
+    second[pos_second .. pos_second + bytes_second.len()]
+        .copy_from_slice(bytes_second);
+
+    Some(bytes.len())
+}
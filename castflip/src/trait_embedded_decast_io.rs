@@ -0,0 +1,157 @@
+//
+// This file defines trait `EmbeddedDecastIO`
+//
+
+use crate::{Cast, Endian, Flip, bulk_swap, experimental::AsifBytes};
+use core::mem;
+use embedded_io::Write;
+
+#[cfg(doc)]
+use crate::DecastIO;
+
+
+///
+/// Provides methods that decast values of a type as byte
+/// representations of the type with endianness handling, and write
+/// them to writer `self` using trait [`embedded_io::Write`].
+///
+/// This trait is blanketly implemented for those types that implement
+/// trait [`embedded_io::Write`].  It is available when feature
+/// `embedded-io` is enabled, even on a `no_std` environment.
+///
+/// The methods of this trait are the counterparts of the methods of
+/// trait [`DecastIO`] with the same names.  On failure, they return
+/// the error value of the writer in [`Err`].
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, EmbeddedDecastIO};
+///
+/// let mut buf = [0_u8; 6];
+/// let mut output = &mut buf[..];
+///
+/// output.decastf(&0x1234_u16, BE).unwrap();
+/// output.decastsf(&[0x5678_u16, 0x9ABC], BE).unwrap();
+///
+/// assert_eq!(buf, [0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC]);
+/// ```
+///
+pub trait EmbeddedDecastIO: Write {
+    ///
+    /// Decasts a value of type `T` in `value` as a byte representation
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the value in `value`.
+    ///
+    fn decast<T: Cast>(&mut self, value: &T) -> Result<usize, Self::Error>;
+
+    ///
+    /// Decasts a value of type `T` in `value` as a byte representation
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value in `value` is in native-endian.
+    ///
+    fn decastf<T: Cast + Flip>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> Result<usize, Self::Error>;
+
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the values in `slice`.
+    ///
+    fn decasts<T: Cast>(&mut self, slice: &[T]) -> Result<usize, Self::Error>;
+
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T`.
+    ///
+    /// If successful, the resulting bytes are written to `self` and
+    /// the number of the bytes is returned in [`Ok`]`(usize)`.  On
+    /// failure, an error value is returned in [`Err`].
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// values in `slice` are in native-endian.
+    ///
+    fn decastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> Result<usize, Self::Error>;
+}
+
+
+impl<W: ?Sized + Write> EmbeddedDecastIO for W {
+    #[inline]
+    fn decast<T: Cast>(&mut self, value: &T) -> Result<usize, Self::Error> {
+        unsafe {
+            // SAFETY: The following function call to `Write::write_all`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            self.write_all(value.asif_bytes_ref())?;
+        }
+        Ok(mem::size_of_val(value))
+    }
+
+    #[inline]
+    fn decastf<T: Cast + Flip>(
+        &mut self,
+        value: &T,
+        endian: Endian,
+    ) -> Result<usize, Self::Error> {
+        if !endian.need_flip() {
+            // The endianness must not be reversed.
+            self.decast::<T>(value)
+        } else {
+            // The endianness must be flipped.
+            self.decast::<T>(&value.flip_val(endian))
+        }
+    }
+
+    #[inline]
+    fn decasts<T: Cast>(&mut self, slice: &[T]) -> Result<usize, Self::Error> {
+        unsafe {
+            // SAFETY: The following function call to `Write::write_all`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            self.write_all(slice.asif_bytes_ref())?;
+        }
+        Ok(mem::size_of_val(slice))
+    }
+
+    fn decastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> Result<usize, Self::Error> {
+        if !endian.need_flip() {
+            // The endianness must not be reversed.
+            return self.decasts::<T>(slice);
+        }
+
+        bulk_swap::decast_chunks(slice, endian, |bytes| self.write_all(bytes))?;
+
+        Ok(mem::size_of_val(slice))
+    }
+}
@@ -0,0 +1,252 @@
+//
+// This file defines trait `EmbeddedEncastIO`
+//
+
+use crate::{Cast, Endian, Flip, bulk_swap, experimental::AsifBytes};
+use core::mem::{self, MaybeUninit};
+use embedded_io::{Read, ReadExactError};
+
+#[cfg(doc)]
+use crate::EncastIO;
+#[cfg(feature = "alloc")]
+use crate::experimental::PushBulk;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+///
+/// Provides methods that encast byte representations of a type read
+/// from reader `self` using trait [`embedded_io::Read`] as values of
+/// the type with endianness handling.
+///
+/// This trait is blanketly implemented for those types that implement
+/// trait [`embedded_io::Read`].  It is available when feature
+/// `embedded-io` is enabled, even on a `no_std` environment.
+///
+/// The methods of this trait are the counterparts of the methods of
+/// trait [`EncastIO`] with the same names.  On failure, they return an
+/// error value of enum [`embedded_io::ReadExactError`] wrapping the
+/// error type of the reader in [`Err`].  If the reader reaches the
+/// end of file before the whole source bytes are read,
+/// [`ReadExactError::UnexpectedEof`] is returned.
+///
+/// Method [`EmbeddedEncastIO::encastv`] and method
+/// [`EmbeddedEncastIO::encastvf`] are available when feature `alloc`
+/// is also enabled.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, EmbeddedEncastIO};
+///
+/// let mut input: &[u8] = &[0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+///
+/// let value: u16 = input.encastf(BE).unwrap();
+/// assert_eq!(value, 0x1234);
+///
+/// let mut slice = [0_u16; 2];
+/// input.encastsf(&mut slice, BE).unwrap();
+/// assert_eq!(slice, [0x5678, 0x9ABC]);
+/// ```
+///
+pub trait EmbeddedEncastIO: Read {
+    ///
+    /// Encasts a byte representation of type `T` read from `self` as
+    /// a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Ok`]`(T)`.  On failure, an error value is returned in
+    /// [`Err`].
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    fn encast<T: Cast>(&mut self) -> Result<T, ReadExactError<Self::Error>>;
+
+    ///
+    /// Encasts a byte representation of type `T` read from `self` as
+    /// a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Ok`]`(T)`.  On failure, an error value is returned in
+    /// [`Err`].
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastf<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> Result<T, ReadExactError<Self::Error>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Ok`]`(usize)`.  On failure, an error value is returned in
+    /// [`Err`].
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    fn encasts<T: Cast>(
+        &mut self,
+        slice: &mut [T],
+    ) -> Result<usize, ReadExactError<Self::Error>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Ok`]`(usize)`.  On failure, an error value is returned in
+    /// [`Err`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> Result<usize, ReadExactError<Self::Error>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T`.  The number of values in the source bytes
+    /// is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Ok`]`(Vec<T>)`.  On failure, an error value is returned in
+    /// [`Err`].
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    #[cfg(feature = "alloc")]
+    fn encastv<T: Cast>(
+        &mut self,
+        len: usize,
+    ) -> Result<Vec<T>, ReadExactError<Self::Error>>;
+
+    ///
+    /// Encasts byte representations of type `T` read from `self` as
+    /// values of type `T`.  The number of values in the source bytes
+    /// is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Ok`]`(Vec<T>)`.  On failure, an error value is returned in
+    /// [`Err`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    #[cfg(feature = "alloc")]
+    fn encastvf<T: Cast + Flip>(
+        &mut self,
+        len: usize,
+        endian: Endian,
+    ) -> Result<Vec<T>, ReadExactError<Self::Error>>;
+}
+
+
+impl<R: ?Sized + Read> EmbeddedEncastIO for R {
+    #[inline]
+    fn encast<T: Cast>(&mut self) -> Result<T, ReadExactError<Self::Error>> {
+        let mut value = MaybeUninit::<T>::uninit();
+
+        unsafe {
+            // SAFETY: The following function call to `Read::read_exact`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            self.read_exact(value.asif_bytes_mut())?;
+
+            // SAFETY: The following method call to `MaybeUninit::assume_init`
+            // is safe because `value` has been initialized.
+            Ok(value.assume_init())
+        }
+    }
+
+    #[inline]
+    fn encastf<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> Result<T, ReadExactError<Self::Error>> {
+        let mut value = self.encast::<T>()?;
+
+        // Flips the endianness of the value in `value` if `endian` is
+        // not equivalent to the endianness of the target system.
+        value.flip_var(endian);
+
+        Ok(value)
+    }
+
+    #[inline]
+    fn encasts<T: Cast>(
+        &mut self,
+        slice: &mut [T],
+    ) -> Result<usize, ReadExactError<Self::Error>> {
+        unsafe {
+            // SAFETY: The following function call to `Read::read_exact`
+            // is safe because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            self.read_exact(slice.asif_bytes_mut())?;
+        }
+
+        Ok(mem::size_of_val(slice))
+    }
+
+    #[inline]
+    fn encastsf<T: Cast + Flip>(
+        &mut self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> Result<usize, ReadExactError<Self::Error>> {
+        let nbytes = self.encasts::<T>(slice)?;
+
+        if endian.need_flip() {
+            // The endianness must be flipped.
+            bulk_swap::flip_slice(slice, endian);
+        }
+
+        Ok(nbytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encastv<T: Cast>(
+        &mut self,
+        len: usize,
+    ) -> Result<Vec<T>, ReadExactError<Self::Error>> {
+        let mut vec: Vec<T> = Vec::new();
+
+        unsafe {
+            // SAFETY: The following method call to `PushBulk::push_bulk` is
+            // safe because the closure fills whole elements in `new_slice`.
+            vec.push_bulk(len, |new_slice| {
+                self.encasts(new_slice)
+            })?;
+        }
+
+        Ok(vec)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encastvf<T: Cast + Flip>(
+        &mut self,
+        len: usize,
+        endian: Endian,
+    ) -> Result<Vec<T>, ReadExactError<Self::Error>> {
+        let mut vec = self.encastv::<T>(len)?;
+
+        if endian.need_flip() {
+            // The endianness must be flipped.
+            bulk_swap::flip_slice(&mut vec, endian);
+        }
+
+        Ok(vec)
+    }
+}
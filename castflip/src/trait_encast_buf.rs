@@ -0,0 +1,168 @@
+//
+// This file defines trait `EncastBufRead`
+//
+
+use crate::{Cast, EncastIO, EncastMem, Endian, Flip};
+use core::mem;
+use std::io;
+
+
+///
+/// Provides methods that encast byte representations of a type read
+/// from the internal buffer of reader `self` using trait
+/// [`std::io::BufRead`] as values of the type with endianness
+/// handling.
+///
+/// This trait is blanketly implemented for those types that implement
+/// trait [`std::io::BufRead`].
+///
+/// Method [`EncastBufRead::encast_buf`] and method
+/// [`EncastBufRead::encastf_buf`] encast the source bytes directly
+/// from the internal buffer returned by method
+/// [`std::io::BufRead::fill_buf`] when the whole source bytes are
+/// buffered, then consume them.  Otherwise, i.e., when the source
+/// bytes straddle the boundary of the internal buffer, they fall back
+/// to method [`EncastIO::encast`] and method [`EncastIO::encastf`],
+/// respectively, which read the source bytes by method
+/// [`std::io::Read::read_exact`].
+///
+/// Method [`EncastBufRead::peek_encast`] and method
+/// [`EncastBufRead::peek_encastf`] encast the source bytes without
+/// consuming them.  They are useful to sniff magic numbers.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::BufReader;
+/// use castflip::{BE, EncastBufRead};
+///
+/// let bytes: &[u8] = &[0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x01];
+/// let mut input = BufReader::new(bytes);
+///
+/// // Sniff the magic number without consuming it.
+/// let magic = input.peek_encastf::<u32>(BE).unwrap();
+/// assert_eq!(magic, Some(0xCAFEBABE));
+///
+/// // Read it again, then the next value.
+/// assert_eq!(input.encastf_buf::<u32>(BE).unwrap(), 0xCAFEBABE);
+/// assert_eq!(input.encastf_buf::<u16>(BE).unwrap(), 0x0001);
+/// ```
+///
+pub trait EncastBufRead: EncastIO + io::BufRead {
+    ///
+    /// Encasts a byte representation of type `T` read from `self` as
+    /// a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Ok`]`(T)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    fn encast_buf<T: Cast>(&mut self) -> io::Result<T>;
+
+    ///
+    /// Encasts a byte representation of type `T` read from `self` as
+    /// a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Ok`]`(T)`.  On failure, an error value of struct
+    /// [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastf_buf<T: Cast + Flip>(&mut self, endian: Endian) -> io::Result<T>;
+
+    ///
+    /// Encasts a byte representation of type `T` in the internal
+    /// buffer of `self` as a value of type `T` without consuming it.
+    ///
+    /// If the whole source bytes are buffered, the resulting value is
+    /// returned in `Ok(Some(T))`.  Otherwise, `Ok(None)` is returned.
+    /// Note that method [`std::io::BufRead::fill_buf`] is called only
+    /// once unless it returns an error of kind
+    /// [`std::io::ErrorKind::Interrupted`].  On failure, an error
+    /// value of struct [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    fn peek_encast<T: Cast>(&mut self) -> io::Result<Option<T>>;
+
+    ///
+    /// Encasts a byte representation of type `T` in the internal
+    /// buffer of `self` as a value of type `T` without consuming it.
+    ///
+    /// If the whole source bytes are buffered, the resulting value is
+    /// returned in `Ok(Some(T))`.  Otherwise, `Ok(None)` is returned.
+    /// Note that method [`std::io::BufRead::fill_buf`] is called only
+    /// once unless it returns an error of kind
+    /// [`std::io::ErrorKind::Interrupted`].  On failure, an error
+    /// value of struct [`std::io::Error`] is returned in [`Err`].
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn peek_encastf<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> io::Result<Option<T>>;
+}
+
+
+impl<R: ?Sized + io::BufRead> EncastBufRead for R {
+    fn encast_buf<T: Cast>(&mut self) -> io::Result<T> {
+        match with_buf(self, |buf| buf.encast::<T>())? {
+            Some(value) => {
+                self.consume(mem::size_of::<T>());
+                Ok(value)
+            },
+            None => self.encast::<T>(),
+        }
+    }
+
+    fn encastf_buf<T: Cast + Flip>(&mut self, endian: Endian) -> io::Result<T> {
+        match with_buf(self, |buf| buf.encastf::<T>(endian))? {
+            Some(value) => {
+                self.consume(mem::size_of::<T>());
+                Ok(value)
+            },
+            None => self.encastf::<T>(endian),
+        }
+    }
+
+    #[inline]
+    fn peek_encast<T: Cast>(&mut self) -> io::Result<Option<T>> {
+        with_buf(self, |buf| buf.encast::<T>())
+    }
+
+    #[inline]
+    fn peek_encastf<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> io::Result<Option<T>> {
+        with_buf(self, |buf| buf.encastf::<T>(endian))
+    }
+}
+
+
+///
+/// Calls method `fill_buf` of `reader`, then returns the result of
+/// `f` called with the buffered bytes.  If method `fill_buf` returns
+/// an error of kind [`std::io::ErrorKind::Interrupted`], the call is
+/// retried.
+///
+fn with_buf<R, U>(reader: &mut R, f: impl FnOnce(&[u8]) -> U) -> io::Result<U>
+where
+    R: ?Sized + io::BufRead,
+{
+    loop {
+        match reader.fill_buf() {
+            Ok(buf) => return Ok(f(buf)),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {},
+            Err(e) => return Err(e),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//
+// This file defines trait `EncastDetect` and enum `DetectError`
+//
+
+use crate::{Cast, Endian, Flip};
+use core::{error, fmt};
+
+
+///
+/// Provides a function that encasts a byte representation of a type
+/// whose endianness is detected from its magic number.
+///
+/// This trait is implemented for a `struct` type by applying
+/// attribute `#[derive(EncastDetect)]` to the type definition
+/// together with attribute `#[castflip(magic(field = "NAME", value =
+/// VALUE))]`, where `NAME` is the name of the field holding the magic
+/// number and `VALUE` is the expected magic number.  The type must
+/// also implement trait [`Cast`] and trait [`Flip`].
+///
+/// Function [`EncastDetect::encast_detect`] reads the magic field in
+/// native-endian.  If it matches the expected magic number, the
+/// endianness is [`Endian::Native`].  If it matches the expected
+/// magic number with its endianness reversed, the endianness is
+/// [`Endian::Swapped`].  Otherwise, the bytes are rejected.  Then the
+/// whole value is encasted in the detected endianness.  Note that if
+/// the expected magic number reads the same in both endiannesses, the
+/// endianness is always detected as [`Endian::Native`].
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{Cast, DetectError, EncastDetect, Endian, Flip};
+///
+/// #[repr(C)]
+/// #[derive(Cast, EncastDetect, Flip)]
+/// #[castflip(magic(field = "magic", value = 0xCAFEBABE))]
+/// struct FatHeader {
+///     magic:      u32,
+///     nfat_arch:  u32,
+/// }
+///
+/// let mut bytes = [0_u8; 8];
+/// bytes[.. 4].copy_from_slice(&0xCAFEBABE_u32.to_be_bytes());
+/// bytes[4 ..].copy_from_slice(&2_u32.to_be_bytes());
+///
+/// let (hdr, endian) = FatHeader::encast_detect(&bytes).unwrap();
+/// assert_eq!(endian.absolute(), Endian::Big);
+/// assert_eq!(hdr.magic, 0xCAFEBABE);
+/// assert_eq!(hdr.nfat_arch, 2);
+///
+/// let result = FatHeader::encast_detect(&[0_u8; 8]);
+/// assert!(matches!(result, Err(DetectError::UnknownMagic)));
+/// ```
+///
+pub trait EncastDetect: Cast + Flip {
+    ///
+    /// Encasts a byte representation of this type at the head of
+    /// `bytes` as a value of this type in the endianness detected from
+    /// its magic number.
+    ///
+    /// If successful, the resulting value in native-endian and the
+    /// detected endianness ([`Endian::Native`] or [`Endian::Swapped`])
+    /// are returned in [`Ok`].  On failure, a value of enum
+    /// [`DetectError`] is returned in [`Err`].
+    ///
+    fn encast_detect(bytes: &[u8]) -> Result<(Self, Endian), DetectError>;
+}
+
+
+///
+/// Is the error returned when function [`EncastDetect::encast_detect`]
+/// fails.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DetectError {
+    ///
+    /// The bytes are shorter than the size of the type.
+    ///
+    Truncated,
+
+    ///
+    /// The magic number matches neither the expected magic number nor
+    /// the expected magic number with its endianness reversed.
+    ///
+    UnknownMagic,
+}
+
+impl fmt::Display for DetectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated     => f.write_str("bytes are too short"),
+            Self::UnknownMagic  => f.write_str("unknown magic number"),
+        }
+    }
+}
+
+impl error::Error for DetectError {}
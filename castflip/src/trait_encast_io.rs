@@ -3,14 +3,14 @@
 //
 
 use crate::{
-    Cast, Endian, Flip, include_doc,
+    Cast, EncastRecords, Endian, Flip, bulk_swap, include_doc,
     experimental::{AsifBytes, PushBulk},
 };
 use core::mem::{self, MaybeUninit};
 use std::io;
 
 #[cfg(doc)]
-use crate::BE;
+use crate::{BE, TruncatedRecord};
 
 
 #[doc = include_doc!("trait_encast_io.md")]
@@ -107,6 +107,27 @@ pub trait EncastIO {
         len: usize,
         endian: Endian,
     ) -> io::Result<Vec<T>>;
+
+    ///
+    /// Returns an iterator which encasts byte representations of type
+    /// `T` read from `self` using trait [`std::io::Read`] as values of
+    /// type `T` until the end of file.
+    ///
+    /// Each item of the iterator is a resulting value in [`Ok`]`(T)`
+    /// or an error value of struct [`std::io::Error`] in [`Err`].  If
+    /// `self` reaches the end of file at a record boundary, the
+    /// iteration ends.  If `self` reaches the end of file in the
+    /// middle of a record, an error wrapping a value of struct
+    /// [`TruncatedRecord`] is returned.  For details, see the document
+    /// of struct [`EncastRecords`].
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encast_records<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> EncastRecords<'_, Self, T>;
 }
 
 
@@ -158,12 +179,12 @@ impl<R: ?Sized + io::Read> EncastIO for R {
         slice: &mut [T],
         endian: Endian,
     ) -> io::Result<usize> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.encasts::<T>(slice)
         } else {
             // The endianness must be reversed.
-            self.encastsf_swapped(slice)
+            self.encastsf_swapped(slice, endian)
         }
     }
 
@@ -187,14 +208,22 @@ impl<R: ?Sized + io::Read> EncastIO for R {
         len: usize,
         endian: Endian,
     ) -> io::Result<Vec<T>> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.encastv::<T>(len)
         } else {
             // The endianness must be reversed.
-            self.encastvf_swapped(len)
+            self.encastvf_swapped(len, endian)
         }
     }
+
+    #[inline]
+    fn encast_records<T: Cast + Flip>(
+        &mut self,
+        endian: Endian,
+    ) -> EncastRecords<'_, Self, T> {
+        EncastRecords::new(self, endian)
+    }
 }
 
 
@@ -211,12 +240,13 @@ trait EncastIOInternal {
     /// On failure, an error value of struct [`std::io::Error`] is
     /// returned in [`Err`].
     ///
-    /// The endianness of each resulting value is the swapped
-    /// endianness of the corresponding source bytes.
+    /// The endianness of the source bytes is specified by `endian`,
+    /// which is not equivalent to the endianness of the target system.
     ///
     fn encastsf_swapped<T: Cast + Flip>(
         &mut self,
         slice: &mut [T],
+        endian: Endian,
     ) -> io::Result<usize>;
 
     ///
@@ -228,12 +258,13 @@ trait EncastIOInternal {
     /// [`Ok`]`(Vec<T>)`.  On failure, an error value of struct
     /// [`std::io::Error`] is returned in [`Err`].
     ///
-    /// The endianness of each resulting value is the swapped
-    /// endianness of the corresponding source bytes.
+    /// The endianness of the source bytes is specified by `endian`,
+    /// which is not equivalent to the endianness of the target system.
     ///
     fn encastvf_swapped<T: Cast + Flip>(
         &mut self,
         len: usize,
+        endian: Endian,
     ) -> io::Result<Vec<T>>;
 }
 
@@ -241,14 +272,13 @@ impl<R: ?Sized + io::Read> EncastIOInternal for R {
     fn encastsf_swapped<T: Cast + Flip>(
         &mut self,
         slice: &mut [T],
+        endian: Endian,
     ) -> io::Result<usize> {
-        let nbytes = mem::size_of_val(slice);
-
-        // Read values from `self`, reverse their endiannesses, then
-        // save the resulting values to `elem` in `slice`.
-        for elem in slice {
-            *elem = self.encast::<T>()?.flip_val_swapped();
-        }
+        // Read byte representations from `self` into `slice`, then
+        // reverse the endiannesses of the resulting values in bulk if
+        // possible.
+        let nbytes = self.encasts::<T>(slice)?;
+        bulk_swap::flip_slice(slice, endian);
 
         Ok(nbytes)
     }
@@ -256,6 +286,7 @@ impl<R: ?Sized + io::Read> EncastIOInternal for R {
     fn encastvf_swapped<T: Cast + Flip>(
         &mut self,
         len: usize,
+        endian: Endian,
     ) -> io::Result<Vec<T>> {
         let mut vec: Vec<T> = Vec::new();
 
@@ -263,7 +294,7 @@ impl<R: ?Sized + io::Read> EncastIOInternal for R {
             // SAFETY: The following method call to `PushBulk::push_bulk` is
             // safe because the closure fills whole elements in `new_slice`.
             vec.push_bulk(len, |new_slice| {
-                self.encastsf_swapped(new_slice)
+                self.encastsf_swapped(new_slice, endian)
             })?;
         }
 
@@ -2,7 +2,7 @@
 // This file defines trait `EncastMem`
 //
 
-use crate::{Cast, Endian, Flip, include_doc};
+use crate::{Cast, Endian, Flip, bulk_swap, include_doc};
 use core::{mem, ptr};
 
 #[cfg(doc)]
@@ -161,12 +161,12 @@ impl EncastMem for [u8] {
         slice: &mut [T],
         endian: Endian,
     ) -> Option<usize> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.encasts::<T>(slice)
         } else {
             // The endianness must be reversed.
-            self.encastsf_swapped(slice)
+            self.encastsf_swapped(slice, endian)
         }
     }
 
@@ -192,12 +192,12 @@ impl EncastMem for [u8] {
         len: usize,
         endian: Endian,
     ) -> Option<Vec<T>> {
-        if !endian.need_swap() {
+        if !endian.need_flip() {
             // The endianness must not be reversed.
             self.encastv::<T>(len)
         } else {
             // The endianness must be reversed.
-            self.encastvf_swapped(len)
+            self.encastvf_swapped(len, endian)
         }
     }
 }
@@ -214,12 +214,13 @@ trait EncastMemInternal {
     /// If successful, the resulting value is returned in [`Some`]`(T)`.
     /// On failure, [`None`] is returned.
     ///
-    /// The endianness of each resulting value is the swapped
-    /// endianness of the corresponding source bytes.
+    /// The endianness of the source bytes is specified by `endian`,
+    /// which is not equivalent to the endianness of the target system.
     ///
     fn encastsf_swapped<T: Cast + Flip>(
         &self,
         slice: &mut [T],
+        endian: Endian,
     ) -> Option<usize>;
 
     ///
@@ -230,42 +231,45 @@ trait EncastMemInternal {
     /// If successful, the resulting values are returned in
     /// [`Some`]`(Vec<T>)`.  On failure, [`None`] is returned.
     ///
-    /// The endianness of each resulting value is the swapped
-    /// endianness of the corresponding source bytes.
+    /// The endianness of the source bytes is specified by `endian`,
+    /// which is not equivalent to the endianness of the target system.
     ///
-    fn encastvf_swapped<T: Cast + Flip>(&self, len: usize) -> Option<Vec<T>>;
+    #[cfg(feature = "alloc")]
+    fn encastvf_swapped<T: Cast + Flip>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>>;
 }
 
 impl EncastMemInternal for [u8] {
     fn encastsf_swapped<T: Cast + Flip>(
         &self,
         slice: &mut [T],
+        endian: Endian,
     ) -> Option<usize> {
-        if self.len() >= mem::size_of_val(slice) {
-            let mut off = 0;
-
-            // Read byte representations from `self`, reverse their
-            // endiannesses, then save the resulting values to `elem`
-            // in `slice`.
-            for elem in slice {
-                *elem = self[off ..].encast::<T>()?.flip_val_swapped();
-                off += mem::size_of_val(elem);
-            }
+        // Read byte representations from `self` into `slice`, then
+        // reverse the endiannesses of the resulting values in bulk if
+        // possible.
+        let nbytes = self.encasts::<T>(slice)?;
+        bulk_swap::flip_slice(slice, endian);
 
-            Some(off)
-        } else {
-            None
-        }
+        Some(nbytes)
     }
 
-    fn encastvf_swapped<T: Cast + Flip>(&self, len: usize) -> Option<Vec<T>> {
+    #[cfg(feature = "alloc")]
+    fn encastvf_swapped<T: Cast + Flip>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>> {
         let mut vec: Vec<T> = Vec::new();
 
         unsafe {
             // SAFETY: The following method call to `PushBulk::push_bulk` is
             // safe because the closure fills whole elements in `new_slice`.
             vec.push_bulk(len, |new_slice| {
-                self.encastsf_swapped(new_slice).ok_or(())
+                self.encastsf_swapped(new_slice, endian).ok_or(())
             }).ok()?;
         }
 
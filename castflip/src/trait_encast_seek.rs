@@ -0,0 +1,288 @@
+//
+// This file defines trait `EncastSeek` and struct `OffsetError`
+//
+
+use crate::{Cast, EncastIO, Endian, Flip};
+use core::fmt;
+use std::{
+    error,
+    io::{self, SeekFrom},
+};
+
+
+///
+/// Provides methods that encast byte representations of a type read
+/// at the specified offset from reader `self` using trait
+/// [`std::io::Read`] and trait [`std::io::Seek`] as values of the type
+/// with endianness handling.
+///
+/// This trait is blanketly implemented for those types that implement
+/// both trait [`std::io::Read`] and trait [`std::io::Seek`].  It is
+/// designed for offset-based file formats such as ELF, PE, TIFF and
+/// ZIP.
+///
+/// Each method seeks to the offset specified by parameter `offset`
+/// from the start of `self`, then calls the method of trait
+/// [`EncastIO`] whose name is the same without suffix `_at`.  After
+/// the method returns successfully, the position of `self` is just
+/// after the source bytes.  If the seek or the read fails, an error
+/// value of struct [`std::io::Error`] is returned in [`Err`].  Its
+/// kind is the same as the kind of the original error, and its inner
+/// error is a value of struct [`OffsetError`], which carries the
+/// offset.
+///
+/// Method [`EncastSeek::with_position`] calls the specified closure
+/// and then restores the position of `self`.
+///
+/// # Example
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use castflip::{BE, EncastSeek};
+///
+/// let bytes: [u8; 8] = [0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x12, 0x34];
+/// let mut input = Cursor::new(bytes);
+///
+/// let value = input.with_position(|input| {
+///     let offset = input.encastf_at::<u32>(0, BE)?;
+///     input.encastf_at::<u16>(offset as u64, BE)
+/// }).unwrap();
+///
+/// assert_eq!(value, 0x1234);
+/// assert_eq!(input.position(), 0); // The position is restored.
+/// ```
+///
+pub trait EncastSeek: EncastIO + io::Seek {
+    ///
+    /// Encasts a byte representation of type `T` read at `offset`
+    /// from `self` as a value of type `T`.
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    fn encast_at<T: Cast>(&mut self, offset: u64) -> io::Result<T>;
+
+    ///
+    /// Encasts a byte representation of type `T` read at `offset`
+    /// from `self` as a value of type `T`.
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastf_at<T: Cast + Flip>(
+        &mut self,
+        offset: u64,
+        endian: Endian,
+    ) -> io::Result<T>;
+
+    ///
+    /// Encasts byte representations of type `T` read at `offset` from
+    /// `self` as values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Ok`]`(usize)`.  The endianness of each resulting value is the
+    /// same as the endianness of the corresponding source bytes.
+    ///
+    fn encasts_at<T: Cast>(
+        &mut self,
+        offset: u64,
+        slice: &mut [T],
+    ) -> io::Result<usize>;
+
+    ///
+    /// Encasts byte representations of type `T` read at `offset` from
+    /// `self` as values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Ok`]`(usize)`.  The resulting values are in native-endian.
+    /// The endianness of the source bytes is specified by `endian`.
+    ///
+    fn encastsf_at<T: Cast + Flip>(
+        &mut self,
+        offset: u64,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> io::Result<usize>;
+
+    ///
+    /// Encasts byte representations of type `T` read at `offset` from
+    /// `self` as values of type `T`.  The number of values in the
+    /// source bytes is specified by `len`.
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    fn encastv_at<T: Cast>(
+        &mut self,
+        offset: u64,
+        len: usize,
+    ) -> io::Result<Vec<T>>;
+
+    ///
+    /// Encasts byte representations of type `T` read at `offset` from
+    /// `self` as values of type `T`.  The number of values in the
+    /// source bytes is specified by `len`.
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastvf_at<T: Cast + Flip>(
+        &mut self,
+        offset: u64,
+        len: usize,
+        endian: Endian,
+    ) -> io::Result<Vec<T>>;
+
+    ///
+    /// Calls closure `f` with `self`, then restores the position of
+    /// `self` to the position before the call.
+    ///
+    /// The position is restored even if closure `f` returns an error.
+    /// If closure `f` succeeds, its result is returned.  Otherwise,
+    /// its error is returned.  If the position cannot be restored, the
+    /// error of the seek is returned.
+    ///
+    fn with_position<U, F>(&mut self, f: F) -> io::Result<U>
+    where
+        F: FnOnce(&mut Self) -> io::Result<U>;
+}
+
+
+impl<R: ?Sized + io::Read + io::Seek> EncastSeek for R {
+    #[inline]
+    fn encast_at<T: Cast>(&mut self, offset: u64) -> io::Result<T> {
+        at_offset(self, offset, |reader| reader.encast::<T>())
+    }
+
+    #[inline]
+    fn encastf_at<T: Cast + Flip>(
+        &mut self,
+        offset: u64,
+        endian: Endian,
+    ) -> io::Result<T> {
+        at_offset(self, offset, |reader| reader.encastf::<T>(endian))
+    }
+
+    #[inline]
+    fn encasts_at<T: Cast>(
+        &mut self,
+        offset: u64,
+        slice: &mut [T],
+    ) -> io::Result<usize> {
+        at_offset(self, offset, |reader| reader.encasts::<T>(slice))
+    }
+
+    #[inline]
+    fn encastsf_at<T: Cast + Flip>(
+        &mut self,
+        offset: u64,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> io::Result<usize> {
+        at_offset(self, offset, |reader| reader.encastsf::<T>(slice, endian))
+    }
+
+    #[inline]
+    fn encastv_at<T: Cast>(
+        &mut self,
+        offset: u64,
+        len: usize,
+    ) -> io::Result<Vec<T>> {
+        at_offset(self, offset, |reader| reader.encastv::<T>(len))
+    }
+
+    #[inline]
+    fn encastvf_at<T: Cast + Flip>(
+        &mut self,
+        offset: u64,
+        len: usize,
+        endian: Endian,
+    ) -> io::Result<Vec<T>> {
+        at_offset(self, offset, |reader| reader.encastvf::<T>(len, endian))
+    }
+
+    fn with_position<U, F>(&mut self, f: F) -> io::Result<U>
+    where
+        F: FnOnce(&mut Self) -> io::Result<U>,
+    {
+        let pos = self.stream_position()?;
+        let result = f(self);
+        self.seek(SeekFrom::Start(pos))?;
+        result
+    }
+}
+
+
+///
+/// Seeks to `offset` from the start of `reader`, then calls closure
+/// `f`.  If either fails, the error is wrapped with the offset.
+///
+fn at_offset<R, U, F>(reader: &mut R, offset: u64, f: F) -> io::Result<U>
+where
+    R: ?Sized + io::Read + io::Seek,
+    F: FnOnce(&mut R) -> io::Result<U>,
+{
+    reader.seek(SeekFrom::Start(offset))
+        .and_then(|_| f(reader))
+        .map_err(|error| OffsetError::wrap(offset, error))
+}
+
+
+///
+/// Is the error returned when the methods of trait [`EncastSeek`]
+/// fail to seek to or read at the specified offset.
+///
+/// It is wrapped in an error value of struct [`std::io::Error`] whose
+/// kind is the same as the kind of the original error.  It can be
+/// retrieved by function [`OffsetError::from_io_error`].  The original
+/// error can be retrieved by method [`std::error::Error::source`].
+///
+#[derive(Debug)]
+pub struct OffsetError {
+    offset: u64,
+    error: io::Error,
+}
+
+impl OffsetError {
+    ///
+    /// Wraps `error` in a new `OffsetError` with `offset`, then wraps
+    /// it in a new error value of struct [`std::io::Error`].
+    ///
+    fn wrap(offset: u64, error: io::Error) -> io::Error {
+        io::Error::new(error.kind(), Self { offset, error })
+    }
+
+    ///
+    /// Returns the offset at which the seek or the read failed.
+    ///
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    ///
+    /// Returns a reference to the original error.
+    ///
+    pub fn error(&self) -> &io::Error {
+        &self.error
+    }
+
+    ///
+    /// Returns a reference to the `OffsetError` wrapped in `err` in
+    /// [`Some`].  If `err` does not wrap it, [`None`] is returned.
+    ///
+    pub fn from_io_error(err: &io::Error) -> Option<&Self> {
+        err.get_ref()?.downcast_ref::<Self>()
+    }
+}
+
+impl fmt::Display for OffsetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to read at offset {:#x}: {}", self.offset, self.error)
+    }
+}
+
+impl error::Error for OffsetError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
@@ -0,0 +1,304 @@
+//
+// This file defines trait `EncastSplit`
+//
+
+use crate::{Cast, EncastMem, Endian, Flip, bulk_swap, experimental::AsifBytes};
+use core::mem::{self, MaybeUninit};
+
+#[cfg(feature = "alloc")]
+use crate::experimental::PushBulk;
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+
+
+///
+/// Provides methods that encast byte representations of a type at the
+/// head of a byte sequence split into two parts as values of the type
+/// with endianness handling.
+///
+/// This trait is implemented for `(&[u8], &[u8])`, which is a byte
+/// sequence consisting of the first part and the second part, such as
+/// the one returned by method `VecDeque::as_slices`.  When feature
+/// `alloc` is enabled, this trait is also implemented for
+/// `VecDeque<u8>`, whose contents may wrap around its ring buffer.
+///
+/// The methods of this trait are the counterparts of the methods of
+/// trait [`EncastMem`] with the same names.  A value is encasted
+/// directly from the first part or the second part if its source
+/// bytes are contained in either part.  Only when its source bytes
+/// straddle the boundary between the two parts, they are stitched
+/// into the resulting value.  The source bytes are not consumed.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::VecDeque;
+/// use castflip::{BE, EncastSplit};
+///
+/// // Make a ring buffer whose contents wrap around.
+/// let mut deque = VecDeque::with_capacity(4);
+/// deque.extend([0xFF, 0xFF, 0xFF, 0x12]);
+/// deque.drain(.. 3);
+/// deque.extend([0x34, 0x56, 0x78]);
+///
+/// let value: u32 = deque.encastf(BE).unwrap();
+/// assert_eq!(value, 0x12345678);
+///
+/// let (first, second) = deque.as_slices();
+/// let value: u16 = (&first[1 ..], second).encastf(BE).unwrap();
+/// assert_eq!(value, 0x3456);
+/// ```
+///
+pub trait EncastSplit {
+    ///
+    /// Encasts a byte representation of type `T` at the head of `self`
+    /// as a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Some`]`(T)`.  On failure, [`None`] is returned.
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    fn encast<T: Cast>(&self) -> Option<T>;
+
+    ///
+    /// Encasts a byte representation of type `T` at the head of `self`
+    /// as a value of type `T`.
+    ///
+    /// If successful, the resulting value is returned in
+    /// [`Some`]`(T)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastf<T: Cast + Flip>(&self, endian: Endian) -> Option<T>;
+
+    ///
+    /// Encasts byte representations of type `T` at the head of `self`
+    /// as values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    fn encasts<T: Cast>(&self, slice: &mut [T]) -> Option<usize>;
+
+    ///
+    /// Encasts byte representations of type `T` at the head of `self`
+    /// as values of type `T` and saves them in `slice`.
+    ///
+    /// If successful, the number of the source bytes is returned in
+    /// [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn encastsf<T: Cast + Flip>(
+        &self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> Option<usize>;
+
+    ///
+    /// Encasts byte representations of type `T` at the head of `self`
+    /// as values of type `T`.  The number of values in the source
+    /// bytes is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Some`]`(Vec<T>)`.  On failure, [`None`] is returned.
+    ///
+    /// The endianness of each resulting value is the same as the
+    /// endianness of the corresponding source bytes.
+    ///
+    #[cfg(feature = "alloc")]
+    fn encastv<T: Cast>(&self, len: usize) -> Option<Vec<T>>;
+
+    ///
+    /// Encasts byte representations of type `T` at the head of `self`
+    /// as values of type `T`.  The number of values in the source
+    /// bytes is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Some`]`(Vec<T>)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    #[cfg(feature = "alloc")]
+    fn encastvf<T: Cast + Flip>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>>;
+}
+
+
+impl EncastSplit for (&[u8], &[u8]) {
+    #[inline]
+    fn encast<T: Cast>(&self) -> Option<T> {
+        let (first, second) = *self;
+        let size = mem::size_of::<T>();
+
+        if first.len() >= size {
+            // The source bytes are contained in the first part.
+            first.encast::<T>()
+        } else if first.is_empty() {
+            // The source bytes are contained in the second part.
+            second.encast::<T>()
+        } else {
+            // The source bytes straddle the boundary.
+            let mut value = MaybeUninit::<T>::uninit();
+
+            unsafe {
+                // SAFETY: The following function call to `copy_split` is
+                // safe because those types that implement trait Cast can be
+                // duplicated simply by copying bits by the definition of
+                // trait Cast.
+                copy_split(first, second, value.asif_bytes_mut())?;
+
+                // SAFETY: The following method call to
+                // `MaybeUninit::assume_init` is safe because `value` has
+                // been initialized.
+                Some(value.assume_init())
+            }
+        }
+    }
+
+    #[inline]
+    fn encastf<T: Cast + Flip>(&self, endian: Endian) -> Option<T> {
+        let mut value = self.encast::<T>()?;
+
+        // Flips the endianness of the value in `value` if `endian` is
+        // not equivalent to the endianness of the target system.
+        value.flip_var(endian);
+
+        Some(value)
+    }
+
+    #[inline]
+    fn encasts<T: Cast>(&self, slice: &mut [T]) -> Option<usize> {
+        let (first, second) = *self;
+
+        unsafe {
+            // SAFETY: The following function call to `copy_split` is safe
+            // because those types that implement trait Cast can be
+            // duplicated simply by copying bits by the definition of trait
+            // Cast.
+            copy_split(first, second, slice.asif_bytes_mut())?;
+        }
+
+        Some(mem::size_of_val(slice))
+    }
+
+    #[inline]
+    fn encastsf<T: Cast + Flip>(
+        &self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> Option<usize> {
+        let nbytes = self.encasts::<T>(slice)?;
+
+        if endian.need_flip() {
+            // The endianness must be flipped.
+            bulk_swap::flip_slice(slice, endian);
+        }
+
+        Some(nbytes)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn encastv<T: Cast>(&self, len: usize) -> Option<Vec<T>> {
+        let mut vec: Vec<T> = Vec::new();
+
+        unsafe {
+            // SAFETY: The following method call to `PushBulk::push_bulk` is
+            // safe because the closure fills whole elements in `new_slice`.
+            vec.push_bulk(len, |new_slice| {
+                self.encasts(new_slice).ok_or(())
+            }).ok()?;
+        }
+
+        Some(vec)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encastvf<T: Cast + Flip>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>> {
+        let mut vec = self.encastv::<T>(len)?;
+
+        if endian.need_flip() {
+            // The endianness must be flipped.
+            bulk_swap::flip_slice(&mut vec, endian);
+        }
+
+        Some(vec)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl EncastSplit for VecDeque<u8> {
+    #[inline]
+    fn encast<T: Cast>(&self) -> Option<T> {
+        self.as_slices().encast::<T>()
+    }
+
+    #[inline]
+    fn encastf<T: Cast + Flip>(&self, endian: Endian) -> Option<T> {
+        self.as_slices().encastf::<T>(endian)
+    }
+
+    #[inline]
+    fn encasts<T: Cast>(&self, slice: &mut [T]) -> Option<usize> {
+        self.as_slices().encasts::<T>(slice)
+    }
+
+    #[inline]
+    fn encastsf<T: Cast + Flip>(
+        &self,
+        slice: &mut [T],
+        endian: Endian,
+    ) -> Option<usize> {
+        self.as_slices().encastsf::<T>(slice, endian)
+    }
+
+    #[inline]
+    fn encastv<T: Cast>(&self, len: usize) -> Option<Vec<T>> {
+        self.as_slices().encastv::<T>(len)
+    }
+
+    #[inline]
+    fn encastvf<T: Cast + Flip>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>> {
+        self.as_slices().encastvf::<T>(len, endian)
+    }
+}
+
+
+///
+/// Fills `dest` with the bytes at the head of the byte sequence
+/// consisting of `first` and `second`.  If the byte sequence is
+/// shorter than `dest`, [`None`] is returned.
+///
+fn copy_split(first: &[u8], second: &[u8], dest: &mut [u8]) -> Option<()> {
+    if first.len() + second.len() < dest.len() {
+        return None;
+    }
+
+    let len = first.len().min(dest.len());
+    let (dest_first, dest_second) = dest.split_at_mut(len);
+
+    dest_first.copy_from_slice(&first[.. len]);
+    dest_second.copy_from_slice(&second[.. dest_second.len()]);
+
+    Some(())
+}
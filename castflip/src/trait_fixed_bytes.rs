@@ -0,0 +1,172 @@
+//
+// This file defines trait `FixedBytes`
+//
+
+use crate::{Cast, Endian, Flip};
+use core::mem;
+
+#[cfg(doc)]
+use crate::{DecastMem, EncastMem};
+
+
+///
+/// Provides methods that convert a value of a type to and from a byte
+/// array of the size of the type with endianness handling.
+///
+/// Unlike the methods of traits [`EncastMem`] and [`DecastMem`], the
+/// methods of this trait need neither a pre-allocated buffer nor
+/// [`Option`] because the size of the byte representation is known
+/// statically.
+///
+/// This trait is implemented for the primitive numeric types.  It can
+/// be implemented for a `struct` type or a `union` type by applying
+/// attribute `#[derive(FixedBytes)]` to the type definition together
+/// with attribute `#[derive(Cast)]`.  Because the length of an array
+/// cannot be computed from a generic type on stable Rust, this trait
+/// is not implemented for arrays.  Wrap an array in a `struct` type
+/// instead.
+///
+/// When this trait is implemented manually, constant `SIZE` must be
+/// the size of the type and associated type `Bytes` must be
+/// `[u8; SIZE]`.  Otherwise, the methods of this trait fail to compile.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, Cast, FixedBytes, Flip, LE};
+///
+/// #[repr(C)]
+/// #[derive(Cast, Clone, Copy, Debug, FixedBytes, Flip, PartialEq)]
+/// struct Message {
+///     kind:   u16,
+///     len:    u16,
+///     id:     u32,
+/// }
+///
+/// let msg = Message { kind: 1, len: 4, id: 0x12345678 };
+///
+/// let bytes: [u8; Message::SIZE] = msg.to_bytes_f(BE);
+/// assert_eq!(bytes, [0x00, 0x01, 0x00, 0x04, 0x12, 0x34, 0x56, 0x78]);
+///
+/// assert_eq!(Message::from_bytes_f(&bytes, BE), msg);
+/// assert_eq!(u32::from_bytes_f(&[0x78, 0x56, 0x34, 0x12], LE), 0x12345678);
+/// ```
+///
+pub trait FixedBytes: Cast {
+    ///
+    /// Is the number of bytes in the byte representation of this
+    /// type.
+    ///
+    const SIZE: usize;
+
+    ///
+    /// Is the type of the byte representation of this type, which is
+    /// `[u8; SIZE]`.
+    ///
+    type Bytes: Cast + Copy + AsRef<[u8]> + AsMut<[u8]>;
+
+    ///
+    /// Decasts the value of `self` as a byte representation of this
+    /// type, then returns the resulting bytes.
+    ///
+    /// The endianness of the resulting bytes is the same as the
+    /// endianness of the value of `self`.
+    ///
+    #[inline]
+    fn to_bytes(&self) -> Self::Bytes {
+        assert_fixed_size::<Self>();
+
+        unsafe {
+            // SAFETY: The following function call to `mem::transmute_copy`
+            // is safe because the sizes of both types are the same and
+            // those types that implement trait Cast can be duplicated
+            // simply by copying bits by the definition of trait Cast.
+            mem::transmute_copy::<Self, Self::Bytes>(self)
+        }
+    }
+
+    ///
+    /// Decasts the value of `self` as a byte representation of this
+    /// type, then returns the resulting bytes.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// value of `self` is in native-endian.
+    ///
+    #[inline]
+    fn to_bytes_f(&self, endian: Endian) -> Self::Bytes
+    where
+        Self: Flip,
+    {
+        if !endian.need_flip() {
+            self.to_bytes()
+        } else {
+            self.flip_val(endian).to_bytes()
+        }
+    }
+
+    ///
+    /// Encasts the byte representation of this type in `bytes` as a
+    /// value of this type, then returns the resulting value.
+    ///
+    /// The endianness of the resulting value is the same as the
+    /// endianness of the source bytes.
+    ///
+    #[inline]
+    fn from_bytes(bytes: &Self::Bytes) -> Self {
+        assert_fixed_size::<Self>();
+
+        unsafe {
+            // SAFETY: The following function call to `mem::transmute_copy`
+            // is safe because the sizes of both types are the same and
+            // those types that implement trait Cast can be duplicated
+            // simply by copying bits by the definition of trait Cast.
+            mem::transmute_copy::<Self::Bytes, Self>(bytes)
+        }
+    }
+
+    ///
+    /// Encasts the byte representation of this type in `bytes` as a
+    /// value of this type, then returns the resulting value.
+    ///
+    /// The resulting value is in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    #[inline]
+    fn from_bytes_f(bytes: &Self::Bytes, endian: Endian) -> Self
+    where
+        Self: Flip,
+    {
+        let mut value = Self::from_bytes(bytes);
+        value.flip_var(endian);
+        value
+    }
+}
+
+
+macro_rules! impl_fixed_bytes {
+    ( $( $ty:ty ),* ) => { $(
+        impl FixedBytes for $ty {
+            const SIZE: usize = mem::size_of::<$ty>();
+            type Bytes = [u8; mem::size_of::<$ty>()];
+        }
+    )* }
+}
+
+impl_fixed_bytes!(i8, i16, i32, i64, i128, isize);
+impl_fixed_bytes!(u8, u16, u32, u64, u128, usize);
+impl_fixed_bytes!(f32, f64);
+
+
+///
+/// Makes sure at compile time that both `T::SIZE` and the size of
+/// `T::Bytes` are the size of type `T`.
+///
+#[inline(always)]
+fn assert_fixed_size<T: FixedBytes>() {
+    const {
+        assert!(T::SIZE == mem::size_of::<T>(),
+                "FixedBytes::SIZE must be the size of the type");
+        assert!(mem::size_of::<T::Bytes>() == mem::size_of::<T>(),
+                "FixedBytes::Bytes must be [u8; SIZE]");
+    }
+}
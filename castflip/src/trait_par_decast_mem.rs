@@ -0,0 +1,89 @@
+//
+// This file defines trait `ParDecastMem`
+//
+
+use crate::{
+    Cast, DecastMem, Endian, Flip,
+    trait_par_flip::par_chunk_len,
+};
+use core::mem;
+use rayon::prelude::*;
+
+
+///
+/// Provides methods that decast values of a type in a slice as byte
+/// representations of the type in parallel using crate [`rayon`], and
+/// save the resulting byte representations at the head of a byte
+/// slice.
+///
+/// This trait is implemented for `[u8]`.  It is enabled by crate
+/// feature `rayon`.
+///
+/// The methods of this trait work in the same way as the
+/// corresponding methods of trait [`DecastMem`] except that the
+/// source values are split into chunks of about [`PAR_CHUNK_SIZE`]
+/// bytes, and the chunks are processed on the thread pool of crate
+/// [`rayon`].  If the source values are smaller than
+/// [`PAR_THRESHOLD`] bytes, the methods of trait [`DecastMem`] are
+/// called on the current thread.
+///
+/// [`PAR_CHUNK_SIZE`]: crate::PAR_CHUNK_SIZE
+/// [`PAR_THRESHOLD`]: crate::PAR_THRESHOLD
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, ParDecastMem};
+///
+/// let vec1 = vec![0x1234_u16; 0x10000];
+/// let mut bytes2 = vec![0_u8; 0x20000];
+/// let size = bytes2.par_decastvf(&vec1, BE).unwrap();
+/// assert_eq!(size, 0x20000);
+/// assert_eq!(bytes2, [0x12_u8, 0x34].repeat(0x10000));
+/// ```
+///
+pub trait ParDecastMem {
+    ///
+    /// Decasts values of type `T` in `slice` as byte representations
+    /// of type `T` in parallel.
+    ///
+    /// If successful, the resulting bytes are saved to the head of
+    /// `self` and the number of the bytes is returned in
+    /// [`Some`]`(usize)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting bytes are in `endian` on the assumption that the
+    /// values in `slice` are in native-endian.
+    ///
+    fn par_decastvf<T: Cast + Flip + Sync>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> Option<usize>;
+}
+
+
+impl ParDecastMem for [u8] {
+    fn par_decastvf<T: Cast + Flip + Sync>(
+        &mut self,
+        slice: &[T],
+        endian: Endian,
+    ) -> Option<usize> {
+        let nelem = match par_chunk_len::<T>(slice.len()) {
+            Some(nelem) => nelem,
+            None => return self.decastsf::<T>(slice, endian),
+        };
+        let nbytes = mem::size_of_val(slice);
+
+        if self.len() < nbytes {
+            return None;
+        }
+
+        self[.. nbytes].par_chunks_mut(nelem * mem::size_of::<T>())
+            .zip(slice.par_chunks(nelem))
+            .try_for_each(|(dst, src)| {
+                dst.decastsf::<T>(src, endian).map(|_| ())
+            })?;
+
+        Some(nbytes)
+    }
+}
@@ -0,0 +1,96 @@
+//
+// This file defines trait `ParEncastMem`
+//
+
+use crate::{
+    Cast, EncastMem, Endian, Flip,
+    experimental::PushBulk,
+    trait_par_flip::par_chunk_len,
+};
+use core::mem;
+use rayon::prelude::*;
+
+
+///
+/// Provides methods that encast byte representations of a type at the
+/// head of a byte slice as values of the type in parallel using crate
+/// [`rayon`].
+///
+/// This trait is implemented for `[u8]`.  It is enabled by crate
+/// feature `rayon`.
+///
+/// The methods of this trait work in the same way as the
+/// corresponding methods of trait [`EncastMem`] except that the
+/// source bytes are split at element boundaries into chunks of about
+/// [`PAR_CHUNK_SIZE`] bytes, and the chunks are processed on the
+/// thread pool of crate [`rayon`].  If the source bytes are smaller
+/// than [`PAR_THRESHOLD`] bytes, the methods of trait [`EncastMem`]
+/// are called on the current thread.
+///
+/// [`PAR_CHUNK_SIZE`]: crate::PAR_CHUNK_SIZE
+/// [`PAR_THRESHOLD`]: crate::PAR_THRESHOLD
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{BE, ParEncastMem};
+///
+/// let bytes1 = vec![0x12_u8, 0x34].repeat(0x10000);
+/// let vec2 = bytes1.par_encastvf::<u16>(0x10000, BE).unwrap();
+/// assert!(vec2.iter().all(|&val| val == 0x1234));
+/// ```
+///
+pub trait ParEncastMem {
+    ///
+    /// Encasts a byte representation of type `T` at the head of `self`
+    /// as values of type `T` in parallel.  The number of values in the
+    /// source bytes is specified by `len`.
+    ///
+    /// If successful, the resulting values are returned in
+    /// [`Some`]`(Vec<T>)`.  On failure, [`None`] is returned.
+    ///
+    /// The resulting values are in native-endian.  The endianness of
+    /// the source bytes is specified by `endian`.
+    ///
+    fn par_encastvf<T: Cast + Flip + Send>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>>;
+}
+
+
+impl ParEncastMem for [u8] {
+    fn par_encastvf<T: Cast + Flip + Send>(
+        &self,
+        len: usize,
+        endian: Endian,
+    ) -> Option<Vec<T>> {
+        let nelem = match par_chunk_len::<T>(len) {
+            Some(nelem) => nelem,
+            None => return self.encastvf::<T>(len, endian),
+        };
+        let elem_size = mem::size_of::<T>();
+
+        if self.len() < len.checked_mul(elem_size)? {
+            return None;
+        }
+
+        let mut vec: Vec<T> = Vec::new();
+
+        unsafe {
+            // SAFETY: The following method call to `PushBulk::push_bulk` is
+            // safe because the closure fills whole elements in `new_slice`.
+            vec.push_bulk(len, |new_slice| {
+                new_slice.par_chunks_mut(nelem)
+                    .zip(self.par_chunks(nelem * elem_size))
+                    .try_for_each(|(dst, src)| {
+                        src.encastsf::<T>(dst, endian).map(|_| ())
+                    })
+                    .ok_or(())
+            }).ok()?;
+        }
+
+        Some(vec)
+    }
+}
@@ -0,0 +1,113 @@
+//
+// This file defines trait `ParFlip`
+//
+
+use crate::{Endian, Flip};
+use core::mem;
+use rayon::prelude::*;
+
+
+///
+/// Provides methods that flip the endiannesses of the values in a
+/// slice in parallel using crate [`rayon`].
+///
+/// This trait is implemented for `[T]` where `T` implements trait
+/// [`Flip`] and trait [`Send`].  It is enabled by crate feature
+/// `rayon`.
+///
+/// The slice is split at element boundaries into chunks of about
+/// [`PAR_CHUNK_SIZE`] bytes, and the chunks are processed on the
+/// thread pool of crate [`rayon`].  If the slice is smaller than
+/// [`PAR_THRESHOLD`] bytes, the values are flipped on the current
+/// thread as trait [`Flip`] does.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::{ParFlip, SE};
+///
+/// let mut vec1 = vec![0x1234_u16; 0x10000];
+/// vec1.par_flip_var(SE);  // SE = Swapped-Endian
+/// assert!(vec1.iter().all(|&val| val == 0x3412));
+/// ```
+///
+pub trait ParFlip {
+    ///
+    /// Reverses the endiannesses of the values in `self` in parallel.
+    ///
+    fn par_flip_var_swapped(&mut self);
+
+    ///
+    /// Flips the endiannesses of the values in `self` in parallel if
+    /// `endian` is not equivalent to the endianness of the target
+    /// system.
+    ///
+    #[inline]
+    fn par_flip_var(&mut self, endian: Endian) {
+        if endian.need_swap() {
+            self.par_flip_var_swapped();
+        }
+    }
+}
+
+
+impl<T: Flip + Send> ParFlip for [T] {
+    fn par_flip_var_swapped(&mut self) {
+        if let Some(nelem) = par_chunk_len::<T>(self.len()) {
+            self.par_chunks_mut(nelem).for_each(T::flip_slice_swapped);
+        } else {
+            T::flip_slice_swapped(self);
+        }
+    }
+
+    fn par_flip_var(&mut self, endian: Endian) {
+        if endian.is_mixed() {
+            if let Some(nelem) = par_chunk_len::<T>(self.len()) {
+                self.par_chunks_mut(nelem)
+                    .for_each(|chunk| chunk.iter_mut()
+                              .for_each(|elem| elem.flip_var(endian)));
+            } else {
+                self.iter_mut().for_each(|elem| elem.flip_var(endian));
+            }
+        } else if endian.need_swap() {
+            self.par_flip_var_swapped();
+        }
+    }
+}
+
+
+///
+/// Is the minimum number of bytes processed in parallel by the
+/// methods of trait [`ParFlip`], trait [`ParEncastMem`] and trait
+/// [`ParDecastMem`].  Smaller inputs are processed on the current
+/// thread.
+///
+/// [`ParEncastMem`]: crate::ParEncastMem
+/// [`ParDecastMem`]: crate::ParDecastMem
+///
+pub const PAR_THRESHOLD: usize = 0x10_0000;
+
+///
+/// Is the approximate number of bytes in each chunk processed by a
+/// task of crate [`rayon`] in the methods of trait [`ParFlip`], trait
+/// [`ParEncastMem`] and trait [`ParDecastMem`].
+///
+/// [`ParEncastMem`]: crate::ParEncastMem
+/// [`ParDecastMem`]: crate::ParDecastMem
+///
+pub const PAR_CHUNK_SIZE: usize = 0x4_0000;
+
+///
+/// Returns the number of values of type `T` in each chunk if `len`
+/// values of type `T` should be processed in parallel.  Otherwise,
+/// returns [`None`].
+///
+pub(crate) fn par_chunk_len<T>(len: usize) -> Option<usize> {
+    let elem_size = mem::size_of::<T>();
+
+    if elem_size == 0 || len.saturating_mul(elem_size) < PAR_THRESHOLD {
+        None
+    } else {
+        Some((PAR_CHUNK_SIZE / elem_size).max(1))
+    }
+}
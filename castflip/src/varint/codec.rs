@@ -0,0 +1,299 @@
+//
+// This file defines the encoders and decoders of variable-length
+// integers shared by the traits in module `varint`
+//
+
+use crate::{BitInt, struct_bit_reader::sign_extend};
+use super::VarIntError;
+
+
+///
+/// Is the maximum number of bytes of an encoded integer.  A 128-bit
+/// value needs 19 bytes in LEB128.
+///
+pub(crate) const MAX_LEN: usize = 19;
+
+///
+/// Is the largest value that can be encoded in QUIC.
+///
+const QUIC_MAX: u128 = (1 << 62) - 1;
+
+
+///
+/// Holds the bytes of an encoded integer.
+///
+pub(crate) struct Encoded {
+    buf: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl Encoded {
+    fn new() -> Self {
+        Self { buf: [0; MAX_LEN], len: 0 }
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.buf[self.len] = byte;
+        self.len += 1;
+    }
+
+    #[inline]
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.buf[.. self.len]
+    }
+}
+
+
+///
+/// Is a 129-bit two's complement value which can hold any value of
+/// the primitive integer types.  If `neg` is `false`, the value is
+/// `bits`.  Otherwise, the value is `bits - 2^128`.
+///
+#[derive(Clone, Copy)]
+struct Wide {
+    neg: bool,
+    bits: u128,
+}
+
+impl Wide {
+    fn from_int<T: BitInt>(value: T) -> Self {
+        if T::SIGNED {
+            let bits = sign_extend(value.to_u128(), T::BITS);
+            Self { neg: (bits as i128) < 0, bits }
+        } else {
+            Self { neg: false, bits: value.to_u128() }
+        }
+    }
+
+    fn to_int<T: BitInt>(self) -> Result<T, VarIntError> {
+        if !self.neg {
+            return unsigned_to_int(self.bits);
+        }
+
+        // A negative value must be larger than or equal to the
+        // minimum value of type `T`.
+        let value = self.bits as i128;
+        if !T::SIGNED || value >= 0 ||
+            (T::BITS < 128 && value >> (T::BITS - 1) != -1)
+        {
+            return Err(VarIntError::Overflow);
+        }
+
+        Ok(T::from_u128(self.bits))
+    }
+}
+
+fn unsigned_to_int<T: BitInt>(bits: u128) -> Result<T, VarIntError> {
+    let width = if T::SIGNED { T::BITS - 1 } else { T::BITS };
+    if width < 128 && bits >> width != 0 {
+        return Err(VarIntError::Overflow);
+    }
+
+    Ok(T::from_u128(bits))
+}
+
+
+///
+/// Is the result of reading the bytes of LEB128.
+///
+struct Leb {
+    bits: u128,     // The lowest 128 bits of the value.
+    width: u32,     // The number of bits in the bytes.
+    last: u8,       // The last byte.
+}
+
+impl Leb {
+    ///
+    /// Returns the bits beyond the lowest 128 bits, which exist only
+    /// if the bytes are the longest.
+    ///
+    fn high(&self) -> u8 {
+        if self.width > 128 { self.last >> 2 } else { 0 }
+    }
+
+    fn read<E>(mut next_byte: impl FnMut() -> Result<u8, E>) -> Result<Self, E>
+    where
+        E: From<VarIntError>,
+    {
+        let mut bits: u128 = 0;
+        let mut width = 0;
+
+        loop {
+            let byte = next_byte()?;
+            bits |= ((byte & 0x7f) as u128) << width;
+            width += 7;
+
+            if byte & 0x80 == 0 {
+                return Ok(Self { bits, width, last: byte & 0x7f });
+            }
+            if width as usize >= MAX_LEN * 7 {
+                return Err(VarIntError::Overflow.into());
+            }
+        }
+    }
+}
+
+
+pub(crate) fn decode_uleb128<T, E>(
+    next_byte: impl FnMut() -> Result<u8, E>,
+) -> Result<T, E>
+where
+    T: BitInt,
+    E: From<VarIntError>,
+{
+    let leb = Leb::read(next_byte)?;
+    if leb.high() != 0 {
+        return Err(VarIntError::Overflow.into());
+    }
+
+    Ok(unsigned_to_int(leb.bits)?)
+}
+
+pub(crate) fn decode_sleb128<T, E>(
+    next_byte: impl FnMut() -> Result<u8, E>,
+) -> Result<T, E>
+where
+    T: BitInt,
+    E: From<VarIntError>,
+{
+    let leb = Leb::read(next_byte)?;
+    let neg = leb.last & 0x40 != 0;
+
+    let wide = if leb.width < 128 {
+        Wide { neg, bits: sign_extend(leb.bits, leb.width) }
+    } else {
+        // The bits beyond the lowest 128 bits must be the sign bits.
+        if leb.high() != if neg { 0x1f } else { 0 } {
+            return Err(VarIntError::Overflow.into());
+        }
+        Wide { neg, bits: leb.bits }
+    };
+
+    Ok(wide.to_int()?)
+}
+
+pub(crate) fn decode_zigzag<T, E>(
+    next_byte: impl FnMut() -> Result<u8, E>,
+) -> Result<T, E>
+where
+    T: BitInt,
+    E: From<VarIntError>,
+{
+    let zigzag = decode_uleb128::<u128, E>(next_byte)?;
+
+    let wide = if zigzag & 1 == 0 {
+        Wide { neg: false, bits: zigzag >> 1 }
+    } else {
+        Wide { neg: true, bits: !(zigzag >> 1) }
+    };
+
+    Ok(wide.to_int()?)
+}
+
+pub(crate) fn decode_quic<T, E>(
+    mut next_byte: impl FnMut() -> Result<u8, E>,
+) -> Result<T, E>
+where
+    T: BitInt,
+    E: From<VarIntError>,
+{
+    let first = next_byte()?;
+    let len = 1 << (first >> 6);
+    let mut bits = (first & 0x3f) as u128;
+
+    for _ in 1 .. len {
+        bits = (bits << 8) | next_byte()? as u128;
+    }
+
+    Ok(unsigned_to_int(bits)?)
+}
+
+
+pub(crate) fn encode_uleb128<T: BitInt>(
+    value: T,
+) -> Result<Encoded, VarIntError> {
+    let wide = Wide::from_int(value);
+    if wide.neg {
+        return Err(VarIntError::Overflow);
+    }
+
+    let mut encoded = Encoded::new();
+    let mut bits = wide.bits;
+
+    while bits >= 0x80 {
+        encoded.push(bits as u8 | 0x80);
+        bits >>= 7;
+    }
+    encoded.push(bits as u8);
+
+    Ok(encoded)
+}
+
+pub(crate) fn encode_sleb128<T: BitInt>(
+    value: T,
+) -> Result<Encoded, VarIntError> {
+    let Wide { neg, mut bits } = Wide::from_int(value);
+    let fill = if neg { !0 << 121 } else { 0 };
+
+    let mut encoded = Encoded::new();
+
+    loop {
+        let byte = bits as u8 & 0x7f;
+        bits = (bits >> 7) | fill;
+
+        // The rest of the bits are the sign bits and they are the same
+        // as bit 6 of `byte`.
+        let done = if neg {
+            bits == !0 && byte & 0x40 != 0
+        } else {
+            bits == 0 && byte & 0x40 == 0
+        };
+
+        if done {
+            encoded.push(byte);
+            return Ok(encoded);
+        }
+        encoded.push(byte | 0x80);
+    }
+}
+
+pub(crate) fn encode_zigzag<T: BitInt>(
+    value: T,
+) -> Result<Encoded, VarIntError> {
+    let wide = Wide::from_int(value);
+
+    let zigzag = if !wide.neg {
+        if wide.bits >> 127 != 0 {
+            return Err(VarIntError::Overflow);
+        }
+        wide.bits << 1
+    } else {
+        (!wide.bits << 1) | 1
+    };
+
+    encode_uleb128(zigzag)
+}
+
+pub(crate) fn encode_quic<T: BitInt>(
+    value: T,
+) -> Result<Encoded, VarIntError> {
+    let wide = Wide::from_int(value);
+    if wide.neg || wide.bits > QUIC_MAX {
+        return Err(VarIntError::Overflow);
+    }
+
+    let (len, prefix) = match wide.bits {
+        0 ..= 0x3f => (1, 0x00),
+        0x40 ..= 0x3fff => (2, 0x40),
+        0x4000 ..= 0x3fff_ffff => (4, 0x80),
+        _ => (8, 0xc0),
+    };
+
+    let mut encoded = Encoded::new();
+    for i in (0 .. len).rev() {
+        encoded.push((wide.bits >> (i * 8)) as u8);
+    }
+    encoded.buf[0] |= prefix;
+
+    Ok(encoded)
+}
@@ -0,0 +1,116 @@
+//!
+//! Defines traits to encode and decode variable-length integers.
+//!
+//! The following encodings are supported.
+//!
+//! - **ULEB128** (`uleb128`) : Unsigned LEB128 used by WebAssembly,
+//!   DWARF and Android DEX.  It is the same as the varint of Protocol
+//!   Buffers.
+//!
+//! - **SLEB128** (`sleb128`) : Signed LEB128 used by WebAssembly and
+//!   DWARF.
+//!
+//! - **Zigzag** (`zigzag`) : ZigZag encoding followed by ULEB128, which
+//!   is used for the `sint32` and `sint64` types of Protocol Buffers.
+//!
+//! - **QUIC** (`quic`) : Variable-length integer of QUIC (RFC 9000)
+//!   whose two most significant bits of the first byte specify the
+//!   length (1, 2, 4 or 8 bytes).  It can hold values up to
+//!   2<sup>62</sup>-1.
+//!
+//! Trait [`DecodeVarInt`] and trait [`EncodeVarInt`] decode and encode
+//! variable-length integers at the head of a byte slice.  Trait
+//! [`DecodeVarIntIO`] and trait [`EncodeVarIntIO`] decode and encode
+//! them using [`std::io::Read`] and [`std::io::Write`] when feature
+//! `std` is enabled.  Each method has the name of an encoding after
+//! `decode_` or `encode_`.
+//!
+//! Values are decoded as any primitive integer type implementing
+//! trait [`BitInt`](crate::BitInt).  If a decoded value does not fit
+//! in the type, or if a value cannot be represented in the encoding,
+//! [`VarIntError::Overflow`] is returned.
+//!
+//! # Example
+//!
+//! ```rust
+//! use castflip::varint::{DecodeVarInt, EncodeVarInt};
+//!
+//! let mut bytes = [0_u8; 8];
+//! let size = bytes.encode_uleb128(624485_u32).unwrap();
+//! assert_eq!(bytes[.. size], [0xE5, 0x8E, 0x26]);
+//!
+//! let (value, size) = bytes.decode_uleb128::<u32>().unwrap();
+//! assert_eq!((value, size), (624485, 3));
+//!
+//! // The value does not fit in u16.
+//! assert!(bytes.decode_uleb128::<u16>().is_err());
+//!
+//! let size = bytes.encode_sleb128(-123456_i64).unwrap();
+//! assert_eq!(bytes[.. size], [0xC0, 0xBB, 0x78]);
+//! assert_eq!(bytes.decode_sleb128::<i32>(), Ok((-123456, 3)));
+//! ```
+//!
+
+use core::{error, fmt};
+
+mod codec;
+mod trait_decode_varint;
+mod trait_encode_varint;
+
+#[cfg(feature = "std")]
+mod trait_decode_varint_io;
+#[cfg(feature = "std")]
+mod trait_encode_varint_io;
+
+pub use self::{
+    trait_decode_varint::DecodeVarInt,
+    trait_encode_varint::EncodeVarInt,
+};
+
+#[cfg(feature = "std")]
+pub use self::{
+    trait_decode_varint_io::DecodeVarIntIO,
+    trait_encode_varint_io::EncodeVarIntIO,
+};
+
+
+///
+/// Is the error returned when a variable-length integer cannot be
+/// decoded or encoded.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VarIntError {
+    ///
+    /// The bytes end before the end of a variable-length integer, or
+    /// the bytes are too short to save an encoded integer.
+    ///
+    Truncated,
+
+    ///
+    /// The decoded value does not fit in the target type, or the value
+    /// cannot be represented in the encoding.
+    ///
+    Overflow,
+}
+
+impl fmt::Display for VarIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("variable-length integer is truncated"),
+            Self::Overflow  => f.write_str("variable-length integer overflows"),
+        }
+    }
+}
+
+impl error::Error for VarIntError {}
+
+#[cfg(feature = "std")]
+impl From<VarIntError> for std::io::Error {
+    fn from(error: VarIntError) -> Self {
+        let kind = match error {
+            VarIntError::Truncated => std::io::ErrorKind::UnexpectedEof,
+            VarIntError::Overflow  => std::io::ErrorKind::InvalidData,
+        };
+        std::io::Error::new(kind, error)
+    }
+}
@@ -0,0 +1,93 @@
+//
+// This file defines trait `DecodeVarInt`
+//
+
+use crate::BitInt;
+use super::{VarIntError, codec};
+
+
+///
+/// Provides methods to decode a variable-length integer at the head
+/// of a byte slice.
+///
+/// If successful, each method returns the decoded value and the
+/// number of the consumed bytes in [`Ok`]`((T, usize))`.  If the
+/// bytes end before the end of the integer,
+/// [`VarIntError::Truncated`] is returned.  If the decoded value does
+/// not fit in type `T`, or if the integer is longer than 19 bytes,
+/// [`VarIntError::Overflow`] is returned.  Non-canonical (padded)
+/// encodings are accepted as long as their values fit in type `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::varint::{DecodeVarInt, VarIntError};
+///
+/// let bytes: [u8; 5] = [0x9d, 0x7f, 0x3e, 0x7d, 0x01];
+///
+/// assert_eq!(bytes.decode_quic::<u32>(), Ok((494878333, 4)));
+/// assert_eq!(bytes[4 ..].decode_zigzag::<i8>(), Ok((-1, 1)));
+/// assert_eq!(bytes[.. 3].decode_quic::<u32>(), Err(VarIntError::Truncated));
+/// assert_eq!(bytes.decode_quic::<u16>(), Err(VarIntError::Overflow));
+/// ```
+///
+pub trait DecodeVarInt {
+    ///
+    /// Decodes an unsigned LEB128 integer as a value of type `T`.
+    ///
+    fn decode_uleb128<T: BitInt>(&self) -> Result<(T, usize), VarIntError>;
+
+    ///
+    /// Decodes a signed LEB128 integer as a value of type `T`.
+    ///
+    fn decode_sleb128<T: BitInt>(&self) -> Result<(T, usize), VarIntError>;
+
+    ///
+    /// Decodes a ZigZag-encoded unsigned LEB128 integer as a value of
+    /// type `T`.
+    ///
+    fn decode_zigzag<T: BitInt>(&self) -> Result<(T, usize), VarIntError>;
+
+    ///
+    /// Decodes a QUIC variable-length integer as a value of type `T`.
+    ///
+    fn decode_quic<T: BitInt>(&self) -> Result<(T, usize), VarIntError>;
+}
+
+
+impl DecodeVarInt for [u8] {
+    #[inline]
+    fn decode_uleb128<T: BitInt>(&self) -> Result<(T, usize), VarIntError> {
+        let mut pos = 0;
+        let value = codec::decode_uleb128(|| next_byte(self, &mut pos))?;
+        Ok((value, pos))
+    }
+
+    #[inline]
+    fn decode_sleb128<T: BitInt>(&self) -> Result<(T, usize), VarIntError> {
+        let mut pos = 0;
+        let value = codec::decode_sleb128(|| next_byte(self, &mut pos))?;
+        Ok((value, pos))
+    }
+
+    #[inline]
+    fn decode_zigzag<T: BitInt>(&self) -> Result<(T, usize), VarIntError> {
+        let mut pos = 0;
+        let value = codec::decode_zigzag(|| next_byte(self, &mut pos))?;
+        Ok((value, pos))
+    }
+
+    #[inline]
+    fn decode_quic<T: BitInt>(&self) -> Result<(T, usize), VarIntError> {
+        let mut pos = 0;
+        let value = codec::decode_quic(|| next_byte(self, &mut pos))?;
+        Ok((value, pos))
+    }
+}
+
+#[inline]
+fn next_byte(bytes: &[u8], pos: &mut usize) -> Result<u8, VarIntError> {
+    let byte = *bytes.get(*pos).ok_or(VarIntError::Truncated)?;
+    *pos += 1;
+    Ok(byte)
+}
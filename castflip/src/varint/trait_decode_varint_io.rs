@@ -0,0 +1,96 @@
+//
+// This file defines trait `DecodeVarIntIO`
+//
+
+use crate::BitInt;
+use super::codec;
+use std::io;
+
+#[cfg(doc)]
+use super::{DecodeVarInt, VarIntError};
+
+
+///
+/// Provides methods to decode a variable-length integer read from a
+/// reader using trait [`std::io::Read`].
+///
+/// Trait `DecodeVarIntIO` is the counterpart of trait
+/// [`DecodeVarInt`] for a reader.  It is implemented for all types
+/// that implement trait [`std::io::Read`].  Bytes are read one by one
+/// until the end of the integer, so wrap the reader in
+/// [`std::io::BufReader`] if reading a byte is expensive.
+///
+/// If the reader reaches the end of file before the end of the
+/// integer, an error of kind [`std::io::ErrorKind::UnexpectedEof`] is
+/// returned.  If the decoded value does not fit in type `T`, an error
+/// of kind [`std::io::ErrorKind::InvalidData`] wrapping
+/// [`VarIntError::Overflow`] is returned.  In both cases, the bytes
+/// that have been read are lost.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::varint::DecodeVarIntIO;
+///
+/// let mut input: &[u8] = &[0xe5, 0x8e, 0x26, 0xc0, 0xbb, 0x78];
+///
+/// assert_eq!(input.decode_uleb128::<u32>().unwrap(), 624485);
+/// assert_eq!(input.decode_sleb128::<i32>().unwrap(), -123456);
+/// assert!(input.decode_uleb128::<u32>().is_err());
+/// ```
+///
+pub trait DecodeVarIntIO {
+    ///
+    /// Reads an unsigned LEB128 integer and decodes it as a value of
+    /// type `T`.
+    ///
+    fn decode_uleb128<T: BitInt>(&mut self) -> io::Result<T>;
+
+    ///
+    /// Reads a signed LEB128 integer and decodes it as a value of type
+    /// `T`.
+    ///
+    fn decode_sleb128<T: BitInt>(&mut self) -> io::Result<T>;
+
+    ///
+    /// Reads a ZigZag-encoded unsigned LEB128 integer and decodes it as
+    /// a value of type `T`.
+    ///
+    fn decode_zigzag<T: BitInt>(&mut self) -> io::Result<T>;
+
+    ///
+    /// Reads a QUIC variable-length integer and decodes it as a value
+    /// of type `T`.
+    ///
+    fn decode_quic<T: BitInt>(&mut self) -> io::Result<T>;
+}
+
+
+impl<R: ?Sized + io::Read> DecodeVarIntIO for R {
+    #[inline]
+    fn decode_uleb128<T: BitInt>(&mut self) -> io::Result<T> {
+        codec::decode_uleb128(|| next_byte(self))
+    }
+
+    #[inline]
+    fn decode_sleb128<T: BitInt>(&mut self) -> io::Result<T> {
+        codec::decode_sleb128(|| next_byte(self))
+    }
+
+    #[inline]
+    fn decode_zigzag<T: BitInt>(&mut self) -> io::Result<T> {
+        codec::decode_zigzag(|| next_byte(self))
+    }
+
+    #[inline]
+    fn decode_quic<T: BitInt>(&mut self) -> io::Result<T> {
+        codec::decode_quic(|| next_byte(self))
+    }
+}
+
+#[inline]
+fn next_byte<R: ?Sized + io::Read>(reader: &mut R) -> io::Result<u8> {
+    let mut byte = [0_u8; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
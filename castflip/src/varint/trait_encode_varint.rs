@@ -0,0 +1,88 @@
+//
+// This file defines trait `EncodeVarInt`
+//
+
+use crate::BitInt;
+use super::{VarIntError, codec::{self, Encoded}};
+
+
+///
+/// Provides methods to encode a value as a variable-length integer at
+/// the head of a byte slice.
+///
+/// If successful, each method returns the number of the written bytes
+/// in [`Ok`]`(usize)`.  The shortest encoding is always used.  If the
+/// byte slice is too short to save the encoded integer,
+/// [`VarIntError::Truncated`] is returned and the byte slice is
+/// unchanged.  If the value cannot be represented in the encoding,
+/// [`VarIntError::Overflow`] is returned.  A negative value cannot be
+/// encoded in unsigned LEB128 nor in QUIC, and a value larger than
+/// 2<sup>62</sup>-1 cannot be encoded in QUIC.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::varint::{EncodeVarInt, VarIntError};
+///
+/// let mut bytes = [0_u8; 4];
+///
+/// assert_eq!(bytes.encode_quic(15293_u16), Ok(2));
+/// assert_eq!(bytes[.. 2], [0x7b, 0xbd]);
+/// assert_eq!(bytes.encode_zigzag(-2_i32), Ok(1));
+/// assert_eq!(bytes[0], 3);
+/// assert_eq!(bytes.encode_uleb128(-1_i32), Err(VarIntError::Overflow));
+/// assert_eq!(bytes.encode_uleb128(u64::MAX), Err(VarIntError::Truncated));
+/// ```
+///
+pub trait EncodeVarInt {
+    ///
+    /// Encodes `value` as an unsigned LEB128 integer.
+    ///
+    fn encode_uleb128<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError>;
+
+    ///
+    /// Encodes `value` as a signed LEB128 integer.
+    ///
+    fn encode_sleb128<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError>;
+
+    ///
+    /// Encodes `value` as a ZigZag-encoded unsigned LEB128 integer.
+    ///
+    fn encode_zigzag<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError>;
+
+    ///
+    /// Encodes `value` as a QUIC variable-length integer.
+    ///
+    fn encode_quic<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError>;
+}
+
+
+impl EncodeVarInt for [u8] {
+    #[inline]
+    fn encode_uleb128<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError> {
+        put_bytes(self, codec::encode_uleb128(value)?)
+    }
+
+    #[inline]
+    fn encode_sleb128<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError> {
+        put_bytes(self, codec::encode_sleb128(value)?)
+    }
+
+    #[inline]
+    fn encode_zigzag<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError> {
+        put_bytes(self, codec::encode_zigzag(value)?)
+    }
+
+    #[inline]
+    fn encode_quic<T: BitInt>(&mut self, value: T) -> Result<usize, VarIntError> {
+        put_bytes(self, codec::encode_quic(value)?)
+    }
+}
+
+#[inline]
+fn put_bytes(bytes: &mut [u8], encoded: Encoded) -> Result<usize, VarIntError> {
+    let src = encoded.as_bytes();
+    let dst = bytes.get_mut(.. src.len()).ok_or(VarIntError::Truncated)?;
+    dst.copy_from_slice(src);
+    Ok(src.len())
+}
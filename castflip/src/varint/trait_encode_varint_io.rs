@@ -0,0 +1,92 @@
+//
+// This file defines trait `EncodeVarIntIO`
+//
+
+use crate::BitInt;
+use super::codec::{self, Encoded};
+use std::io;
+
+#[cfg(doc)]
+use super::{EncodeVarInt, VarIntError};
+
+
+///
+/// Provides methods to encode a value as a variable-length integer
+/// and write it to a writer using trait [`std::io::Write`].
+///
+/// Trait `EncodeVarIntIO` is the counterpart of trait
+/// [`EncodeVarInt`] for a writer.  It is implemented for all types
+/// that implement trait [`std::io::Write`].  If successful, each
+/// method returns the number of the written bytes in
+/// [`Ok`]`(usize)`.  If the value cannot be represented in the
+/// encoding, an error of kind [`std::io::ErrorKind::InvalidData`]
+/// wrapping [`VarIntError::Overflow`] is returned and nothing is
+/// written.
+///
+/// # Example
+///
+/// ```rust
+/// use castflip::varint::EncodeVarIntIO;
+///
+/// let mut output = Vec::new();
+///
+/// assert_eq!(output.encode_uleb128(624485_u32).unwrap(), 3);
+/// assert_eq!(output.encode_quic(37_u8).unwrap(), 1);
+/// assert!(output.encode_quic(-1_i8).is_err());
+/// assert_eq!(output, [0xe5, 0x8e, 0x26, 0x25]);
+/// ```
+///
+pub trait EncodeVarIntIO {
+    ///
+    /// Encodes `value` as an unsigned LEB128 integer and writes it.
+    ///
+    fn encode_uleb128<T: BitInt>(&mut self, value: T) -> io::Result<usize>;
+
+    ///
+    /// Encodes `value` as a signed LEB128 integer and writes it.
+    ///
+    fn encode_sleb128<T: BitInt>(&mut self, value: T) -> io::Result<usize>;
+
+    ///
+    /// Encodes `value` as a ZigZag-encoded unsigned LEB128 integer and
+    /// writes it.
+    ///
+    fn encode_zigzag<T: BitInt>(&mut self, value: T) -> io::Result<usize>;
+
+    ///
+    /// Encodes `value` as a QUIC variable-length integer and writes it.
+    ///
+    fn encode_quic<T: BitInt>(&mut self, value: T) -> io::Result<usize>;
+}
+
+
+impl<W: ?Sized + io::Write> EncodeVarIntIO for W {
+    #[inline]
+    fn encode_uleb128<T: BitInt>(&mut self, value: T) -> io::Result<usize> {
+        write_bytes(self, codec::encode_uleb128(value)?)
+    }
+
+    #[inline]
+    fn encode_sleb128<T: BitInt>(&mut self, value: T) -> io::Result<usize> {
+        write_bytes(self, codec::encode_sleb128(value)?)
+    }
+
+    #[inline]
+    fn encode_zigzag<T: BitInt>(&mut self, value: T) -> io::Result<usize> {
+        write_bytes(self, codec::encode_zigzag(value)?)
+    }
+
+    #[inline]
+    fn encode_quic<T: BitInt>(&mut self, value: T) -> io::Result<usize> {
+        write_bytes(self, codec::encode_quic(value)?)
+    }
+}
+
+#[inline]
+fn write_bytes<W: ?Sized + io::Write>(
+    writer: &mut W,
+    encoded: Encoded,
+) -> io::Result<usize> {
+    writer.write_all(encoded.as_bytes())?;
+    Ok(encoded.as_bytes().len())
+}
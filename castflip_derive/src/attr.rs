@@ -0,0 +1,12 @@
+use syn::{Expr, meta::ParseNestedMeta};
+
+
+// Skips the value or the nested arguments of `meta`, if any.
+pub fn skip_meta(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| skip_meta(&meta))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,253 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Fields, Ident, LitInt, Member, Token, Type,
+    parenthesized, parse::ParseStream, punctuated::Punctuated,
+};
+
+use crate::attr::skip_meta;
+
+
+pub fn proc_tokens(input: TokenStream) -> TokenStream {
+    // Parse TokenStream into an Abstract Syntax Tree (AST).
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    match proc_struct(&ast) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+// The numbering of the bits in an integer field.
+#[derive(Clone, Copy)]
+enum Order {
+    Msb,    // The first bitfield occupies the most significant bits.
+    Lsb,    // The first bitfield occupies the least significant bits.
+}
+
+// A bitfield in an integer field.  `name` is None if it is reserved.
+struct BitField {
+    name: Option<Ident>,
+    width: u32,
+}
+
+// The bitfields in an integer field.
+struct BitSpec {
+    order: Order,
+    fields: Vec<BitField>,
+}
+
+// e.g. #[castflip(bits(version: 4, ihl: 4))] struct Ident(u8);
+//      struct Ident { #[castflip(bits(lsb, a: 1, b: 7))] flags: u8, ... }
+fn proc_struct(ast: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data_struct) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            &ast.ident, "Derive macro `BitFields` supports only struct."));
+    };
+
+    let ident = &ast.ident;
+    let mut methods = Vec::new();
+    let mut checks = Vec::new();
+
+    // The bitfields of a newtype are specified on the struct.
+    if let Some(spec) = parse_bits(&ast.attrs)? {
+        match &data_struct.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let member = Member::from(0);
+                let ty = &fields.unnamed[0].ty;
+                proc_field(&member, ty, &spec, &mut methods, &mut checks);
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "Attribute `bits` on a struct requires a single-field \
+                     tuple struct."));
+            },
+        }
+    }
+
+    // The bitfields of the other fields are specified on the fields.
+    for (index, field) in data_struct.fields.iter().enumerate() {
+        if let Some(spec) = parse_bits(&field.attrs)? {
+            let member = match &field.ident {
+                Some(name) => Member::from(name.clone()),
+                None => Member::from(index),
+            };
+            proc_field(&member, &field.ty, &spec, &mut methods, &mut checks);
+        }
+    }
+
+    if methods.is_empty() {
+        return Err(syn::Error::new_spanned(
+            ident,
+            "Derive macro `BitFields` requires attribute \
+             #[castflip(bits(NAME: WIDTH, ...))]."));
+    }
+
+    Ok(quote! {
+        impl #ident {
+            #( #methods )*
+        }
+
+        #( #checks )*
+    })
+}
+
+// Generates the getters and the setters of the bitfields in integer
+// field `member` of type `ty`.
+fn proc_field(
+    member: &Member,
+    ty: &Type,
+    spec: &BitSpec,
+    methods: &mut Vec<TokenStream2>,
+    checks: &mut Vec<TokenStream2>,
+) {
+    let total: u32 = spec.fields.iter().map(|field| field.width).sum();
+
+    // Make sure the bitfields fit in the integer field.
+    checks.push(quote! {
+        const _: () = ::core::assert!(
+            #total <= <#ty>::BITS,
+            "The bitfields do not fit in the field."
+        );
+    });
+
+    // `offset` is the number of the bits before the bitfield.
+    let mut offset = 0;
+
+    for field in &spec.fields {
+        let width = field.width;
+        let shift = match spec.order {
+            Order::Msb => {
+                let used = offset + width;
+                quote! { (<#ty>::BITS - #used) }
+            },
+            Order::Lsb => quote! { #offset },
+        };
+        // The mask is computed from all-one bits so that it is also
+        // correct for signed integer types.
+        let mask = quote! {
+            (if #width < <#ty>::BITS { !(!(0 as #ty) << #width) }
+             else { !(0 as #ty) })
+        };
+
+        if let Some(name) = &field.name {
+            let setter = format_ident!("set_{}", name);
+            let get_doc = format!("Returns bitfield `{}`.", name);
+            let set_doc = format!("Sets bitfield `{}` to `value`.", name);
+
+            if width == 1 {
+                methods.push(quote! {
+                    #[doc = #get_doc]
+                    #[inline]
+                    pub fn #name(&self) -> bool {
+                        (self.#member >> #shift) & #mask != 0
+                    }
+
+                    #[doc = #set_doc]
+                    #[inline]
+                    pub fn #setter(&mut self, value: bool) {
+                        self.#member = (self.#member & !(#mask << #shift))
+                            | ((value as #ty) << #shift);
+                    }
+                });
+            } else {
+                methods.push(quote! {
+                    #[doc = #get_doc]
+                    #[inline]
+                    pub fn #name(&self) -> #ty {
+                        (self.#member >> #shift) & #mask
+                    }
+
+                    #[doc = #set_doc]
+                    #[inline]
+                    pub fn #setter(&mut self, value: #ty) {
+                        self.#member = (self.#member & !(#mask << #shift))
+                            | ((value & #mask) << #shift);
+                    }
+                });
+            }
+        }
+
+        offset += width;
+    }
+}
+
+// Parses attribute #[castflip(bits(...))], if any.
+fn parse_bits(attrs: &[Attribute]) -> syn::Result<Option<BitSpec>> {
+    let mut spec = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("castflip") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("bits") {
+                let content;
+                parenthesized!(content in meta.input);
+                spec = Some(parse_spec(&content)?);
+                Ok(())
+            } else {
+                // Ignore the arguments for the other derive macros.
+                skip_meta(&meta)
+            }
+        })?;
+    }
+
+    Ok(spec)
+}
+
+// Parses the arguments of `bits`, e.g. `lsb, a: 1, _: 3, b: 4`.
+fn parse_spec(input: ParseStream) -> syn::Result<BitSpec> {
+    let mut order = Order::Msb;
+    let mut fields = Vec::new();
+
+    let items = Punctuated::<(Option<Ident>, Option<LitInt>), Token![,]>
+        ::parse_terminated_with(input, |input| {
+            let name = if input.peek(Token![_]) {
+                input.parse::<Token![_]>()?;
+                None
+            } else {
+                Some(input.parse::<Ident>()?)
+            };
+            let width = if input.peek(Token![:]) {
+                input.parse::<Token![:]>()?;
+                Some(input.parse::<LitInt>()?)
+            } else {
+                None
+            };
+            Ok((name, width))
+        })?;
+
+    for (index, (name, width)) in items.into_iter().enumerate() {
+        match (name, width) {
+            (name, Some(width)) => {
+                let width = match width.base10_parse::<u32>()? {
+                    0 => {
+                        return Err(syn::Error::new_spanned(
+                            width, "The width must not be zero."));
+                    },
+                    value => value,
+                };
+                fields.push(BitField { name, width });
+            },
+            (Some(name), None) if index == 0 && name == "msb" => {
+                order = Order::Msb;
+            },
+            (Some(name), None) if index == 0 && name == "lsb" => {
+                order = Order::Lsb;
+            },
+            (name, None) => {
+                let message = "Expected `NAME: WIDTH`, or `msb` or `lsb` \
+                               at the beginning.";
+                return Err(match name {
+                    Some(name) => syn::Error::new_spanned(name, message),
+                    None => input.error(message),
+                });
+            },
+        }
+    }
+
+    Ok(BitSpec { order, fields })
+}
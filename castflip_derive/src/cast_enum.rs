@@ -0,0 +1,195 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Attribute, Data, DeriveInput, Expr, Fields, Type, Variant};
+
+use crate::attr::skip_meta;
+
+
+pub fn proc_tokens(input: TokenStream) -> TokenStream {
+    // Parse TokenStream into an Abstract Syntax Tree (AST).
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    match proc_enum(&ast) {
+        Ok(tokens) => tokens,
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+// The role of a variant specified by its attribute.
+enum Role {
+    Tag(Expr),  // #[castflip(tag = VALUE)]
+    Raw,        // #[castflip(raw)]
+}
+
+// e.g. #[castflip(tag_type = u8)]
+//      enum Ident { #[castflip(tag = 1)] Variant1(Type1), ... }
+fn proc_enum(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data_enum) = &ast.data else {
+        return Err(syn::Error::new_spanned(
+            &ast.ident, "Derive macro `CastEnum` supports only enum."));
+    };
+
+    let ident = &ast.ident;
+    let tag_type = parse_tag_type(&ast.attrs)?;
+
+    let mut decode_arms = Vec::new();
+    let mut tag_arms = Vec::new();
+    let mut encode_arms = Vec::new();
+    let mut raw_decode = None;
+
+    for variant in &data_enum.variants {
+        let name = &variant.ident;
+
+        match (parse_role(variant)?, &variant.fields) {
+            // e.g. #[castflip(tag = VALUE)] Name(Type)
+            (Role::Tag(tag), Fields::Unnamed(fields))
+                if fields.unnamed.len() == 1 =>
+            {
+                decode_arms.push(quote! {
+                    if tag == (#tag) {
+                        return ::core::option::Option::Some(Self::#name(
+                            <[u8] as castflip::EncastMem>
+                                ::encastf(bytes, endian)?
+                        ));
+                    }
+                });
+                tag_arms.push(quote! { Self::#name(..) => #tag, });
+                encode_arms.push(quote! {
+                    Self::#name(value) =>
+                        <[u8] as castflip::DecastMem>
+                            ::decastf(bytes, value, endian),
+                });
+            },
+
+            // e.g. #[castflip(tag = VALUE)] Name
+            (Role::Tag(tag), Fields::Unit) => {
+                decode_arms.push(quote! {
+                    if tag == (#tag) {
+                        return ::core::option::Option::Some(Self::#name);
+                    }
+                });
+                tag_arms.push(quote! { Self::#name => #tag, });
+                encode_arms.push(quote! {
+                    Self::#name => ::core::option::Option::Some(0),
+                });
+            },
+
+            // e.g. #[castflip(raw)] Name(TagType, Vec<u8>)
+            (Role::Raw, Fields::Unnamed(fields))
+                if fields.unnamed.len() == 2 =>
+            {
+                if raw_decode.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        variant, "Only one variant can be `raw`."));
+                }
+                raw_decode = Some(quote! {
+                    ::core::option::Option::Some(
+                        Self::#name(tag, ::core::convert::From::from(bytes)))
+                });
+                tag_arms.push(quote! { Self::#name(tag, _) => *tag, });
+                encode_arms.push(quote! {
+                    Self::#name(_, raw) =>
+                        <[u8] as castflip::DecastMem>
+                            ::decasts::<u8>(bytes, raw),
+                });
+            },
+
+            (Role::Tag(_), _) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "A variant with `tag` must wrap a single value \
+                     or have no field."));
+            },
+            (Role::Raw, _) => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "A variant with `raw` must wrap a tag and bytes."));
+            },
+        }
+    }
+
+    let raw_decode = raw_decode.unwrap_or_else(|| quote! {
+        ::core::option::Option::None
+    });
+
+    Ok(quote! {
+        impl castflip::CastEnum for #ident {
+            type Tag = #tag_type;
+
+            fn decode_tagged(
+                tag: Self::Tag,
+                bytes: &[u8],
+                endian: castflip::Endian,
+            ) -> ::core::option::Option<Self> {
+                #( #decode_arms )*
+                #raw_decode
+            }
+
+            fn tag(&self) -> Self::Tag {
+                match self {
+                    #( #tag_arms )*
+                }
+            }
+
+            fn encode(
+                &self,
+                bytes: &mut [u8],
+                endian: castflip::Endian,
+            ) -> ::core::option::Option<usize> {
+                match self {
+                    #( #encode_arms )*
+                }
+            }
+        }
+    }.into())
+}
+
+// Parses attribute #[castflip(tag_type = TYPE)] of the enum.
+fn parse_tag_type(attrs: &[Attribute]) -> syn::Result<Type> {
+    let mut tag_type = None;
+
+    for attr in attrs {
+        if !attr.path().is_ident("castflip") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag_type") {
+                tag_type = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else {
+                // Ignore the arguments for the other derive macros.
+                skip_meta(&meta)
+            }
+        })?;
+    }
+
+    Ok(tag_type.unwrap_or_else(|| syn::parse_quote! { u32 }))
+}
+
+// Parses attribute #[castflip(tag = VALUE)] or #[castflip(raw)] of
+// a variant.
+fn parse_role(variant: &Variant) -> syn::Result<Role> {
+    let mut role = None;
+
+    for attr in &variant.attrs {
+        if !attr.path().is_ident("castflip") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tag") {
+                role = Some(Role::Tag(meta.value()?.parse::<Expr>()?));
+                Ok(())
+            } else if meta.path.is_ident("raw") {
+                role = Some(Role::Raw);
+                Ok(())
+            } else {
+                Err(meta.error("Expected `tag` or `raw`."))
+            }
+        })?;
+    }
+
+    role.ok_or_else(|| syn::Error::new_spanned(
+        variant,
+        "Each variant requires #[castflip(tag = VALUE)] or \
+         #[castflip(raw)]."))
+}
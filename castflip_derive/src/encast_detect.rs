@@ -0,0 +1,115 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Fields, Ident, LitStr};
+
+use crate::attr::skip_meta;
+
+
+pub fn proc_tokens(input: TokenStream) -> TokenStream {
+    // Parse TokenStream into an Abstract Syntax Tree (AST).
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    match proc_struct(&ast) {
+        Ok(tokens) => tokens,
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+// e.g. #[castflip(magic(field = "magic", value = 0xCAFEBABE))]
+//      struct Ident { magic: Type1, field2: Type2, ... }
+fn proc_struct(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let fields = match &ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields_named) => &fields_named.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &ast.ident,
+                    "Derive macro `EncastDetect` supports only struct \
+                     with named fields."));
+            },
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &ast.ident,
+                "Derive macro `EncastDetect` supports only struct."));
+        },
+    };
+
+    let (field_name, magic_value) = parse_magic(ast)?;
+
+    let field = fields.iter()
+        .find(|field| field.ident.as_ref() == Some(&field_name))
+        .ok_or_else(|| syn::Error::new_spanned(
+            &field_name, "No such field in the struct."))?;
+
+    let ident = &ast.ident;
+    let field_type = &field.ty;
+
+    Ok(quote! {
+        impl castflip::EncastDetect for #ident {
+            fn encast_detect(
+                bytes: &[u8],
+            ) -> ::core::result::Result<
+                (Self, castflip::Endian),
+                castflip::DetectError,
+            > {
+                let mut value = <[u8] as castflip::EncastMem>
+                    ::encast::<Self>(bytes)
+                    .ok_or(castflip::DetectError::Truncated)?;
+                let magic: #field_type = #magic_value;
+
+                let endian = if value.#field_name == magic {
+                    castflip::Endian::Native
+                } else if value.#field_name
+                    == castflip::Flip::flip_val_swapped(&magic)
+                {
+                    castflip::Endian::Swapped
+                } else {
+                    return ::core::result::Result::Err(
+                        castflip::DetectError::UnknownMagic);
+                };
+
+                castflip::Flip::flip_var(&mut value, endian);
+                ::core::result::Result::Ok((value, endian))
+            }
+        }
+    }.into())
+}
+
+// Parses attribute #[castflip(magic(field = "NAME", value = VALUE))].
+fn parse_magic(ast: &DeriveInput) -> syn::Result<(Ident, Expr)> {
+    let mut field_name = None;
+    let mut magic_value = None;
+
+    for attr in &ast.attrs {
+        if !attr.path().is_ident("castflip") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("magic") {
+                // Ignore the arguments for the other derive macros.
+                return skip_meta(&meta);
+            }
+            meta.parse_nested_meta(|meta| {
+                if meta.path.is_ident("field") {
+                    let name: LitStr = meta.value()?.parse()?;
+                    field_name = Some(name.parse::<Ident>()?);
+                    Ok(())
+                } else if meta.path.is_ident("value") {
+                    magic_value = Some(meta.value()?.parse::<Expr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("Expected `field` or `value`."))
+                }
+            })
+        })?;
+    }
+
+    match (field_name, magic_value) {
+        (Some(field_name), Some(magic_value)) => Ok((field_name, magic_value)),
+        _ => Err(syn::Error::new_spanned(
+            &ast.ident,
+            "Derive macro `EncastDetect` requires attribute \
+             #[castflip(magic(field = \"NAME\", value = VALUE))].")),
+    }
+}
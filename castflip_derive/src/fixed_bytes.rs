@@ -0,0 +1,30 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Ident};
+
+
+pub fn proc_tokens(input: TokenStream) -> TokenStream {
+    // Parse TokenStream into an Abstract Syntax Tree (AST).
+    let ast: DeriveInput = syn::parse(input).unwrap();
+
+    match &ast.data {
+        Data::Struct(_data_struct) => {
+            with_size(&ast.ident)
+        },
+        Data::Enum(_data_enum) => {
+            panic!("Derive macro `FixedBytes` does not support enum.")
+        },
+        Data::Union(_data_union) => {
+            with_size(&ast.ident)
+        },
+    }
+}
+
+fn with_size(ident: &Ident) -> TokenStream {
+    quote! {
+        impl castflip::FixedBytes for #ident {
+            const SIZE: usize = ::core::mem::size_of::<#ident>();
+            type Bytes = [u8; ::core::mem::size_of::<#ident>()];
+        }
+    }.into()
+}
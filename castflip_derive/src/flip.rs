@@ -36,6 +36,8 @@ fn proc_named_struct(ident: &Ident, fields: &FieldsNamed) -> TokenStream {
     let field_name1 = fields.named.iter().map(|field| &field.ident);
     let field_name2 = fields.named.iter().map(|field| &field.ident);
     let field_name3 = fields.named.iter().map(|field| &field.ident);
+    let field_name4 = fields.named.iter().map(|field| &field.ident);
+    let field_name5 = fields.named.iter().map(|field| &field.ident);
 
     quote! {
         impl castflip::Flip for #ident
@@ -55,6 +57,14 @@ fn proc_named_struct(ident: &Ident, fields: &FieldsNamed) -> TokenStream {
                     self.#field_name3.flip_var_swapped();
                 )*
             }
+
+            fn flip_val_mixed(&self, endian: castflip::Endian) -> Self {
+                Self {
+                    #(
+                        #field_name4: self.#field_name5.flip_val(endian),
+                    )*
+                }
+            }
         }
     }.into()
 }
@@ -64,6 +74,7 @@ fn proc_unnamed_struct(ident: &Ident, fields: &FieldsUnnamed) -> TokenStream {
     let field_type = fields.unnamed.iter().map(|field| &field.ty);
     let field_index1 = ( 0 .. fields.unnamed.len() ).map(syn::Index::from);
     let field_index2 = ( 0 .. fields.unnamed.len() ).map(syn::Index::from);
+    let field_index3 = ( 0 .. fields.unnamed.len() ).map(syn::Index::from);
 
     quote! {
         impl castflip::Flip for #ident
@@ -83,6 +94,14 @@ fn proc_unnamed_struct(ident: &Ident, fields: &FieldsUnnamed) -> TokenStream {
                     self.#field_index2.flip_var_swapped();
                 )*
             }
+
+            fn flip_val_mixed(&self, endian: castflip::Endian) -> Self {
+                Self (
+                    #(
+                        self.#field_index3.flip_val(endian),
+                    )*
+                )
+            }
         }
     }.into()
 }
@@ -96,6 +115,10 @@ fn proc_unit_struct(ident: &Ident) -> TokenStream {
             }
 
             fn flip_var_swapped(&mut self) {}
+
+            fn flip_val_mixed(&self, _endian: castflip::Endian) -> Self {
+                Self
+            }
         }
     }.into()
 }
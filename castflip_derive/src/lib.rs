@@ -4,11 +4,63 @@
 use proc_macro::TokenStream;
 
 
+mod attr;
+mod bit_fields;
 mod cast;
+mod cast_enum;
+mod encast_detect;
+mod fixed_bytes;
 mod flip;
 mod nop_flip;
 
 
+///
+/// Derive macro generating getters and setters of bitfields in the
+/// integer fields of a `struct` type.
+///
+/// The bitfields in an integer field are specified by attribute
+/// `#[castflip(bits(NAME: WIDTH, ...))]` on the field.  For a
+/// single-field tuple `struct` type such as a `#[repr(transparent)]`
+/// newtype of an integer type, the attribute can be applied to the
+/// `struct` type instead.  A reserved bitfield is specified as
+/// `_: WIDTH`.
+///
+/// By default, the bitfields are numbered MSB-first, i.e. the first
+/// bitfield occupies the most significant bits of the integer field.
+/// If the first argument is `lsb`, the bitfields are numbered
+/// LSB-first.  The first argument `msb` is also accepted.
+///
+/// For each bitfield `NAME`, method `NAME` returning its value and
+/// method `set_NAME` setting its value are generated.  A bitfield of
+/// width 1 is returned and set as a `bool` value.  Wider bitfields are
+/// returned and set as values of the type of the integer field.  The
+/// excess bits of a value to be set are discarded.  Even if the type
+/// of the integer field is signed, the bits of a bitfield are returned
+/// without sign extension.  The methods act on the value of the
+/// integer field, which must be in native-endian, e.g. after being
+/// encasted with endianness handling.
+///
+/// ```text
+/// #[repr(C)]
+/// #[derive(BitFields, Cast, Flip)]
+/// struct Ipv4Head {
+///     #[castflip(bits(version: 4, ihl: 4))]
+///     ver_ihl:    u8,
+///     tos:        u8,
+///     len:        u16,
+///     id:         u16,
+///     #[castflip(bits(_: 1, dont_fragment: 1, more_fragments: 1,
+///                     fragment_offset: 13))]
+///     frag:       u16,
+/// }
+/// ```
+///
+#[proc_macro_derive(BitFields, attributes(castflip))]
+pub fn bit_fields_derive(input: TokenStream) -> TokenStream {
+    bit_fields::proc_tokens(input)
+}
+
+
 ///
 /// Derive macro generating an `impl` of trait [`Cast`] for a `struct`
 /// type or a `union` type.
@@ -17,7 +69,7 @@ mod nop_flip;
 ///
 /// For detailed information, see the document of trait [`Cast`].
 ///
-/// [`Cast`]: https://docs.rs/castflip/0.1/castflip/trait.Cast.html
+/// [`Cast`]: https://docs.rs/castflip/0.2/castflip/trait.Cast.html
 /// [`repr(C)`]: https://doc.rust-lang.org/reference/type-layout.html#the-c-representation
 ///
 #[proc_macro_derive(Cast)]
@@ -26,13 +78,67 @@ pub fn cast_derive(input: TokenStream) -> TokenStream {
 }
 
 
+///
+/// Derive macro generating an `impl` of trait [`CastEnum`] for an
+/// `enum` type.
+///
+/// Each variant must be annotated with attribute
+/// `#[castflip(tag = VALUE)]` or `#[castflip(raw)]`.  The type of the
+/// tag can be specified by attribute `#[castflip(tag_type = TYPE)]` on
+/// the `enum` type.
+///
+/// For detailed information, see the document of trait [`CastEnum`].
+///
+/// [`CastEnum`]: https://docs.rs/castflip/0.2/castflip/trait.CastEnum.html
+///
+#[proc_macro_derive(CastEnum, attributes(castflip))]
+pub fn cast_enum_derive(input: TokenStream) -> TokenStream {
+    cast_enum::proc_tokens(input)
+}
+
+
+///
+/// Derive macro generating an `impl` of trait [`EncastDetect`] for a
+/// `struct` type with named fields.
+///
+/// It must be applied together with attribute
+/// `#[castflip(magic(field = "NAME", value = VALUE))]`, where `NAME` is
+/// the name of the field holding the magic number and `VALUE` is the
+/// expected magic number.
+///
+/// For detailed information, see the document of trait [`EncastDetect`].
+///
+/// [`EncastDetect`]: https://docs.rs/castflip/0.2/castflip/trait.EncastDetect.html
+///
+#[proc_macro_derive(EncastDetect, attributes(castflip))]
+pub fn encast_detect_derive(input: TokenStream) -> TokenStream {
+    encast_detect::proc_tokens(input)
+}
+
+
+///
+/// Derive macro generating an `impl` of trait [`FixedBytes`] for a
+/// `struct` type or a `union` type.
+///
+/// It must be applied together with attribute `#[derive(Cast)]`.
+///
+/// For detailed information, see the document of trait [`FixedBytes`].
+///
+/// [`FixedBytes`]: https://docs.rs/castflip/0.2/castflip/trait.FixedBytes.html
+///
+#[proc_macro_derive(FixedBytes)]
+pub fn fixed_bytes_derive(input: TokenStream) -> TokenStream {
+    fixed_bytes::proc_tokens(input)
+}
+
+
 ///
 /// Derive macro generating an `impl` of trait [`Flip`] for a `struct`
 /// type.
 ///
 /// For detailed information, see the document of trait [`Flip`].
 ///
-/// [`Flip`]: https://docs.rs/castflip/0.1/castflip/trait.Flip.html
+/// [`Flip`]: https://docs.rs/castflip/0.2/castflip/trait.Flip.html
 ///
 #[proc_macro_derive(Flip)]
 pub fn flip_derive(input: TokenStream) -> TokenStream {
@@ -47,7 +153,7 @@ pub fn flip_derive(input: TokenStream) -> TokenStream {
 ///
 /// For detailed information, see the document of trait [`NopFlip`].
 ///
-/// [`NopFlip`]: https://docs.rs/castflip/0.1/castflip/trait.NopFlip.html
+/// [`NopFlip`]: https://docs.rs/castflip/0.2/castflip/trait.NopFlip.html
 ///
 #[proc_macro_derive(NopFlip)]
 pub fn nop_flip_derive(input: TokenStream) -> TokenStream {
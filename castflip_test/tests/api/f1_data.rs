@@ -1,3 +1,5 @@
+#![allow(clippy::needless_return)]
+
 use core::mem::size_of;
 
 use castflip::{Cast, Flip};
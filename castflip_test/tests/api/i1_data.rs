@@ -1,3 +1,9 @@
+#![allow(
+    clippy::double_parens,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+)]
+
 use core::mem::size_of;
 use castflip::{Cast, Flip};
 
@@ -69,11 +69,42 @@ mod u3_data;
 
 mod u4_data;
 
+#[cfg(feature = "futures-io")]
+mod test_async_io;
+mod test_bit_fields;
+mod test_bit_io;
+mod test_bulk_swap;
+mod test_cast_enum;
+mod test_const;
+mod test_encast_detect;
+mod test_fixed;
+mod test_fixed_bytes;
+mod test_guid;
+mod test_mixed_endian;
+mod test_odd_int;
+mod test_offset;
+mod test_recast;
+mod test_split;
+mod test_varint;
+#[cfg(feature = "embedded-io")]
+mod test_embedded_io;
+
+mod test_flip_reader;
+mod test_flip_writer;
+#[cfg(feature = "rayon")]
+mod test_par_flip;
+
 mod test_flip_val_size;
 mod test_flip_var_size;
 mod test_flip_val_struct;
 mod test_flip_var_struct;
 
+mod test_io_encast_buf;
+mod test_io_encast_records;
+mod test_io_encast_seek;
+mod test_io_decast_seek;
+mod test_io_decast_vectored;
+
 mod test_io_decast_size;
 mod test_io_decast_struct;
 mod test_io_decastf_size;
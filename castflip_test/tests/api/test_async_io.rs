@@ -0,0 +1,363 @@
+use std::io;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::executor::block_on;
+use futures::io::{AsyncRead, AsyncWrite, Cursor};
+
+use castflip::{AsyncDecastIO, AsyncEncastIO, BE, Cast, Flip, LE, NE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    id:     u32,
+    len:    u16,
+    flags:  [u8; 2],
+}
+
+const BYTES: [u8; 16] = [
+    0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0xAA, 0xBB,
+    0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0xCC, 0xDD,
+];
+
+
+// Is a reader and a writer which returns `Poll::Pending` before each
+// successful call and transfers one byte at a time.
+struct Trickle {
+    bytes: Vec<u8>,
+    pos: usize,
+    ready: bool,
+}
+
+impl Trickle {
+    fn new(bytes: &[u8]) -> Self {
+        Self { bytes: bytes.to_vec(), pos: 0, ready: false }
+    }
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        self.ready = !self.ready;
+        if self.ready {
+            Poll::Ready(())
+        } else {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+impl AsyncRead for Trickle {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8])
+                 -> Poll<io::Result<usize>>
+    {
+        if self.poll_ready(cx).is_pending() {
+            return Poll::Pending;
+        }
+        if buf.is_empty() || self.pos == self.bytes.len() {
+            return Poll::Ready(Ok(0));
+        }
+        buf[0] = self.bytes[self.pos];
+        self.pos += 1;
+        Poll::Ready(Ok(1))
+    }
+}
+
+impl AsyncWrite for Trickle {
+    fn poll_write(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8])
+                  -> Poll<io::Result<usize>>
+    {
+        if self.poll_ready(cx).is_pending() {
+            return Poll::Pending;
+        }
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        self.bytes.push(buf[0]);
+        Poll::Ready(Ok(1))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>)
+                  -> Poll<io::Result<()>>
+    {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>)
+                  -> Poll<io::Result<()>>
+    {
+        Poll::Ready(Ok(()))
+    }
+}
+
+
+#[test]
+fn encast() {
+    block_on(async {
+        let mut input = Cursor::new(BYTES);
+
+        let rec = input.encastf::<Record>(LE).await.unwrap();
+        assert_eq!(rec, Record { id: 1, len: 0x10, flags: [0xAA, 0xBB] });
+
+        let value = input.encast::<[u8; 4]>().await.unwrap();
+        assert_eq!(value, [0x02, 0x00, 0x00, 0x00]);
+
+        let mut slice = [0_u16; 2];
+        let size = input.encastsf(&mut slice, BE).await.unwrap();
+        assert_eq!(size, 4);
+        assert_eq!(slice, [0x2000, 0xCCDD]);
+        assert_eq!(input.position(), 16);
+
+        input.set_position(0);
+        let mut slice = [0_u8; 3];
+        assert_eq!(input.encasts(&mut slice).await.unwrap(), 3);
+        assert_eq!(slice, [0x01, 0x00, 0x00]);
+    });
+}
+
+#[test]
+fn encastv() {
+    block_on(async {
+        let mut input: &[u8] = &BYTES;
+        let vec = input.encastvf::<Record>(2, LE).await.unwrap();
+        assert_eq!(vec[1], Record { id: 2, len: 0x20, flags: [0xCC, 0xDD] });
+
+        let mut input: &[u8] = &BYTES;
+        let vec = input.encastvf::<u32>(4, BE).await.unwrap();
+        assert_eq!(vec, [0x01000000, 0x1000AABB, 0x02000000, 0x2000CCDD]);
+
+        let mut input: &[u8] = &BYTES;
+        let vec = input.encastv::<u8>(3).await.unwrap();
+        assert_eq!(vec, [0x01, 0x00, 0x00]);
+
+        let vec = input.encastv::<u64>(0).await.unwrap();
+        assert!(vec.is_empty());
+    });
+}
+
+#[test]
+fn encast_pending() {
+    block_on(async {
+        let mut input = Trickle::new(&BYTES);
+
+        let vec = input.encastvf::<u16>(3, NE).await.unwrap();
+        assert_eq!(vec, [u16::from_ne_bytes([0x01, 0x00]),
+                         u16::from_ne_bytes([0x00, 0x00]),
+                         u16::from_ne_bytes([0x10, 0x00])]);
+
+        let value = input.encastf::<u64>(BE).await.unwrap();
+        assert_eq!(value, 0xAABB_0200_0000_2000);
+    });
+}
+
+#[test]
+fn encast_eof() {
+    block_on(async {
+        let mut input = Cursor::new(&BYTES[.. 6]);
+        let err = input.encastf::<Record>(LE).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut input = Trickle::new(&BYTES[.. 6]);
+        let err = input.encastvf::<u32>(2, LE).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    });
+}
+
+#[test]
+fn decast() {
+    block_on(async {
+        let mut output = Cursor::new(Vec::new());
+
+        let rec = Record { id: 1, len: 0x10, flags: [0xAA, 0xBB] };
+        assert_eq!(output.decastf(&rec, LE).await.unwrap(), 8);
+        assert_eq!(output.decast(&[0x02_u8, 0x00, 0x00, 0x00]).await.unwrap(),
+                   4);
+        assert_eq!(output.decastsf(&[0x2000_u16, 0xCCDD], BE).await.unwrap(),
+                   4);
+        assert_eq!(output.into_inner(), BYTES);
+
+        let mut output = Vec::new();
+        assert_eq!(output.decasts(&[1_u8, 2]).await.unwrap(), 2);
+        assert_eq!(output, [1, 2]);
+    });
+}
+
+#[test]
+fn decast_large() {
+    block_on(async {
+        let values: Vec<u32> = (0 .. 1000).collect();
+
+        let mut output = Vec::new();
+        assert_eq!(output.decastsf(&values, BE).await.unwrap(), 4000);
+
+        let mut input: &[u8] = &output;
+        let vec = input.encastvf::<u32>(1000, BE).await.unwrap();
+        assert_eq!(vec, values);
+        assert_eq!(output[3996 ..], [0x00, 0x00, 0x03, 0xE7]);
+    });
+}
+
+#[test]
+fn decast_pending() {
+    block_on(async {
+        let mut output = Trickle::new(&[]);
+
+        output.decastf(&0x1234_u16, BE).await.unwrap();
+        output.decastsf(&[0x5678_u16, 0x9ABC], LE).await.unwrap();
+        assert_eq!(output.bytes, [0x12, 0x34, 0x78, 0x56, 0xBC, 0x9A]);
+    });
+}
+
+#[test]
+fn decast_write_zero() {
+    block_on(async {
+        let mut buf = [0_u8; 3];
+        let mut output = Cursor::new(&mut buf[..]);
+
+        let err = output.decastf(&0x12345678_u32, BE).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    });
+}
+
+#[test]
+fn decastv() {
+    block_on(async {
+        let mut output = Vec::new();
+        assert_eq!(output.decastv(&[1_u8, 2]).await.unwrap(), 2);
+        assert_eq!(output.decastvf(&[0x0304_u16], BE).await.unwrap(), 2);
+        assert_eq!(output, [1, 2, 3, 4]);
+    });
+}
+
+#[test]
+fn send_futures() {
+    fn assert_send<F: Future + Send>(future: F) -> F {
+        future
+    }
+
+    // The futures can be spawned on multi-threaded executors if the
+    // reader, the writer and the arguments implement trait Send.
+    block_on(async {
+        let mut input = Cursor::new(BYTES);
+        let mut output = Vec::new();
+
+        let rec = assert_send(input.encastf::<Record>(LE)).await.unwrap();
+        assert_send(output.decastf(&rec, BE)).await.unwrap();
+        let vec = assert_send(input.encastvf::<u16>(2, BE)).await.unwrap();
+        assert_send(output.decastsf(&vec, LE)).await.unwrap();
+
+        assert_eq!(output, [0x00, 0x00, 0x00, 0x01, 0x00, 0x10, 0xAA, 0xBB,
+                            0x00, 0x02, 0x00, 0x00]);
+    });
+}
+
+// Is a reader and a writer which does not implement trait Send.
+struct NotSend<T> {
+    inner: T,
+    _marker: PhantomData<*const ()>,
+}
+
+impl<T: AsyncRead + Unpin> AsyncRead for NotSend<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+}
+
+impl<T: AsyncWrite + Unpin> AsyncWrite for NotSend<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+#[test]
+fn not_send() {
+    block_on(async {
+        let mut input = NotSend {
+            inner: Cursor::new(BYTES), _marker: PhantomData,
+        };
+        let mut output = NotSend {
+            inner: Vec::new(), _marker: PhantomData,
+        };
+
+        let rec = input.encastf::<Record>(LE).await.unwrap();
+        assert_eq!(output.decastf(&rec, BE).await.unwrap(), 8);
+        let vec = input.encastv::<u8>(4).await.unwrap();
+        assert_eq!(output.decastsf(&vec, BE).await.unwrap(), 4);
+
+        assert_eq!(output.inner, [0x00, 0x00, 0x00, 0x01, 0x00, 0x10,
+                                  0xAA, 0xBB, 0x02, 0x00, 0x00, 0x00]);
+    });
+}
+
+#[test]
+fn qualified_calls() {
+    use castflip::{DecastIO, EncastIO};
+
+    // Both `&[u8]` and `Vec<u8>` implement the synchronous and the
+    // asynchronous traits.
+    block_on(async {
+        let mut input: &[u8] = &BYTES;
+        let value = AsyncEncastIO::encastf::<u32>(&mut input, LE).await;
+        assert_eq!(value.unwrap(), 1);
+        let value = EncastIO::encastf::<u16>(&mut input, BE).unwrap();
+        assert_eq!(value, 0x1000);
+
+        let mut output = Vec::new();
+        AsyncDecastIO::decastf(&mut output, &1_u16, BE).await.unwrap();
+        DecastIO::decastf(&mut output, &2_u16, BE).unwrap();
+        assert_eq!(output, [0x00, 0x01, 0x00, 0x02]);
+    });
+}
+
+#[test]
+fn encastv_chunks() {
+    let bytes: Vec<u8> = (0 .. 3000).map(|i| (i % 251) as u8).collect();
+
+    block_on(async {
+        // The values are read in chunks.
+        let mut input = Cursor::new(bytes.clone());
+        let vec = input.encastvf::<u16>(1500, BE).await.unwrap();
+        for (i, value) in vec.iter().enumerate() {
+            assert_eq!(*value, u16::from_be_bytes([bytes[i * 2],
+                                                   bytes[i * 2 + 1]]));
+        }
+
+        // Each value is larger than the buffer.
+        let mut input = Cursor::new(bytes.clone());
+        let vec = input.encastv::<[u8; 1500]>(2).await.unwrap();
+        assert_eq!(vec.concat(), bytes);
+
+        // Zero-sized values are read without any byte.
+        let mut input = Cursor::new(Vec::new());
+        let vec = input.encastv::<[u16; 0]>(3).await.unwrap();
+        assert_eq!(vec.len(), 3);
+
+        // The reader reaches the end of file.
+        let mut input = Cursor::new(bytes.clone());
+        let err = input.encastv::<u32>(751).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    });
+}
@@ -0,0 +1,127 @@
+use castflip::{BE, BitFields, Cast, DecastMem, EncastMem, Flip, LE};
+
+
+#[repr(C)]
+#[derive(BitFields, Cast, Clone, Copy, Debug, Flip, PartialEq)]
+struct Ipv4Head {
+    #[castflip(bits(version: 4, ihl: 4))]
+    ver_ihl:    u8,
+    tos:        u8,
+    len:        u16,
+    id:         u16,
+    #[castflip(bits(_: 1, dont_fragment: 1, more_fragments: 1,
+                    fragment_offset: 13))]
+    frag:       u16,
+}
+
+// The data offset and the flags of TCP.
+#[repr(transparent)]
+#[derive(BitFields, Cast, Clone, Copy, Debug, Flip, PartialEq)]
+#[castflip(bits(data_offset: 4, _: 3, ns: 1, cwr: 1, ece: 1, urg: 1,
+                ack: 1, psh: 1, rst: 1, syn: 1, fin: 1))]
+struct TcpFlags(u16);
+
+// A flag word numbered from the least significant bit.
+#[repr(transparent)]
+#[derive(BitFields, Cast, Clone, Copy, Debug, Flip, PartialEq)]
+#[castflip(bits(lsb, abi: 8, pic: 1, _: 7, arch: 16))]
+struct ElfFlags(u32);
+
+// Bitfields in signed integer fields.
+#[repr(C)]
+#[derive(BitFields, Cast, Clone, Copy, Debug, Flip, PartialEq)]
+struct Signed {
+    #[castflip(bits(lsb, lo: 4, hi: 4))]
+    byte:   i8,
+    #[castflip(bits(sign: 1, rest: 15))]
+    word:   i16,
+    #[castflip(bits(all: 32))]
+    full:   i32,
+}
+
+const IPV4: [u8; 8] = [0x45, 0x00, 0x00, 0x54, 0x12, 0x34, 0x40, 0x00];
+
+
+#[test]
+fn named_fields() {
+    let mut hdr: Ipv4Head = IPV4.encastf(BE).unwrap();
+    assert_eq!(hdr.version(), 4);
+    assert_eq!(hdr.ihl(), 5);
+    assert!(hdr.dont_fragment());
+    assert!(!hdr.more_fragments());
+    assert_eq!(hdr.fragment_offset(), 0);
+
+    hdr.set_ihl(6);
+    hdr.set_dont_fragment(false);
+    hdr.set_more_fragments(true);
+    hdr.set_fragment_offset(0x1ABC);
+    assert_eq!(hdr.ver_ihl, 0x46);
+    assert_eq!(hdr.frag, 0x3ABC);
+    assert_eq!(hdr.version(), 4);
+
+    // The excess bits are discarded.
+    hdr.set_version(0x17);
+    assert_eq!(hdr.ver_ihl, 0x76);
+
+    let mut bytes = [0_u8; 8];
+    bytes[..].decastf(&hdr, BE).unwrap();
+    assert_eq!(bytes, [0x76, 0x00, 0x00, 0x54, 0x12, 0x34, 0x3A, 0xBC]);
+}
+
+#[test]
+fn newtype_msb() {
+    let mut flags: TcpFlags = [0x50, 0x12].encastf(BE).unwrap();
+    assert_eq!(flags.data_offset(), 5);
+    assert!(flags.syn());
+    assert!(flags.ack());
+    assert!(!flags.fin());
+    assert!(!flags.ns());
+
+    flags.set_syn(false);
+    flags.set_fin(true);
+    flags.set_ns(true);
+    assert_eq!(flags, TcpFlags(0x5111));
+}
+
+#[test]
+fn newtype_lsb() {
+    let mut flags: ElfFlags = [0x03, 0x01, 0x34, 0x12].encastf(LE).unwrap();
+    assert_eq!(flags.abi(), 3);
+    assert!(flags.pic());
+    assert_eq!(flags.arch(), 0x1234);
+
+    flags.set_abi(0xFF);
+    flags.set_pic(false);
+    flags.set_arch(0xABCD);
+    assert_eq!(flags, ElfFlags(0xABCD_00FF));
+}
+
+#[test]
+fn signed_fields() {
+    let mut val = Signed { byte: 0x7F, word: -1, full: -2 };
+    assert_eq!(val.lo(), 0xF);
+    assert_eq!(val.hi(), 0x7);
+    assert!(val.sign());
+    assert_eq!(val.rest(), 0x7FFF);
+    assert_eq!(val.all(), -2);
+
+    // The bits are not sign-extended.
+    val.byte = 0xF0_u8 as i8;
+    assert_eq!(val.lo(), 0x0);
+    assert_eq!(val.hi(), 0xF);
+
+    val.set_hi(0x7);
+    assert_eq!(val.byte, 0x70);
+    val.set_hi(0xF);
+    val.set_lo(-1);
+    assert_eq!(val.byte, -1);
+
+    val.set_sign(false);
+    assert_eq!(val.word, 0x7FFF);
+    val.set_rest(0);
+    val.set_sign(true);
+    assert_eq!(val.word, i16::MIN);
+
+    val.set_all(i32::MIN);
+    assert_eq!(val.full, i32::MIN);
+}
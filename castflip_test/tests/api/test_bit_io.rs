@@ -0,0 +1,139 @@
+use std::io::ErrorKind;
+
+use castflip::{
+    BE, BitOrder, BitReader, BitWriter, Cast, Flip, IoBitReader, LE,
+};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Trailer {
+    len:    u16,
+    crc:    u32,
+}
+
+const TRAILER: Trailer = Trailer { len: 0x1234, crc: 0x89ABCDEF };
+
+// (value, nbits) pairs of odd widths.
+const FIELDS: [(u32, u32); 6] = [
+    (0b101, 3), (0xABC, 12), (0x1F_FFFF, 21), (0, 1), (0x7F, 7), (1, 1),
+];
+
+
+#[test]
+fn round_trip() {
+    for order in [BitOrder::Msb, BitOrder::Lsb] {
+        let mut writer = BitWriter::new(order);
+        for (value, nbits) in FIELDS {
+            writer.write_bits(value, nbits);
+        }
+        writer.write_bits(-3_i16, 5);
+        writer.write_bool(true);
+        assert_eq!(writer.bit_len(), 51);
+        assert!(!writer.is_aligned());
+        writer.decastf(&TRAILER, BE);
+        let bytes = writer.into_bytes();
+        assert_eq!(bytes.len(), 7 + size_of::<Trailer>());
+
+        // Read from a byte slice.
+        let mut reader = BitReader::new(&bytes, order);
+        for (value, nbits) in FIELDS {
+            assert_eq!(reader.read_bits::<u32>(nbits), Some(value));
+        }
+        assert_eq!(reader.read_signed::<i16>(5), Some(-3));
+        assert_eq!(reader.read_bool(), Some(true));
+        assert_eq!(reader.bit_position(), 51);
+        assert_eq!(reader.encastf::<Trailer>(BE), Some(TRAILER));
+        assert_eq!(reader.bits_left(), 0);
+
+        // Read from a reader.
+        let mut reader = IoBitReader::new(&bytes[..], order);
+        for (value, nbits) in FIELDS {
+            assert_eq!(reader.read_bits::<u32>(nbits).unwrap(), value);
+        }
+        assert_eq!(reader.read_signed::<i16>(5).unwrap(), -3);
+        assert!(reader.read_bool().unwrap());
+        assert_eq!(reader.encastf::<Trailer>(BE).unwrap(), TRAILER);
+        assert!(reader.into_inner().is_empty());
+    }
+}
+
+#[test]
+fn bit_order() {
+    let bytes = [0b1100_0001, 0b0000_0011];
+
+    let mut reader = BitReader::new(&bytes, BitOrder::Msb);
+    assert_eq!(reader.read_bits::<u16>(10), Some(0b11_0000_0100));
+
+    let mut reader = BitReader::new(&bytes, BitOrder::Lsb);
+    assert_eq!(reader.read_bits::<u16>(10), Some(0b11_1100_0001));
+
+    // A whole byte-aligned value is big-endian in MSB-first order and
+    // little-endian in LSB-first order.
+    let mut reader = BitReader::new(&bytes, BitOrder::Msb);
+    assert_eq!(reader.read_bits::<u16>(16), Some(0xC103));
+    let mut reader = BitReader::new(&bytes, BitOrder::Lsb);
+    assert_eq!(reader.read_bits::<u16>(16), Some(0x03C1));
+}
+
+#[test]
+fn sign_extension() {
+    let mut writer = BitWriter::new(BitOrder::Msb);
+    writer.write_bits(-1_i8, 3);
+    writer.write_bits(3_i8, 3);
+    writer.write_bits(i64::MIN, 64);
+    writer.write_bits(-2_i8, 8);
+    let bytes = writer.into_bytes();
+
+    let mut reader = BitReader::new(&bytes, BitOrder::Msb);
+    assert_eq!(reader.read_signed::<i8>(3), Some(-1));
+    assert_eq!(reader.read_signed::<i32>(3), Some(3));
+    assert_eq!(reader.read_signed::<i64>(64), Some(i64::MIN));
+    assert_eq!(reader.read_bits::<u8>(8), Some(0xFE));
+    assert_eq!(reader.read_signed::<i8>(0), Some(0));
+}
+
+#[test]
+fn alignment() {
+    let bytes = [0xFF, 0x34, 0x12, 0xAA];
+    let mut reader = BitReader::new(&bytes, BitOrder::Lsb);
+
+    assert!(reader.is_aligned());
+    assert_eq!(reader.read_bits::<u8>(1), Some(1));
+    assert!(!reader.is_aligned());
+    assert_eq!(reader.remaining_bytes(), [0x34, 0x12, 0xAA]);
+    reader.align();
+    assert_eq!(reader.bit_position(), 8);
+    assert_eq!(reader.encastf::<u16>(LE), Some(0x1234));
+    assert_eq!(reader.encastf::<u16>(LE), None);
+    assert_eq!(reader.read_bits::<u8>(8), Some(0xAA));
+
+    let mut writer = BitWriter::new(BitOrder::Lsb);
+    writer.write_bits(1_u8, 1);
+    writer.align();
+    writer.align();
+    writer.write_bits(0x3_u8, 2);
+    assert_eq!(writer.as_bytes(), [0x01, 0x03]);
+}
+
+#[test]
+fn not_enough_bits() {
+    let bytes = [0x12, 0x34];
+    let mut reader = BitReader::new(&bytes, BitOrder::Msb);
+
+    assert_eq!(reader.read_bits::<u8>(4), Some(0x1));
+    assert_eq!(reader.read_bits::<u16>(13), None);
+    assert_eq!(reader.bit_position(), 4);
+    assert_eq!(reader.read_bits::<u16>(12), Some(0x234));
+
+    let mut reader = IoBitReader::new(&bytes[..], BitOrder::Msb);
+    let err = reader.read_bits::<u32>(17).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[should_panic]
+fn too_many_bits() {
+    let bytes = [0_u8; 4];
+    let _ = BitReader::new(&bytes, BitOrder::Msb).read_bits::<u8>(9);
+}
@@ -0,0 +1,282 @@
+use core::mem::size_of;
+use std::io::Cursor;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use castflip::experimental::FlipUnsized;
+use castflip::{
+    DecastIO, DecastMem, DecastSplit, EncastIO, EncastMem, Flip, SE,
+};
+
+
+// The number of elements is chosen so that both the SIMD path and
+// the scalar path for the trailing elements are exercised.
+const NELEM: usize = 37;
+
+macro_rules! test {
+    ( $ty:ty ) => {
+        {
+            let ne_vals: Vec<$ty> = (0 .. NELEM)
+                .map(|i| <$ty>::from_ne_bytes(gen_bytes(i)))
+                .collect();
+            let se_vals: Vec<$ty> = ne_vals.iter()
+                .map(|val| <$ty>::from_bits(val.to_bits().swap_bytes()))
+                .collect();
+            let ne_bytes: Vec<u8> = ne_vals.iter()
+                .flat_map(|val| val.to_ne_bytes())
+                .collect();
+            let se_bytes: Vec<u8> = se_vals.iter()
+                .flat_map(|val| val.to_ne_bytes())
+                .collect();
+
+            // EncastMem::encastsf
+            let mut vals = vec![<$ty>::default(); NELEM];
+            let size = se_bytes.encastsf::<$ty>(&mut vals, SE).unwrap();
+            assert_eq!(size, size_of::<$ty>() * NELEM);
+            assert_eq!(to_bits(&vals), to_bits(&ne_vals));
+
+            // DecastMem::decastsf
+            let mut bytes = vec![0_u8; size_of::<$ty>() * NELEM + 3];
+            let size = bytes[..].decastsf::<$ty>(&ne_vals, SE).unwrap();
+            assert_eq!(size, size_of::<$ty>() * NELEM);
+            assert_eq!(bytes[.. size], se_bytes[..]);
+            assert_eq!(bytes[size ..], [0_u8; 3]);
+
+            // EncastIO::encastvf
+            let mut input = Cursor::new(se_bytes.clone());
+            let vals = input.encastvf::<$ty>(NELEM, SE).unwrap();
+            assert_eq!(to_bits(&vals), to_bits(&ne_vals));
+
+            // DecastIO::decastsf
+            let mut output = Cursor::new(Vec::new());
+            let size = output.decastsf::<$ty>(&ne_vals, SE).unwrap();
+            assert_eq!(size, size_of::<$ty>() * NELEM);
+            assert_eq!(output.into_inner(), se_bytes);
+
+            // FlipUnsized::flip_var_swapped
+            let mut vals = ne_vals.clone();
+            vals[..].flip_var_swapped();
+            assert_eq!(to_bits(&vals), to_bits(&se_vals));
+
+            // Flip::flip_var_swapped for an array
+            let mut array = [<$ty>::default(); NELEM];
+            array.copy_from_slice(&ne_vals);
+            array.flip_var_swapped();
+            assert_eq!(to_bits(&array), to_bits(&se_vals));
+
+            // EncastMem::encastvf
+            let vals = ne_bytes.encastvf::<$ty>(NELEM, SE).unwrap();
+            assert_eq!(to_bits(&vals), to_bits(&se_vals));
+        }
+    }
+}
+
+
+// Generates bytes in which every byte differs from its neighbours.
+fn gen_bytes<const N: usize>(index: usize) -> [u8; N] {
+    let mut bytes = [0_u8; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = ((index * N + i) * 7 + 1) as u8;
+    }
+    bytes
+}
+
+// Converts values into their bits so that floats can be compared.
+fn to_bits<T: ToBits>(vals: &[T]) -> Vec<u128> {
+    vals.iter().map(|val| val.to_bits_u128()).collect()
+}
+
+trait ToBits {
+    fn to_bits_u128(&self) -> u128;
+}
+
+macro_rules! impl_to_bits {
+    ( $( $ty:ty ),* ) => { $(
+        impl ToBits for $ty {
+            fn to_bits_u128(&self) -> u128 {
+                self.to_bits() as u128
+            }
+        }
+    )* }
+}
+
+impl_to_bits!(u16, u32, u64, u128, i16, i32, i64, f32, f64);
+
+// Provides `to_bits` and `from_bits` for integers as well as floats.
+trait Bits: Sized {
+    type Bits;
+    fn to_bits(self) -> Self::Bits;
+    fn from_bits(bits: Self::Bits) -> Self;
+}
+
+macro_rules! impl_bits_for_int {
+    ( $( $ty:ty ),* ) => { $(
+        impl Bits for $ty {
+            type Bits = $ty;
+            fn to_bits(self) -> $ty { self }
+            fn from_bits(bits: $ty) -> $ty { bits }
+        }
+    )* }
+}
+
+impl_bits_for_int!(u16, u32, u64, u128, i16, i32, i64);
+
+
+#[test]
+fn unsigned() {
+    test!(u16);
+    test!(u32);
+    test!(u64);
+    test!(u128);
+}
+
+#[test]
+fn signed() {
+    test!(i16);
+    test!(i32);
+    test!(i64);
+}
+
+#[test]
+fn float() {
+    test!(f32);
+    test!(f64);
+}
+
+#[test]
+fn array() {
+    let ne_vals: Vec<[u16; 3]> = (0 .. NELEM)
+        .map(|i| (i * 0x0301) as u16)
+        .map(|i| [i, i.rotate_left(5), i.rotate_left(11)])
+        .collect();
+    let se_vals: Vec<[u16; 3]> = ne_vals.iter()
+        .map(|val| [val[0].swap_bytes(), val[1].swap_bytes(),
+                    val[2].swap_bytes()])
+        .collect();
+
+    let mut bytes = vec![0_u8; size_of::<[u16; 3]>() * NELEM];
+    bytes[..].decastsf(&ne_vals, SE).unwrap();
+    assert_eq!(bytes.encastvf::<[u16; 3]>(NELEM, SE).unwrap(), ne_vals);
+    assert_eq!(bytes.encastv::<[u16; 3]>(NELEM).unwrap(), se_vals);
+}
+
+#[test]
+fn zero_sized() {
+    let vals = [[0_u32; 0]; 3];
+
+    let mut bytes = Vec::new();
+    assert_eq!(bytes.decastsf(&vals, SE).unwrap(), 0);
+    assert_eq!(bytes[..].decastsf(&vals, SE), Some(0));
+    assert_eq!(bytes.encastvf::<[u32; 0]>(3, SE).unwrap(), vals);
+}
+
+// A type which is not `Cast` and whose bulk swap width is wrong.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Padded {
+    tag:    u8,
+    val:    u16,
+}
+
+impl Flip for Padded {
+    const BULK_SWAP_WIDTH: usize = 4;
+
+    fn flip_val_swapped(&self) -> Self {
+        Self { tag: self.tag, val: self.val.swap_bytes() }
+    }
+}
+
+#[test]
+fn not_cast() {
+    let vals = [Padded { tag: 0x12, val: 0x3456 }; NELEM];
+    let expected = [Padded { tag: 0x12, val: 0x5634 }; NELEM];
+
+    // The values are flipped one by one instead of in bulk.
+    let mut vec = vals.to_vec();
+    vec.flip_var_swapped();
+    assert_eq!(vec, expected);
+
+    let mut vec = vals.to_vec();
+    FlipUnsized::flip_var(&mut vec[..], SE);
+    assert_eq!(vec, expected);
+
+    assert_eq!(vals.flip_val_swapped(), expected);
+    let mut array = vals;
+    array.flip_var(SE);
+    assert_eq!(array, expected);
+}
+
+// Is the number of calls to `Flip::flip_slice_swapped` of `Counted`.
+static NSLICES: AtomicUsize = AtomicUsize::new(0);
+
+// A type which records the calls to `Flip::flip_slice_swapped`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Counted(u16);
+
+impl Flip for Counted {
+    fn flip_val_swapped(&self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    fn flip_slice_swapped(slice: &mut [Self]) {
+        NSLICES.fetch_add(1, Ordering::Relaxed);
+        for elem in slice {
+            elem.flip_var_swapped();
+        }
+    }
+}
+
+#[test]
+fn slice_at_once() {
+    let vals = [Counted(0x1234); NELEM];
+    let expected = [Counted(0x3412); NELEM];
+
+    // Each slice and each array is flipped by a single call.
+    let mut vec = vals.to_vec();
+    vec.flip_var_swapped();
+    assert_eq!(vec, expected);
+    assert_eq!(NSLICES.load(Ordering::Relaxed), 1);
+
+    FlipUnsized::flip_var(&mut vec[..], SE);
+    assert_eq!(vec, vals);
+    assert_eq!(NSLICES.load(Ordering::Relaxed), 2);
+
+    assert_eq!(vals.flip_val_swapped(), expected);
+    assert_eq!(NSLICES.load(Ordering::Relaxed), 3);
+
+    let mut arrays = [vals; 3];
+    arrays.flip_var(SE);
+    assert_eq!(arrays, [expected; 3]);
+    assert_eq!(NSLICES.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn decast_in_chunks() {
+    // The values span multiple chunks of the buffer.
+    let words: Vec<u32> = (0 .. 300).map(|i| i * 0x00010203).collect();
+    // Each value is larger than the buffer.
+    let arrays = [[0x0102_u16; 200], [0x0304_u16; 200]];
+
+    let mut expected = Vec::new();
+    for word in &words {
+        expected.extend_from_slice(&word.swap_bytes().to_ne_bytes());
+    }
+    for array in &arrays {
+        for elem in array {
+            expected.extend_from_slice(&elem.swap_bytes().to_ne_bytes());
+        }
+    }
+
+    let mut output = Vec::new();
+    output.decastsf(&words, SE).unwrap();
+    output.decastsf(&arrays, SE).unwrap();
+    assert_eq!(output, expected);
+
+    let mut first = vec![0_u8; 1000];
+    let mut second = vec![0_u8; expected.len() - 1000];
+    let mut split = (&mut first[..], &mut second[..]);
+    assert_eq!(split.decastsf(&words, SE), Some(1200));
+    let (head, tail) = second[200 ..].split_at_mut(300);
+    assert_eq!((head, tail).decastsf(&arrays, SE), Some(800));
+    assert_eq!([first, second].concat(), expected);
+}
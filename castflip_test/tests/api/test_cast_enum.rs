@@ -0,0 +1,116 @@
+use castflip::{BE, Cast, CastEnum, EncastMem, Flip, LE, NE, SE};
+
+
+// Mach-O style load commands selected by `cmd`.
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct LoadCommand {
+    cmd:        u32,
+    cmdsize:    u32,
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Segment {
+    vmaddr:     u64,
+    vmsize:     u64,
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Uuid {
+    uuid:       [u8; 16],
+}
+
+const LC_SEGMENT_64: u32 = 0x19;
+const LC_UUID: u32 = 0x1B;
+
+#[derive(CastEnum, Debug, PartialEq)]
+enum Command {
+    #[castflip(tag = LC_SEGMENT_64)]
+    Segment(Segment),
+    #[castflip(tag = LC_UUID)]
+    Uuid(Uuid),
+    #[castflip(tag = 0x0E)]
+    Empty,
+    #[castflip(raw)]
+    Unknown(u32, Vec<u8>),
+}
+
+// TLV options without a raw variant.
+#[derive(CastEnum, Debug, PartialEq)]
+#[castflip(tag_type = u8)]
+enum Option16 {
+    #[castflip(tag = 1)]
+    Short(u16),
+    #[castflip(tag = 2)]
+    Pair([u16; 2]),
+}
+
+
+#[test]
+fn decode_and_encode() {
+    let bytes: [u8; 24] = [
+        0x00, 0x00, 0x00, 0x19, 0x00, 0x00, 0x00, 0x18,
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
+    ];
+
+    let lc: LoadCommand = bytes.encastf(BE).unwrap();
+    let cmd = Command::decode_tagged(lc.cmd, &bytes[8 ..], BE).unwrap();
+    assert_eq!(cmd, Command::Segment(Segment {
+        vmaddr: 0x1_0000_0000,
+        vmsize: 0x1000,
+    }));
+    assert_eq!(cmd.tag(), LC_SEGMENT_64);
+
+    let mut output = [0xFF_u8; 17];
+    assert_eq!(cmd.encode(&mut output, BE), Some(16));
+    assert_eq!(output[.. 16], bytes[8 ..]);
+    assert_eq!(output[16], 0xFF);
+
+    for endian in [BE, LE, NE, SE] {
+        let mut output = [0_u8; 16];
+        cmd.encode(&mut output, endian).unwrap();
+        assert_eq!(Command::decode_tagged(LC_SEGMENT_64, &output, endian),
+                   Some(Command::Segment(Segment {
+                       vmaddr: 0x1_0000_0000,
+                       vmsize: 0x1000,
+                   })));
+    }
+
+    let uuid = Command::decode_tagged(LC_UUID, &[0xAB; 16], LE).unwrap();
+    assert_eq!(uuid, Command::Uuid(Uuid { uuid: [0xAB; 16] }));
+
+    let empty = Command::decode_tagged(0x0E, &[], LE).unwrap();
+    assert_eq!(empty, Command::Empty);
+    assert_eq!(empty.tag(), 0x0E);
+    assert_eq!(empty.encode(&mut [], LE), Some(0));
+}
+
+#[test]
+fn raw_fallback() {
+    let cmd = Command::decode_tagged(0x80000022, &[1, 2, 3], BE).unwrap();
+    assert_eq!(cmd, Command::Unknown(0x80000022, vec![1, 2, 3]));
+    assert_eq!(cmd.tag(), 0x80000022);
+
+    let mut output = [0_u8; 4];
+    assert_eq!(cmd.encode(&mut output, LE), Some(3));
+    assert_eq!(output, [1, 2, 3, 0]);
+    assert_eq!(cmd.encode(&mut output[.. 2], LE), None);
+}
+
+#[test]
+fn without_raw() {
+    assert_eq!(Option16::decode_tagged(1, &[0x12, 0x34], BE),
+               Some(Option16::Short(0x1234)));
+    assert_eq!(Option16::decode_tagged(2, &[0x12, 0x34, 0x56, 0x78], LE),
+               Some(Option16::Pair([0x3412, 0x7856])));
+    assert_eq!(Option16::Pair([1, 2]).tag(), 2_u8);
+
+    // Unknown tag.
+    assert_eq!(Option16::decode_tagged(3, &[0x12, 0x34], BE), None);
+    // Too short payload.
+    assert_eq!(Option16::decode_tagged(2, &[0x12, 0x34], BE), None);
+    assert_eq!(Option16::Short(1).encode(&mut [0_u8; 1], BE), None);
+}
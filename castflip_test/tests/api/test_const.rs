@@ -0,0 +1,85 @@
+use castflip::{
+    BE, Cast, EncastMem, Flip, LE, NE, SE, encast_const, to_bytes_const,
+};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Header {
+    kind:   u16,
+    len:    u16,
+}
+
+const IMAGE: [u8; 16] = [
+    0x7F, 0x45, 0x4C, 0x46, 0x02, 0x01, 0x01, 0x00,
+    0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC, 0xDE, 0xF0,
+];
+
+const MAGIC_BE: u32 = encast_const::<u32>(&IMAGE, BE).unwrap();
+const MAGIC_LE: u32 = encast_const::<u32>(&IMAGE, LE).unwrap();
+const WORDS_BE: [u16; 4] = encast_const::<[u16; 4]>(&IMAGE, BE).unwrap();
+const FLOAT_BE: f64 = encast_const::<f64>(&IMAGE, BE).unwrap();
+const WIDE_LE: u128 = encast_const::<u128>(&IMAGE, LE).unwrap();
+const MAGIC_NE: [u8; 6] = encast_const::<[u8; 6]>(&IMAGE, NE).unwrap();
+const HEADER: Header = encast_const::<Header>(&IMAGE, NE).unwrap();
+const SHORT: Option<u64> = encast_const::<u64>(IMAGE.split_at(12).1, BE);
+
+const MAGIC_BYTES: [u8; 4] = to_bytes_const(&0x7F454C46_u32, BE);
+const WORD_BYTES: [u8; 8] = to_bytes_const(&WORDS_BE, BE);
+const MAGIC_NE_BYTES: [u8; 6] = to_bytes_const(&MAGIC_NE, NE);
+const HEADER_BYTES: [u8; 4] = to_bytes_const(&HEADER, NE);
+
+
+#[test]
+fn encast() {
+    assert_eq!(MAGIC_BE, 0x7F454C46);
+    assert_eq!(MAGIC_LE, 0x464C457F);
+    assert_eq!(WORDS_BE, [0x7F45, 0x4C46, 0x0201, 0x0100]);
+    assert_eq!(FLOAT_BE.to_bits(), 0x7F454C46_02010100);
+    assert_eq!(WIDE_LE, IMAGE.encastf::<u128>(LE).unwrap());
+    assert_eq!(MAGIC_NE, *b"\x7FELF\x02\x01");
+    assert_eq!(HEADER, Header {
+        kind: u16::from_ne_bytes([0x7F, 0x45]),
+        len: u16::from_ne_bytes([0x4C, 0x46]),
+    });
+    assert_eq!(SHORT, None);
+
+    // The results must agree with the results of trait EncastMem.
+    for endian in [BE, LE, NE, SE] {
+        assert_eq!(encast_const::<u64>(&IMAGE[3 ..], endian),
+                   IMAGE[3 ..].encastf::<u64>(endian));
+        assert_eq!(encast_const::<[i32; 3]>(&IMAGE[1 ..], endian),
+                   IMAGE[1 ..].encastf::<[i32; 3]>(endian));
+    }
+}
+
+#[test]
+fn to_bytes() {
+    assert_eq!(MAGIC_BYTES, IMAGE[.. 4]);
+    assert_eq!(WORD_BYTES, IMAGE[.. 8]);
+    assert_eq!(MAGIC_NE_BYTES, IMAGE[.. 6]);
+    assert_eq!(HEADER_BYTES, IMAGE[.. 4]);
+
+    let bytes: [u8; 4] = to_bytes_const(&0x1234_5678_u32, LE);
+    assert_eq!(bytes, [0x78, 0x56, 0x34, 0x12]);
+    let bytes: [u8; 0] = to_bytes_const(&[0_u32; 0], SE);
+    assert_eq!(bytes, []);
+}
+
+#[test]
+#[should_panic]
+fn to_bytes_wrong_size() {
+    let _: [u8; 3] = to_bytes_const(&0_u32, BE);
+}
+
+#[test]
+#[should_panic(expected = "cannot be flipped")]
+fn encast_struct_swapped() {
+    let _ = encast_const::<Header>(&IMAGE, SE);
+}
+
+#[test]
+#[should_panic(expected = "cannot be flipped")]
+fn to_bytes_struct_swapped() {
+    let _: [u8; 4] = to_bytes_const(&HEADER, SE);
+}
@@ -0,0 +1,147 @@
+use embedded_io::{ErrorKind, ErrorType, Read, ReadExactError, Write};
+
+use castflip::{BE, Cast, EmbeddedDecastIO, EmbeddedEncastIO, Flip, LE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    id:     u32,
+    len:    u16,
+    flags:  [u8; 2],
+}
+
+const BYTES: [u8; 16] = [
+    0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0xAA, 0xBB,
+    0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0xCC, 0xDD,
+];
+
+
+// Is a UART-like device which transfers at most 3 bytes at a time and
+// fails after `limit` bytes are transferred.
+struct Uart {
+    bytes: Vec<u8>,
+    pos: usize,
+    limit: usize,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct UartError;
+
+impl embedded_io::Error for UartError {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl ErrorType for Uart {
+    type Error = UartError;
+}
+
+impl Read for Uart {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, UartError> {
+        if self.pos >= self.limit {
+            return Err(UartError);
+        }
+        let len = buf.len().min(3).min(self.bytes.len() - self.pos);
+        buf[.. len].copy_from_slice(&self.bytes[self.pos .. self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+impl Write for Uart {
+    fn write(&mut self, buf: &[u8]) -> Result<usize, UartError> {
+        if self.bytes.len() >= self.limit {
+            return Err(UartError);
+        }
+        let len = buf.len().min(3);
+        self.bytes.extend_from_slice(&buf[.. len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<(), UartError> {
+        Ok(())
+    }
+}
+
+
+#[test]
+fn encast() {
+    let mut input: &[u8] = &BYTES;
+
+    let rec = input.encastf::<Record>(LE).unwrap();
+    assert_eq!(rec, Record { id: 1, len: 0x10, flags: [0xAA, 0xBB] });
+
+    let value = input.encast::<[u8; 4]>().unwrap();
+    assert_eq!(value, [0x02, 0x00, 0x00, 0x00]);
+
+    let mut slice = [0_u16; 2];
+    assert_eq!(input.encastsf(&mut slice, BE).unwrap(), 4);
+    assert_eq!(slice, [0x2000, 0xCCDD]);
+    assert!(input.is_empty());
+
+    let mut input: &[u8] = &BYTES;
+    let mut slice = [0_u8; 3];
+    assert_eq!(input.encasts(&mut slice).unwrap(), 3);
+    assert_eq!(slice, [0x01, 0x00, 0x00]);
+}
+
+#[test]
+fn encastv() {
+    let mut input = Uart { bytes: BYTES.to_vec(), pos: 0, limit: 16 };
+    let vec = input.encastvf::<u32>(4, BE).unwrap();
+    assert_eq!(vec, [0x01000000, 0x1000AABB, 0x02000000, 0x2000CCDD]);
+
+    let mut input: &[u8] = &BYTES;
+    let vec = input.encastv::<Record>(2).unwrap();
+    assert_eq!(vec[0].flags, [0xAA, 0xBB]);
+}
+
+#[test]
+fn encast_errors() {
+    let mut input: &[u8] = &BYTES[.. 6];
+    let err = input.encastf::<Record>(LE).unwrap_err();
+    assert_eq!(err, ReadExactError::UnexpectedEof);
+
+    let mut input = Uart { bytes: BYTES.to_vec(), pos: 0, limit: 6 };
+    let err = input.encastvf::<Record>(2, LE).unwrap_err();
+    assert_eq!(err, ReadExactError::Other(UartError));
+}
+
+#[test]
+fn decast() {
+    let mut output = Uart { bytes: Vec::new(), pos: 0, limit: 16 };
+
+    let rec = Record { id: 1, len: 0x10, flags: [0xAA, 0xBB] };
+    assert_eq!(output.decastf(&rec, LE).unwrap(), 8);
+    assert_eq!(output.decast(&[0x02_u8, 0x00, 0x00, 0x00]).unwrap(), 4);
+    assert_eq!(output.decastsf(&[0x2000_u16, 0xCCDD], BE).unwrap(), 4);
+    assert_eq!(output.bytes, BYTES);
+
+    let mut buf = [0_u8; 2];
+    let mut output = &mut buf[..];
+    assert_eq!(output.decasts(&[1_u8, 2]).unwrap(), 2);
+    assert_eq!(buf, [1, 2]);
+}
+
+#[test]
+fn decast_large() {
+    let values: Vec<u32> = (0 .. 1000).collect();
+
+    let mut buf = vec![0_u8; 4000];
+    let mut output = &mut buf[..];
+    assert_eq!(output.decastsf(&values, BE).unwrap(), 4000);
+
+    let mut input: &[u8] = &buf;
+    let vec = input.encastvf::<u32>(1000, BE).unwrap();
+    assert_eq!(vec, values);
+}
+
+#[test]
+fn decast_errors() {
+    let mut output = Uart { bytes: Vec::new(), pos: 0, limit: 4 };
+    let err = output.decastsf(&[1_u16, 2, 3, 4], BE).unwrap_err();
+    assert_eq!(err, UartError);
+    assert_eq!(output.bytes, [0x00, 0x01, 0x00, 0x02, 0x00, 0x03]);
+}
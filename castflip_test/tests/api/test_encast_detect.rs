@@ -0,0 +1,69 @@
+use castflip::{
+    BE, Cast, DecastMem, DetectError, EncastDetect, Endian, Flip, LE, NE, SE,
+};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, EncastDetect, Eq, Flip, PartialEq)]
+#[castflip(magic(field = "magic", value = 0xCAFEBABE))]
+struct FatHeader {
+    magic:      u32,
+    nfat_arch:  u32,
+}
+
+// TIFF header whose magic number 42 follows the byte order mark.
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, EncastDetect, Eq, Flip, PartialEq)]
+#[castflip(magic(value = TIFF_MAGIC, field = "version"))]
+struct TiffHeader {
+    order:      [u8; 2],
+    version:    u16,
+    ifd_offset: u32,
+}
+
+const TIFF_MAGIC: u16 = 42;
+
+const FAT: FatHeader = FatHeader { magic: 0xCAFEBABE, nfat_arch: 3 };
+
+
+#[test]
+fn detect() {
+    for endian in [BE, LE, NE, SE] {
+        let mut bytes = [0_u8; 12];
+        bytes[..].decastf(&FAT, endian).unwrap();
+
+        let (hdr, detected) = FatHeader::encast_detect(&bytes).unwrap();
+        assert_eq!(hdr, FAT);
+        assert_eq!(detected.absolute(), endian.absolute());
+        assert!(matches!(detected, Endian::Native | Endian::Swapped));
+    }
+}
+
+#[test]
+fn detect_tiff() {
+    let bytes = [b'M', b'M', 0x00, 0x2A, 0x00, 0x00, 0x00, 0x08];
+    let (hdr, endian) = TiffHeader::encast_detect(&bytes).unwrap();
+    assert_eq!(endian.absolute(), Endian::Big);
+    assert_eq!(hdr.order, *b"MM");
+    assert_eq!(hdr.version, 42);
+    assert_eq!(hdr.ifd_offset, 8);
+
+    let bytes = [b'I', b'I', 0x2A, 0x00, 0x08, 0x00, 0x00, 0x00];
+    let (hdr, endian) = TiffHeader::encast_detect(&bytes).unwrap();
+    assert_eq!(endian.absolute(), Endian::Little);
+    assert_eq!(hdr.ifd_offset, 8);
+}
+
+#[test]
+fn errors() {
+    let bytes = 0xCAFEBABF_u32.to_ne_bytes();
+    assert_eq!(FatHeader::encast_detect(&bytes),
+               Err(DetectError::Truncated));
+
+    let mut bytes = [0_u8; 8];
+    bytes[.. 4].copy_from_slice(&0xCAFEBABF_u32.to_ne_bytes());
+    assert_eq!(FatHeader::encast_detect(&bytes),
+               Err(DetectError::UnknownMagic));
+
+    assert_eq!(DetectError::UnknownMagic.to_string(), "unknown magic number");
+}
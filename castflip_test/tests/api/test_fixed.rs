@@ -0,0 +1,195 @@
+use std::io::Cursor;
+
+use castflip::{
+    BE, Cast, DecastMem, EncastIO, EncastMem, Endian, Fixed, Flip, I1F15,
+    I1F31, I2F14, I8F8, I16F16, LE, U8F8, U16F16,
+};
+
+
+// A part of the `head` table of TrueType.
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Head {
+    version:    I16F16,
+    revision:   I16F16,
+    checksum:   u32,
+    scale:      [I2F14; 2],
+}
+
+const HEAD_BYTES: [u8; 16] = [
+    0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x80, 0x00,
+    0x12, 0x34, 0x56, 0x78, 0x40, 0x00, 0xF0, 0x00,
+];
+
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<I8F8>(), 2);
+    assert_eq!(size_of::<I16F16>(), 4);
+    assert_eq!(align_of::<I16F16>(), align_of::<i32>());
+    assert_eq!(size_of::<Fixed<u64, 32>>(), 8);
+
+    assert_eq!(I16F16::FRAC_BITS, 16);
+    assert_eq!(I1F15::FRAC_BITS, 15);
+    assert_eq!(I16F16::default().to_bits(), 0);
+    assert_eq!(I16F16::MIN.to_bits(), i32::MIN);
+    assert_eq!(U16F16::MAX.to_bits(), u32::MAX);
+}
+
+#[test]
+fn to_f64() {
+    assert_eq!(I16F16::from_bits(0x0001_8000).to_f64(), 1.5);
+    assert_eq!(I16F16::from_bits(-0x0001_8000).to_f64(), -1.5);
+    assert_eq!(I2F14::from_bits(0x7FFF).to_f64(), 1.99993896484375);
+    assert_eq!(I2F14::from_bits(-0x8000).to_f64(), -2.0);
+    assert_eq!(I1F15::MIN.to_f64(), -1.0);
+    assert_eq!(U8F8::from_bits(0xFF80).to_f64(), 255.5);
+    assert_eq!(Fixed::<u16, 16>::MAX.to_f64(), 0.9999847412109375);
+    assert_eq!(Fixed::<i32, 0>::from_bits(-7).to_f64(), -7.0);
+    assert_eq!(f64::from(I8F8::from_bits(0x0140)), 1.25);
+}
+
+#[test]
+fn from_f64() {
+    assert_eq!(I16F16::from_f64(1.5).to_bits(), 0x0001_8000);
+    assert_eq!(I16F16::from_f64(-1.5).to_bits(), -0x0001_8000);
+    assert_eq!(I2F14::from_f64(-0.5).to_bits(), -0x2000);
+    assert_eq!(I1F31::from_f64(0.5).to_bits(), 0x4000_0000);
+
+    // Rounded to the nearest value, and a tie away from zero.
+    assert_eq!(I8F8::from_f64(1.0 / 3.0).to_bits(), 0x0055);
+    assert_eq!(I8F8::from_f64(0.5 / 256.0).to_bits(), 1);
+    assert_eq!(I8F8::from_f64(-0.5 / 256.0).to_bits(), -1);
+    assert_eq!(I8F8::from_f64(0.49 / 256.0).to_bits(), 0);
+    assert_eq!(I8F8::from_f64(-1.7 / 256.0).to_bits(), -2);
+
+    // Saturated.
+    assert_eq!(I1F15::from_f64(1.0), I1F15::MAX);
+    assert_eq!(I1F15::from_f64(-1.0), I1F15::MIN);
+    assert_eq!(I1F15::from_f64(-2.0), I1F15::MIN);
+    assert_eq!(U8F8::from_f64(-1.0).to_bits(), 0);
+    assert_eq!(U16F16::from_f64(1e300), U16F16::MAX);
+    assert_eq!(I16F16::from_f64(f64::INFINITY), I16F16::MAX);
+    assert_eq!(I16F16::from_f64(f64::NEG_INFINITY), I16F16::MIN);
+    assert_eq!(I16F16::from_f64(f64::NAN).to_bits(), 0);
+    assert_eq!(Fixed::<u64, 64>::from_f64(2.0), Fixed::<u64, 64>::MAX);
+}
+
+#[test]
+fn saturating_add_sub() {
+    let one = I8F8::from_f64(1.0);
+    let half = I8F8::from_f64(0.5);
+
+    assert_eq!(one.saturating_add(half).to_f64(), 1.5);
+    assert_eq!(half.saturating_sub(one).to_f64(), -0.5);
+    assert_eq!(I8F8::MAX.saturating_add(half), I8F8::MAX);
+    assert_eq!(I8F8::MIN.saturating_sub(half), I8F8::MIN);
+    assert_eq!(U8F8::from_f64(0.5).saturating_sub(U8F8::from_f64(1.0)),
+               U8F8::from_bits(0));
+
+    assert_eq!(half.saturating_neg().to_f64(), -0.5);
+    assert_eq!(I8F8::MIN.saturating_neg(), I8F8::MAX);
+    assert_eq!(U8F8::from_f64(1.0).saturating_neg().to_bits(), 0);
+}
+
+#[test]
+fn saturating_i128() {
+    type Wide = Fixed<i128, 8>;
+    let one = Wide::from_f64(1.0);
+
+    assert_eq!(Wide::MAX.saturating_add(Wide::MAX), Wide::MAX);
+    assert_eq!(Wide::MIN.saturating_add(Wide::MIN), Wide::MIN);
+    assert_eq!(Wide::MAX.saturating_sub(Wide::MIN), Wide::MAX);
+    assert_eq!(Wide::MIN.saturating_sub(Wide::MAX), Wide::MIN);
+    assert_eq!(Wide::MIN.saturating_neg(), Wide::MAX);
+    assert_eq!(Wide::MAX.saturating_neg(), Wide::from_bits(-i128::MAX));
+
+    assert_eq!(one.saturating_add(one).to_f64(), 2.0);
+    assert_eq!(one.saturating_sub(one.saturating_add(one)).to_f64(), -1.0);
+    assert_eq!(Wide::MAX.saturating_mul(Wide::MAX), Wide::MAX);
+    assert_eq!(Wide::MIN.saturating_mul(Wide::MAX), Wide::MIN);
+    assert_eq!(Wide::MAX.saturating_div(one), Wide::MAX);
+
+    // The quotient overflows without the scale.
+    let min = Fixed::<i128, 0>::MIN;
+    assert_eq!(min.saturating_div(Fixed::from_bits(-1)), Fixed::MAX);
+
+    // The largest number of fractional bits.
+    assert_eq!(Fixed::<i128, 126>::from_f64(1.5).to_f64(), 1.5);
+}
+
+#[test]
+fn saturating_mul_div() {
+    let a = I16F16::from_f64(1.5);
+    let b = I16F16::from_f64(-2.25);
+
+    assert_eq!(a.saturating_mul(b).to_f64(), -3.375);
+    assert_eq!(b.saturating_mul(b).to_f64(), 5.0625);
+    assert_eq!(b.saturating_div(a).to_f64(), -1.5);
+    assert_eq!(a.saturating_div(b).to_f64(), -0.666656494140625);
+
+    // Rounded toward zero.
+    let tiny = I8F8::from_bits(1);
+    assert_eq!(tiny.saturating_mul(I8F8::from_f64(0.5)).to_bits(), 0);
+    assert_eq!(tiny.saturating_neg().saturating_mul(I8F8::from_f64(0.5))
+               .to_bits(), 0);
+
+    // Saturated.
+    let big = I16F16::from_f64(30000.0);
+    assert_eq!(big.saturating_mul(big), I16F16::MAX);
+    assert_eq!(big.saturating_mul(big.saturating_neg()), I16F16::MIN);
+    assert_eq!(big.saturating_div(I16F16::from_bits(1)), I16F16::MAX);
+    assert_eq!(I1F15::MIN.saturating_mul(I1F15::MIN), I1F15::MAX);
+
+    // The product overflows even in 128 bits.
+    let wide = Fixed::<u64, 0>::MAX;
+    assert_eq!(wide.saturating_mul(wide), wide);
+    let wide = Fixed::<i64, 0>::MIN;
+    assert_eq!(wide.saturating_mul(wide), Fixed::<i64, 0>::MAX);
+    assert_eq!(Fixed::<u64, 64>::MAX.saturating_div(Fixed::from_bits(1)),
+               Fixed::<u64, 64>::MAX);
+}
+
+#[test]
+#[should_panic(expected = "divide by zero")]
+fn saturating_div_by_zero() {
+    I16F16::from_f64(1.0).saturating_div(I16F16::from_bits(0));
+}
+
+#[test]
+fn ord_fmt() {
+    assert!(I16F16::from_f64(-1.0) < I16F16::from_f64(0.5));
+    assert!(I16F16::MIN < I16F16::MAX);
+
+    assert_eq!(format!("{}", I16F16::from_f64(-1.25)), "-1.25");
+    assert_eq!(format!("{:?}", I8F8::from_f64(2.0)), "2.0");
+    assert_eq!(format!("{:.2}", I2F14::from_f64(0.125)), "0.12");
+}
+
+#[test]
+fn encast() {
+    let head: Head = HEAD_BYTES.encastf(BE).unwrap();
+    assert_eq!(head.version.to_f64(), 1.0);
+    assert_eq!(head.revision.to_f64(), 2.5);
+    assert_eq!(head.checksum, 0x12345678);
+    assert_eq!(head.scale[0].to_f64(), 1.0);
+    assert_eq!(head.scale[1].to_f64(), -0.25);
+
+    let mut bytes = [0_u8; 16];
+    bytes.decastf(&head, BE).unwrap();
+    assert_eq!(bytes, HEAD_BYTES);
+
+    let mut input = Cursor::new(HEAD_BYTES);
+    assert_eq!(input.encastf::<Head>(BE).unwrap(), head);
+
+    // Q15 samples in little-endian.
+    let samples: [u8; 6] = [0x00, 0x40, 0x00, 0x80, 0xFF, 0x7F];
+    let mut values = [I1F15::default(); 3];
+    samples.encastsf(&mut values, LE).unwrap();
+    assert_eq!(values.map(I1F15::to_f64), [0.5, -1.0, 0.999969482421875]);
+
+    let value = I16F16::from_f64(1.5);
+    assert_eq!(value.flip_val(BE).to_bits(), 0x0001_8000_i32.flip_val(BE));
+    assert_eq!(value.flip_val(Endian::Pdp).to_bits(),
+               0x0001_8000_i32.flip_val(Endian::Pdp));
+}
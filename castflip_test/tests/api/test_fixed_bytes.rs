@@ -0,0 +1,85 @@
+use castflip::{BE, Cast, EncastMem, FixedBytes, Flip, LE, NE, NopFlip, SE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, FixedBytes, Flip, PartialEq)]
+struct Message {
+    kind:   u16,
+    len:    u16,
+    words:  [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, FixedBytes, Flip, PartialEq)]
+struct Pair(u8, i16);
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, FixedBytes, NopFlip)]
+union Word {
+    bytes:  [u8; 4],
+    value:  u32,
+}
+
+const BYTES: [u8; 12] = [
+    0x00, 0x01, 0x00, 0x0C, 0x12, 0x34, 0x56, 0x78,
+    0x9A, 0xBC, 0xDE, 0xF0,
+];
+
+const MSG: Message = Message {
+    kind:   0x0001,
+    len:    0x000C,
+    words:  [0x12345678, 0x9ABCDEF0],
+};
+
+
+#[test]
+fn size() {
+    assert_eq!(Message::SIZE, 12);
+    assert_eq!(Pair::SIZE, 4);
+    assert_eq!(Word::SIZE, 4);
+    assert_eq!(u8::SIZE, 1);
+    assert_eq!(u128::SIZE, 16);
+    assert_eq!(f64::SIZE, 8);
+}
+
+#[test]
+fn primitives() {
+    assert_eq!(0x1234_u16.to_bytes_f(BE), [0x12, 0x34]);
+    assert_eq!(0x1234_u16.to_bytes_f(LE), [0x34, 0x12]);
+    assert_eq!((-2_i32).to_bytes_f(BE), [0xFF, 0xFF, 0xFF, 0xFE]);
+    assert_eq!(1.0_f32.to_bytes_f(LE), [0x00, 0x00, 0x80, 0x3F]);
+    assert_eq!(0x12345678_u32.to_bytes(), 0x12345678_u32.to_ne_bytes());
+
+    assert_eq!(u16::from_bytes_f(&[0x12, 0x34], BE), 0x1234);
+    assert_eq!(i64::from_bytes_f(&[0xFF; 8], LE), -1);
+    assert_eq!(f64::from_bytes_f(&2.5_f64.to_be_bytes(), BE), 2.5);
+    assert_eq!(u32::from_bytes(&[0x12, 0x34, 0x56, 0x78]),
+               u32::from_ne_bytes([0x12, 0x34, 0x56, 0x78]));
+}
+
+#[test]
+fn structs() {
+    let bytes: [u8; Message::SIZE] = MSG.to_bytes_f(BE);
+    assert_eq!(bytes, BYTES);
+    assert_eq!(Message::from_bytes_f(&BYTES, BE), MSG);
+
+    // The results must agree with the results of trait EncastMem.
+    for endian in [BE, LE, NE, SE] {
+        let msg = Message::from_bytes_f(&BYTES, endian);
+        assert_eq!(Some(msg), BYTES.encastf::<Message>(endian));
+        assert_eq!(msg.to_bytes_f(endian), BYTES);
+    }
+
+    let pair = Pair(0xAA, 0x1234);
+    let bytes = pair.to_bytes_f(LE);
+    assert_eq!(bytes[0], 0xAA);
+    assert_eq!(bytes[2 ..], [0x34, 0x12]);
+    assert_eq!(Pair::from_bytes_f(&bytes, LE), pair);
+}
+
+#[test]
+fn unions() {
+    let word = Word::from_bytes_f(&[0x12, 0x34, 0x56, 0x78], BE);
+    assert_eq!(unsafe { word.bytes }, [0x12, 0x34, 0x56, 0x78]);
+    assert_eq!(word.to_bytes_f(LE), [0x12, 0x34, 0x56, 0x78]);
+}
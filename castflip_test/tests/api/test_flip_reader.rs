@@ -0,0 +1,122 @@
+use std::io::{self, BufRead, Read};
+
+use castflip::{BE, Cast, FlipReader, Flip, LE, NE, SE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    val1:   u32,
+    val2:   u16,
+    val3:   [u8; 2],
+}
+
+
+// A reader which returns at most `step` bytes at a time.
+struct Trickle<'a> {
+    bytes:  &'a [u8],
+    step:   usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let nbytes = self.step.min(buf.len()).min(self.bytes.len());
+        buf[.. nbytes].copy_from_slice(&self.bytes[.. nbytes]);
+        self.bytes = &self.bytes[nbytes ..];
+        Ok(nbytes)
+    }
+}
+
+
+fn gen_bytes(len: usize) -> Vec<u8> {
+    (0 .. len).map(|i| (i * 7 + 1) as u8).collect()
+}
+
+fn swap_u32(bytes: &[u8]) -> Vec<u8> {
+    bytes.chunks(4).flat_map(|c| c.iter().rev().copied()).collect()
+}
+
+
+#[test]
+fn copy_u32() {
+    let input = gen_bytes(4 * 1000);
+
+    // LE -> BE
+    let mut output = Vec::new();
+    let mut reader = FlipReader::<_, u32>::new(&input[..], LE, BE);
+    let size = io::copy(&mut reader, &mut output).unwrap();
+    assert_eq!(size, 4 * 1000);
+    assert_eq!(output, swap_u32(&input));
+
+    // NE -> NE and SE -> SE
+    for endian in [NE, SE] {
+        let mut output = Vec::new();
+        let mut reader = FlipReader::<_, u32>::new(&input[..], endian, endian);
+        io::copy(&mut reader, &mut output).unwrap();
+        assert_eq!(output, input);
+    }
+}
+
+#[test]
+fn copy_struct() {
+    let input = gen_bytes(8 * 100);
+
+    let mut output = Vec::new();
+    let mut reader = FlipReader::<_, Record>::new(&input[..], NE, SE);
+    io::copy(&mut reader, &mut output).unwrap();
+
+    let expected: Vec<u8> = input.chunks(8).flat_map(|c| {
+        [c[3], c[2], c[1], c[0], c[5], c[4], c[6], c[7]]
+    }).collect();
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn partial_reads() {
+    let input = gen_bytes(4 * 50);
+
+    for step in [1, 3, 5, 7] {
+        let inner = Trickle { bytes: &input, step };
+        let mut reader = FlipReader::<_, u32>::with_capacity(12, inner, LE, BE);
+
+        // Read 3 bytes at a time so that records are split.
+        let mut output = Vec::new();
+        let mut buf = [0_u8; 3];
+        loop {
+            let nbytes = reader.read(&mut buf).unwrap();
+            if nbytes == 0 {
+                break;
+            }
+            output.extend_from_slice(&buf[.. nbytes]);
+        }
+        assert_eq!(output, swap_u32(&input));
+    }
+}
+
+#[test]
+fn short_final_record() {
+    let input = gen_bytes(4 * 3 + 2);
+
+    let inner = Trickle { bytes: &input, step: 5 };
+    let mut reader = FlipReader::<_, u32>::new(inner, LE, BE);
+
+    let mut output = Vec::new();
+    let err = reader.read_to_end(&mut output).unwrap_err();
+
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(output, swap_u32(&input[.. 12]));
+    assert_eq!(reader.remainder(), &input[12 ..]);
+}
+
+#[test]
+fn fill_buf() {
+    let input = gen_bytes(4 * 2);
+
+    let mut reader = FlipReader::<_, u32>::new(&input[..], BE, LE);
+    assert_eq!(reader.fill_buf().unwrap(), swap_u32(&input));
+    reader.consume(5);
+    assert_eq!(reader.fill_buf().unwrap(), &swap_u32(&input)[5 ..]);
+    reader.consume(3);
+    assert_eq!(reader.fill_buf().unwrap(), &[] as &[u8]);
+    assert_eq!(reader.remainder(), &[] as &[u8]);
+}
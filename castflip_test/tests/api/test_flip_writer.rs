@@ -0,0 +1,159 @@
+use std::io::{self, Write};
+
+use castflip::{BE, Cast, DecastIO, FlipWriter, Flip, LE, NE, SE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    val1:   u32,
+    val2:   u16,
+    val3:   [u8; 2],
+}
+
+
+fn gen_bytes(len: usize) -> Vec<u8> {
+    (0 .. len).map(|i| (i * 7 + 1) as u8).collect()
+}
+
+fn swap_u32(bytes: &[u8]) -> Vec<u8> {
+    bytes.chunks(4).flat_map(|c| c.iter().rev().copied()).collect()
+}
+
+
+#[test]
+fn copy_u32() {
+    let input = gen_bytes(4 * 1000);
+
+    // LE -> BE
+    let mut writer = FlipWriter::<_, u32>::new(Vec::new(), LE, BE);
+    let size = io::copy(&mut &input[..], &mut writer).unwrap();
+    assert_eq!(size, 4 * 1000);
+    assert_eq!(writer.finish().unwrap(), 0);
+    assert_eq!(writer.into_inner(), swap_u32(&input));
+
+    // NE -> NE and SE -> SE
+    for endian in [NE, SE] {
+        let mut writer = FlipWriter::<_, u32>::new(Vec::new(), endian, endian);
+        writer.write_all(&input).unwrap();
+        assert_eq!(writer.finish().unwrap(), 0);
+        assert_eq!(writer.into_inner(), input);
+    }
+}
+
+#[test]
+fn decasts_struct() {
+    let records = [
+        Record { val1: 0x0102_0304, val2: 0x0506, val3: [0x07, 0x08] },
+        Record { val1: 0x1112_1314, val2: 0x1516, val3: [0x17, 0x18] },
+    ];
+
+    let mut writer = FlipWriter::<_, Record>::new(Vec::new(), NE, BE);
+    writer.decasts(&records).unwrap();
+    assert_eq!(writer.finish().unwrap(), 0);
+
+    assert_eq!(writer.into_inner(),
+               [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08,
+                0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18]);
+}
+
+#[test]
+fn partial_records() {
+    let input = gen_bytes(4 * 50);
+
+    for step in [1, 3, 5, 7, 13] {
+        let mut writer =
+            FlipWriter::<_, u32>::with_capacity(12, Vec::new(), BE, LE);
+
+        for chunk in input.chunks(step) {
+            let size = writer.write(chunk).unwrap();
+            assert_eq!(size, chunk.len());
+        }
+
+        assert_eq!(writer.finish().unwrap(), 0);
+        assert_eq!(writer.into_inner(), swap_u32(&input));
+    }
+}
+
+#[test]
+fn leftover_bytes() {
+    let input = gen_bytes(4 * 3 + 3);
+
+    let mut writer = FlipWriter::<_, u32>::new(Vec::new(), LE, BE);
+    writer.write_all(&input).unwrap();
+
+    assert_eq!(writer.finish().unwrap(), 3);
+    assert_eq!(writer.remainder(), &input[12 ..]);
+    assert_eq!(writer.get_ref(), &swap_u32(&input[.. 12]));
+
+    // Complete the record.
+    writer.write_all(&[0xFF]).unwrap();
+    assert_eq!(writer.finish().unwrap(), 0);
+    assert_eq!(writer.remainder(), &[] as &[u8]);
+    assert_eq!(&writer.get_ref()[12 ..],
+               &[0xFF, input[14], input[13], input[12]]);
+}
+
+// A writer which accepts at most `step` bytes at a time and fails
+// on every `period`-th call.
+struct Flaky {
+    bytes:  Vec<u8>,
+    step:   usize,
+    period: usize,
+    ncalls: usize,
+    kind:   io::ErrorKind,
+}
+
+impl Flaky {
+    fn new(step: usize, period: usize, kind: io::ErrorKind) -> Self {
+        Self { bytes: Vec::new(), step, period, ncalls: 0, kind }
+    }
+}
+
+impl Write for Flaky {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.ncalls += 1;
+        if self.ncalls == self.period {
+            self.ncalls = 0;
+            return Err(io::Error::new(self.kind, "flaky"));
+        }
+        let nbytes = self.step.min(buf.len());
+        self.bytes.extend_from_slice(&buf[.. nbytes]);
+        Ok(nbytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn failing_writer() {
+    let input = gen_bytes(4 * 500 + 2);
+
+    // Retry every failed call with the same bytes.
+    for (step, period, chunk) in [(8, 3, 7), (5, 2, 4), (1, 4, 100), (64, 5, 13)] {
+        let inner = Flaky::new(step, period, io::ErrorKind::Other);
+        let mut writer = FlipWriter::<_, u32>::with_capacity(16, inner, LE, BE);
+
+        for mut bytes in input.chunks(chunk) {
+            while !bytes.is_empty() {
+                if let Ok(nbytes) = writer.write(bytes) {
+                    assert!(nbytes > 0);
+                    bytes = &bytes[nbytes ..];
+                }
+            }
+        }
+        while writer.flush().is_err() {}
+
+        assert_eq!(writer.remainder(), &input[4 * 500 ..]);
+        assert_eq!(writer.get_ref().bytes, swap_u32(&input[.. 4 * 500]));
+    }
+
+    // Method write_all retries on Interrupted.
+    let inner = Flaky::new(6, 2, io::ErrorKind::Interrupted);
+    let mut writer = FlipWriter::<_, u32>::with_capacity(16, inner, LE, BE);
+    writer.write_all(&input).unwrap();
+    assert_eq!(writer.finish().unwrap(), 2);
+    assert_eq!(writer.get_ref().bytes, swap_u32(&input[.. 4 * 500]));
+}
@@ -0,0 +1,133 @@
+use std::io::Cursor;
+
+use castflip::{
+    BE, Cast, DecastMem, EncastIO, EncastMem, Endian, Flip, Guid, LE,
+};
+
+
+// The partition type GUID of the EFI System Partition.
+const ESP_STR: &str = "c12a7328-f81f-11d2-ba4b-00a0c93ec93b";
+
+const ESP: Guid = Guid::new(
+    0xC12A7328, 0xF81F, 0x11D2,
+    [0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B],
+);
+
+// The mixed-endian form stored in GPT.
+const ESP_GUID_BYTES: [u8; 16] = [
+    0x28, 0x73, 0x2A, 0xC1, 0x1F, 0xF8, 0xD2, 0x11,
+    0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B,
+];
+
+// The byte order of RFC 4122.
+const ESP_UUID_BYTES: [u8; 16] = [
+    0xC1, 0x2A, 0x73, 0x28, 0xF8, 0x1F, 0x11, 0xD2,
+    0xBA, 0x4B, 0x00, 0xA0, 0xC9, 0x3E, 0xC9, 0x3B,
+];
+
+// A part of a GPT partition entry.
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct PartEntry {
+    type_guid:  Guid,
+    part_guid:  Guid,
+    first_lba:  u64,
+}
+
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<Guid>(), 16);
+    assert_eq!(align_of::<Guid>(), 4);
+    assert!(Guid::NIL.is_nil());
+    assert!(!ESP.is_nil());
+    assert_eq!(Guid::default(), Guid::NIL);
+}
+
+#[test]
+fn bytes() {
+    assert_eq!(Guid::from_guid_bytes(ESP_GUID_BYTES), ESP);
+    assert_eq!(Guid::from_uuid_bytes(ESP_UUID_BYTES), ESP);
+    assert_eq!(ESP.to_guid_bytes(), ESP_GUID_BYTES);
+    assert_eq!(ESP.to_uuid_bytes(), ESP_UUID_BYTES);
+
+    // Both forms are handled by Flip.
+    assert_eq!(ESP_GUID_BYTES.encastf::<Guid>(LE), Some(ESP));
+    assert_eq!(ESP_UUID_BYTES.encastf::<Guid>(BE), Some(ESP));
+
+    let mut bytes = [0_u8; 16];
+    assert_eq!(bytes.decastf(&ESP, LE), Some(16));
+    assert_eq!(bytes, ESP_GUID_BYTES);
+    assert_eq!(bytes.decastf(&ESP, BE), Some(16));
+    assert_eq!(bytes, ESP_UUID_BYTES);
+
+    // The last field is unchanged by Flip.
+    let swapped = ESP.flip_val_swapped();
+    assert_eq!(swapped.data1, 0x28732AC1);
+    assert_eq!(swapped.data2, 0x1FF8);
+    assert_eq!(swapped.data3, 0xD211);
+    assert_eq!(swapped.data4, ESP.data4);
+}
+
+#[test]
+fn struct_field() {
+    let mut bytes = [0_u8; 40];
+    bytes[.. 16].copy_from_slice(&ESP_GUID_BYTES);
+    bytes[16 .. 32].copy_from_slice(&ESP_GUID_BYTES);
+    bytes[32 ..].copy_from_slice(&34_u64.to_le_bytes());
+
+    let mut input = Cursor::new(bytes);
+    let entry: PartEntry = input.encastf(LE).unwrap();
+    assert_eq!(entry.type_guid, ESP);
+    assert_eq!(entry.part_guid, ESP);
+    assert_eq!(entry.first_lba, 34);
+}
+
+#[test]
+fn mixed_endian() {
+    let guid = Guid::new(0x01020304, 0x0506, 0x0708,
+                         [0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10]);
+    let bytes: [u8; 16] = [
+        0x02, 0x01, 0x04, 0x03, 0x06, 0x05, 0x08, 0x07,
+        0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x10,
+    ];
+
+    // Only the first field is split into words in PDP-endian.
+    let mut buf = [0_u8; 16];
+    buf.decastf(&guid, Endian::Pdp).unwrap();
+    assert_eq!(buf, bytes);
+    assert_eq!(bytes.encastf::<Guid>(Endian::Pdp).unwrap(), guid);
+
+    let entry = PartEntry { type_guid: guid, part_guid: ESP, first_lba: 1 };
+    let flipped = entry.flip_val(Endian::Pdp).flip_val(Endian::Pdp);
+    assert_eq!(flipped, entry);
+}
+
+#[test]
+fn string() {
+    assert_eq!(ESP.to_string(), ESP_STR);
+    assert_eq!(format!("{:?}", ESP), format!("Guid({ESP_STR})"));
+    assert_eq!(Guid::NIL.to_string(), "00000000-0000-0000-0000-000000000000");
+
+    assert_eq!(ESP_STR.parse(), Ok(ESP));
+    assert_eq!(ESP_STR.to_uppercase().parse(), Ok(ESP));
+    assert_eq!(format!("{{{ESP_STR}}}").parse(), Ok(ESP));
+
+    let invalid = [
+        "",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93bb",
+        "c12a7328f81f-11d2-ba4b-00a0c93ec93b0",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93g",
+        "{c12a7328-f81f-11d2-ba4b-00a0c93ec93b",
+        "c12a7328-f81f-11d2-ba4b-00a0c93ec93b}",
+        "+12a7328-f81f-11d2-ba4b-00a0c93ec93b",
+    ];
+    for s in invalid {
+        assert!(s.parse::<Guid>().is_err(), "{s}");
+    }
+
+    let error = "x".parse::<Guid>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid GUID syntax");
+    let _: &dyn std::error::Error = &error;
+}
@@ -0,0 +1,107 @@
+use std::io::{Cursor, Seek, SeekFrom, Write};
+
+use castflip::{BE, Cast, DecastIO, DecastSeek, Flip, LE, Placeholder};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Header {
+    magic:  u32,
+    size:   u32,    // Size of the body
+}
+
+
+#[test]
+fn reserve_and_patch() {
+    let mut output = Cursor::new(Vec::new());
+
+    let hdr = output.reserve::<Header>().unwrap();
+    assert_eq!(hdr.offset(), 0);
+    assert_eq!(output.position(), 8);
+
+    let count = output.reserve::<u16>().unwrap();
+    assert_eq!(count.offset(), 8);
+
+    let size = output.decastsf(&[0x0102_u16, 0x0304, 0x0506], BE).unwrap();
+
+    let nbytes = output.patch(count, &3, LE).unwrap();
+    assert_eq!(nbytes, 2);
+    assert_eq!(output.position(), 16);
+
+    let nbytes = output.patch(hdr, &Header { magic: 0xCAFE, size: size as u32 },
+                              BE).unwrap();
+    assert_eq!(nbytes, 8);
+    assert_eq!(output.position(), 16);
+
+    // Writing continues at the end.
+    output.write_all(&[0xFF]).unwrap();
+
+    assert_eq!(output.into_inner(),
+               [0x00, 0x00, 0xCA, 0xFE, 0x00, 0x00, 0x00, 0x06,
+                0x03, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+                0xFF]);
+}
+
+#[test]
+fn patch_restores_position() {
+    let mut output = Cursor::new(Vec::new());
+
+    output.write_all(&[0xAA; 4]).unwrap();
+    let value = output.reserve::<u32>().unwrap();
+    output.write_all(&[0xBB; 4]).unwrap();
+
+    // Patch after seeking into the middle.
+    output.seek(SeekFrom::Start(2)).unwrap();
+    output.patch(value, &0x11223344, LE).unwrap();
+    assert_eq!(output.position(), 2);
+
+    assert_eq!(output.into_inner(),
+               [0xAA, 0xAA, 0xAA, 0xAA, 0x44, 0x33, 0x22, 0x11,
+                0xBB, 0xBB, 0xBB, 0xBB]);
+}
+
+#[test]
+fn reserve_large() {
+    let mut output = Cursor::new(Vec::new());
+
+    let array = output.reserve::<[u32; 40]>().unwrap();
+    assert_eq!(output.position(), 160);
+
+    let mut value = [0_u32; 40];
+    for (i, v) in value.iter_mut().enumerate() {
+        *v = i as u32;
+    }
+    output.patch(array, &value, BE).unwrap();
+
+    let bytes = output.into_inner();
+    assert_eq!(bytes.len(), 160);
+    assert_eq!(bytes[156 ..], [0x00, 0x00, 0x00, 0x27]);
+}
+
+#[test]
+fn vec_in_memory() {
+    let mut output = Vec::new();
+
+    output.push(0x7F);
+    let size = Placeholder::<u32>::reserve_in(&mut output);
+    assert_eq!(size.offset(), 1);
+    assert_eq!(output, [0x7F, 0x00, 0x00, 0x00, 0x00]);
+
+    output.extend_from_slice(&[0xEE; 3]);
+
+    let nbytes = size.patch_in(&mut output, &3, BE).unwrap();
+    assert_eq!(nbytes, 4);
+    assert_eq!(output, [0x7F, 0x00, 0x00, 0x00, 0x03, 0xEE, 0xEE, 0xEE]);
+}
+
+#[test]
+fn vec_out_of_range() {
+    let mut output = Vec::new();
+
+    output.extend_from_slice(&[0xEE; 4]);
+    let value = Placeholder::<u64>::reserve_in(&mut output);
+
+    // The reserved space is out of the truncated buffer.
+    output.truncate(8);
+    assert_eq!(value.patch_in(&mut output, &1, LE), None);
+}
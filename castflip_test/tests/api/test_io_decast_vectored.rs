@@ -0,0 +1,183 @@
+use std::io::{self, IoSlice, Write};
+
+use castflip::{BE, Cast, DecastIO, DecastPart, Flip, LE, NE, SE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Flip)]
+struct Header {
+    kind:   u16,
+    len:    u16,
+}
+
+// A record which is larger than the scratch buffer of
+// `decast_vectored` and whose fields are flipped one by one.
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Flip)]
+struct Large {
+    tag:    u32,
+    words:  [u32; 400],
+}
+
+
+// Is a writer which records the number of calls to method
+// `write_vectored` and writes at most `limit` bytes at a time.
+struct LimitedWriter {
+    bytes: Vec<u8>,
+    limit: usize,
+    ncalls: usize,
+}
+
+impl Write for LimitedWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.write_vectored(&[IoSlice::new(buf)])
+    }
+
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        self.ncalls += 1;
+        let mut nbytes = 0;
+        for buf in bufs {
+            let len = buf.len().min(self.limit - nbytes);
+            self.bytes.extend_from_slice(&buf[.. len]);
+            nbytes += len;
+        }
+        Ok(nbytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+
+#[test]
+fn header_and_payload() {
+    let hdr = Header { kind: 0x0102, len: 6 };
+    let payload: &[u16] = &[0x1122, 0x3344, 0x5566];
+
+    let mut output = Vec::new();
+    let size = output.decast_vectored(&[&hdr, &payload], BE).unwrap();
+    assert_eq!(size, 10);
+    assert_eq!(output, [0x01, 0x02, 0x00, 0x06,
+                        0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+
+    let mut output = Vec::new();
+    let size = output.decast_vectored(&[&hdr, &payload], LE).unwrap();
+    assert_eq!(size, 10);
+    assert_eq!(output, [0x02, 0x01, 0x06, 0x00,
+                        0x22, 0x11, 0x44, 0x33, 0x66, 0x55]);
+}
+
+#[test]
+fn native_endian() {
+    let value = 0x12345678_u32;
+    let vec = vec![1_u8, 2, 3];
+
+    let mut expected = Vec::new();
+    expected.extend_from_slice(&value.to_ne_bytes());
+    expected.extend_from_slice(&vec);
+
+    let mut output = Vec::new();
+    let size = output.decast_vectored(&[&value, &vec], NE).unwrap();
+    assert_eq!(size, 7);
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn single_call() {
+    let mut output = LimitedWriter { bytes: Vec::new(), limit: 64, ncalls: 0 };
+
+    let parts: [&dyn DecastPart; 3] = [&1_u16, &[2_u32, 3], &4_u8];
+    let size = output.decast_vectored(&parts, BE).unwrap();
+
+    assert_eq!(size, 11);
+    assert_eq!(output.ncalls, 1);
+    assert_eq!(output.bytes, [0x00, 0x01, 0x00, 0x00, 0x00, 0x02,
+                              0x00, 0x00, 0x00, 0x03, 0x04]);
+}
+
+#[test]
+fn partial_writes() {
+    let mut output = LimitedWriter { bytes: Vec::new(), limit: 3, ncalls: 0 };
+
+    let empty: &[u32] = &[];
+    let payload = vec![0x0A0B_u16; 4];
+    let size = output.decast_vectored(&[&empty, &0x01020304_u32, &payload],
+                                      LE).unwrap();
+
+    assert_eq!(size, 12);
+    assert_eq!(output.ncalls, 4);
+    assert_eq!(output.bytes, [0x04, 0x03, 0x02, 0x01,
+                              0x0B, 0x0A, 0x0B, 0x0A, 0x0B, 0x0A, 0x0B, 0x0A]);
+}
+
+#[test]
+fn empty_parts() {
+    let mut output = LimitedWriter { bytes: Vec::new(), limit: 0, ncalls: 0 };
+
+    let empty: &[u64] = &[];
+    assert_eq!(output.decast_vectored(&[], BE).unwrap(), 0);
+    assert_eq!(output.decast_vectored(&[&empty], BE).unwrap(), 0);
+    assert_eq!(output.ncalls, 0);
+
+    // The writer cannot accept any byte.
+    let err = output.decast_vectored(&[&1_u8], BE).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+}
+
+#[test]
+fn need_flip_part() {
+    let bytes = vec![0x01_u8, 0x02, 0x03];
+    let rec = Header { kind: 0x7F45, len: 4 };
+
+    // The byte parts are written as they are.
+    assert!(!0x01_u8.need_flip_part(BE));
+    assert!(![0x01_u8, 0x02].need_flip_part(BE));
+    assert!(!bytes.need_flip_part(BE));
+
+    assert!(0x0102_u16.need_flip_part(BE));
+    assert!(rec.need_flip_part(BE));
+    assert!(!rec.need_flip_part(NE));
+
+    let mut output = Vec::new();
+    let size = output.decast_vectored(&[&rec, &bytes, &0x0405_u16], BE)
+        .unwrap();
+    assert_eq!(size, 9);
+    assert_eq!(output, [0x7F, 0x45, 0x00, 0x04,
+                        0x01, 0x02, 0x03, 0x04, 0x05]);
+}
+
+#[test]
+fn larger_than_scratch() {
+    let mut large = Large { tag: 0x01020304, words: [0; 400] };
+    for (i, word) in large.words.iter_mut().enumerate() {
+        *word = (i as u32).wrapping_mul(0x01030507);
+    }
+    let payload: Vec<u16> = (0 .. 1500).collect();
+
+    let mut expected = Vec::new();
+    expected.decastf(&0x0A0B_u16, SE).unwrap();
+    expected.decastf(&large, SE).unwrap();
+    expected.decastsf(&payload, SE).unwrap();
+    expected.decastsf(&[0x01_u8, 0x02], SE).unwrap();
+
+    // The parts are written in multiple rounds.
+    let mut output = LimitedWriter {
+        bytes: Vec::new(), limit: usize::MAX, ncalls: 0,
+    };
+    let parts: [&dyn DecastPart; 4] =
+        [&0x0A0B_u16, &large, &payload, &[0x01_u8, 0x02]];
+    let size = output.decast_vectored(&parts, SE).unwrap();
+    assert_eq!(size, expected.len());
+    assert_eq!(output.bytes, expected);
+    assert!(output.ncalls > 1);
+
+    // Each part is decasted in chunks.
+    let mut chunk = [0_u8; 7];
+    assert_eq!(large.decast_part(0, &mut chunk, SE), 7);
+    assert_eq!(chunk, expected[2 .. 9]);
+    assert_eq!(large.decast_part(5, &mut chunk, SE), 7);
+    assert_eq!(chunk, expected[7 .. 14]);
+    assert_eq!(payload.decast_part(10, &mut chunk, SE), 6);
+    assert_eq!(chunk[.. 6], expected[2 + 1604 + 10 .. 2 + 1604 + 16]);
+}
@@ -0,0 +1,109 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use castflip::{BE, Cast, EncastBufRead, Flip, LE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    id:     u32,
+    len:    u16,
+    flags:  [u8; 2],
+}
+
+const BYTES: [u8; 16] = [
+    0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0xAA, 0xBB,
+    0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0xCC, 0xDD,
+];
+
+
+// Is a reader which records the number of calls to method `read`.
+struct CountingReader<'a> {
+    bytes: &'a [u8],
+    nreads: usize,
+}
+
+impl Read for CountingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.nreads += 1;
+        self.bytes.read(buf)
+    }
+}
+
+
+#[test]
+fn within_buffer() {
+    let mut input = BufReader::new(CountingReader { bytes: &BYTES, nreads: 0 });
+
+    let rec1 = input.encastf_buf::<Record>(LE).unwrap();
+    let rec2 = input.encastf_buf::<Record>(LE).unwrap();
+
+    assert_eq!(rec1, Record { id: 1, len: 0x10, flags: [0xAA, 0xBB] });
+    assert_eq!(rec2, Record { id: 2, len: 0x20, flags: [0xCC, 0xDD] });
+
+    // Both records are decoded from the buffer filled at once.
+    assert_eq!(input.get_ref().nreads, 1);
+    assert!(input.fill_buf().unwrap().is_empty());
+}
+
+#[test]
+fn across_buffer_boundary() {
+    // The buffer of 5 bytes cannot hold a whole record.
+    let mut input = BufReader::with_capacity(5, &BYTES[..]);
+
+    let rec1 = input.encastf_buf::<Record>(BE).unwrap();
+    assert_eq!(rec1.id, 0x01000000);
+    assert_eq!(rec1.len, 0x1000);
+
+    let value = input.encast_buf::<[u8; 3]>().unwrap();
+    assert_eq!(value, [0x02, 0x00, 0x00]);
+
+    let value = input.encastf_buf::<u32>(LE).unwrap();
+    assert_eq!(value, 0xCC00_2000);
+
+    let value = input.encast_buf::<u8>().unwrap();
+    assert_eq!(value, 0xDD);
+}
+
+#[test]
+fn peek() {
+    let mut input = BufReader::new(&BYTES[..]);
+
+    assert_eq!(input.peek_encastf::<u32>(LE).unwrap(), Some(1));
+    assert_eq!(input.peek_encastf::<u32>(BE).unwrap(), Some(0x01000000));
+    assert_eq!(input.peek_encast::<[u8; 2]>().unwrap(), Some([0x01, 0x00]));
+
+    // Nothing has been consumed.
+    assert_eq!(input.encastf_buf::<Record>(LE).unwrap().id, 1);
+    assert_eq!(input.peek_encastf::<u16>(LE).unwrap(), Some(2));
+}
+
+#[test]
+fn peek_not_buffered() {
+    let mut input = BufReader::with_capacity(4, &BYTES[..]);
+
+    // Only 4 bytes are buffered.
+    assert_eq!(input.peek_encastf::<u64>(LE).unwrap(), None);
+    assert_eq!(input.peek_encastf::<u32>(LE).unwrap(), Some(1));
+
+    // At the end of file.
+    let mut input: &[u8] = &[];
+    assert_eq!(input.peek_encast::<u8>().unwrap(), None);
+}
+
+#[test]
+fn unexpected_eof() {
+    let mut input = BufReader::new(&BYTES[.. 6]);
+
+    let err = input.encastf_buf::<Record>(LE).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn peek_at_eof() {
+    let mut input = BufReader::new(CountingReader { bytes: &[], nreads: 0 });
+
+    // The inner reader is read only once at the end of file.
+    assert_eq!(input.peek_encastf::<u32>(LE).unwrap(), None);
+    assert_eq!(input.get_ref().nreads, 1);
+}
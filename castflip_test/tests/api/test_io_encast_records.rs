@@ -0,0 +1,111 @@
+use std::io::{self, Cursor, Read};
+
+use castflip::{BE, Cast, EncastIO, Flip, LE, TruncatedRecord};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Frame {
+    kind:   u16,
+    len:    u16,
+    seq:    u32,
+}
+
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Empty;
+
+
+// A reader which returns at most 3 bytes at a time and is sometimes
+// interrupted.
+struct Choppy<'a> {
+    bytes:  &'a [u8],
+    count:  usize,
+}
+
+impl Read for Choppy<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.count += 1;
+        if self.count.is_multiple_of(4) {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+        let nbytes = buf.len().min(self.bytes.len()).min(3);
+        buf[.. nbytes].copy_from_slice(&self.bytes[.. nbytes]);
+        self.bytes = &self.bytes[nbytes ..];
+        Ok(nbytes)
+    }
+}
+
+
+const FRAMES: [u8; 24] = [
+    0x00, 0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0x01,
+    0x00, 0x02, 0x00, 0x20, 0x00, 0x00, 0x00, 0x02,
+    0x00, 0x03, 0x00, 0x30, 0x00, 0x00, 0x00, 0x03,
+];
+
+fn expected(i: u16) -> Frame {
+    Frame { kind: i, len: i * 0x10, seq: i as u32 }
+}
+
+
+#[test]
+fn clean_eof() {
+    let mut input = Cursor::new(FRAMES);
+    let frames: Vec<Frame> = input.encast_records::<Frame>(BE)
+        .collect::<io::Result<_>>()
+        .unwrap();
+    assert_eq!(frames, [expected(1), expected(2), expected(3)]);
+
+    // The reader is at the end.
+    assert_eq!(input.position(), 24);
+}
+
+#[test]
+fn empty_input() {
+    let mut input: &[u8] = &[];
+    assert!(input.encast_records::<u32>(LE).next().is_none());
+}
+
+#[test]
+fn zero_sized() {
+    let mut input = Cursor::new([0x12_u8, 0x34]);
+    assert!(input.encast_records::<Empty>(LE).next().is_none());
+    assert!(input.encast_records::<[u32; 0]>(BE).next().is_none());
+
+    // No byte is consumed.
+    assert_eq!(input.position(), 0);
+}
+
+#[test]
+fn truncated_record() {
+    for len in 17 .. 24 {
+        let mut input = &FRAMES[.. len];
+        let mut records = input.encast_records::<Frame>(BE);
+
+        assert_eq!(records.next().unwrap().unwrap(), expected(1));
+        assert_eq!(records.next().unwrap().unwrap(), expected(2));
+
+        let err = records.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let truncated = TruncatedRecord::from_io_error(&err).unwrap();
+        assert_eq!(truncated.nbytes(), len - 16);
+        assert_eq!(truncated.size(), 8);
+
+        assert!(records.next().is_none());
+    }
+}
+
+#[test]
+fn partial_reads() {
+    let mut input = Choppy { bytes: &FRAMES, count: 0 };
+    let frames: Vec<Frame> = input.encast_records::<Frame>(BE)
+        .collect::<io::Result<_>>()
+        .unwrap();
+    assert_eq!(frames, [expected(1), expected(2), expected(3)]);
+}
+
+#[test]
+fn other_errors() {
+    let err = io::Error::other("failed");
+    assert!(TruncatedRecord::from_io_error(&err).is_none());
+}
@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::io::{self, Cursor, Seek, SeekFrom};
+
+use castflip::{BE, Cast, EncastSeek, Flip, LE, NE, OffsetError};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Header {
+    magic:  u32,
+    off:    u32,    // Offset of the table
+    num:    u16,    // Number of entries in the table
+    pad:    u16,
+}
+
+const BYTES: [u8; 24] = [
+    0x7F, 0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00,
+    0x04, 0x00, 0x00, 0x00, 0xEE, 0xEE, 0xEE, 0xEE,
+    0x01, 0x00, 0x02, 0x00, 0x03, 0x00, 0x04, 0x00,
+];
+
+
+#[test]
+fn navigate() {
+    let mut input = Cursor::new(BYTES);
+
+    let hdr: Header = input.encastf_at(0, LE).unwrap();
+    assert_eq!(hdr, Header { magic: 0x7F, off: 0x10, num: 4, pad: 0 });
+
+    let table = input.encastvf_at::<u16>(hdr.off as u64, hdr.num as usize, LE)
+        .unwrap();
+    assert_eq!(table, [1, 2, 3, 4]);
+    assert_eq!(input.position(), 24);
+
+    let mut slice = [0_u16; 2];
+    let size = input.encastsf_at(0x12, &mut slice, BE).unwrap();
+    assert_eq!(size, 4);
+    assert_eq!(slice, [0x0200, 0x0300]);
+
+    let magic = input.encast_at::<[u8; 4]>(0).unwrap();
+    assert_eq!(magic, [0x7F, 0, 0, 0]);
+
+    let mut slice = [0_u8; 3];
+    input.encasts_at(0x0C, &mut slice).unwrap();
+    assert_eq!(slice, [0xEE; 3]);
+
+    let vec = input.encastv_at::<u8>(0x16, 2).unwrap();
+    assert_eq!(vec, [0x04, 0x00]);
+}
+
+#[test]
+fn error_with_offset() {
+    let mut input = Cursor::new(BYTES);
+
+    let err = input.encastvf_at::<u32>(0x14, 2, NE).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+    let offset_err = OffsetError::from_io_error(&err).unwrap();
+    assert_eq!(offset_err.offset(), 0x14);
+    assert_eq!(offset_err.error().kind(), io::ErrorKind::UnexpectedEof);
+    assert!(offset_err.source().is_some());
+    assert!(err.to_string().contains("0x14"));
+}
+
+#[test]
+fn with_position() {
+    let mut input = Cursor::new(BYTES);
+    input.seek(SeekFrom::Start(5)).unwrap();
+
+    // Success
+    let num = input.with_position(|input| input.encastf_at::<u16>(8, LE))
+        .unwrap();
+    assert_eq!(num, 4);
+    assert_eq!(input.position(), 5);
+
+    // Failure
+    let err = input.with_position(|input| input.encastf_at::<u64>(20, LE))
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    assert_eq!(input.position(), 5);
+}
@@ -0,0 +1,250 @@
+use std::io::{self, Cursor};
+
+use castflip::{
+    BE, Cast, DecastIO, DecastMem, EncastIO, EncastMem, Endian, Flip,
+    FlipReader, FlipWriter, LE, NE, SE, U24, encast_const, to_bytes_const,
+};
+
+
+const PDP: Endian = Endian::Pdp;
+const WS: Endian = Endian::WordSwapped;
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    val1:   u32,
+    val2:   u16,
+    val3:   [u8; 2],
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Flip, PartialEq)]
+struct FpaRecord {
+    val:    f64,
+    count:  u32,
+    flags:  u32,
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Pair(u32, u16, u16);
+
+
+// Implements trait Flip manually without overriding the conversion
+// for the mixed endiannesses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Plain(u32);
+
+impl Flip for Plain {
+    fn flip_val_swapped(&self) -> Self {
+        Plain(self.0.swap_bytes())
+    }
+}
+
+// Implements trait Flip manually with the conversion for the mixed
+// endiannesses.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Mixed(u32);
+
+impl Flip for Mixed {
+    fn flip_val_swapped(&self) -> Self {
+        Mixed(self.0.swap_bytes())
+    }
+
+    fn flip_val_mixed(&self, endian: Endian) -> Self {
+        Mixed(self.0.flip_val(endian))
+    }
+}
+
+
+const PDP_BYTES: [u8; 4] = [0x0B, 0x0A, 0x0D, 0x0C];
+const PDP_VALUE: u32 = encast_const::<u32>(&PDP_BYTES, PDP).unwrap();
+const PDP_ARRAY: [u8; 8] = to_bytes_const(&[0x0A0B0C0D_u32, 0x01020304], PDP);
+const PDP_SHORT: [u8; 2] = to_bytes_const(&0x1234_u16, PDP);
+
+
+#[test]
+fn endian() {
+    assert_eq!(PDP.relative(), PDP);
+    assert_eq!(PDP.absolute(), PDP);
+    assert_eq!(WS.relative(), WS);
+    assert_eq!(WS.absolute(), WS);
+
+    assert!(!PDP.need_swap());
+    assert!(!WS.need_swap());
+    assert!(PDP.need_flip());
+    assert!(WS.need_flip());
+    assert!(!NE.need_flip());
+    assert!(SE.need_flip());
+
+    assert_eq!(PDP.name(), "Pdp");
+    assert_eq!(WS.name(), "WordSwapped");
+
+    assert!(PDP.is_mixed());
+    assert!(WS.is_mixed());
+    assert!(!LE.is_mixed());
+    assert!(!BE.relative().is_mixed());
+}
+
+#[test]
+fn flip_val() {
+    assert_eq!(0x0A0B0C0D_u32.flip_val(PDP).to_ne_bytes(), PDP_BYTES);
+    assert_eq!(0x0A0B0C0D_i32.flip_val(PDP).to_ne_bytes(), PDP_BYTES);
+    assert_eq!(u32::from_ne_bytes(PDP_BYTES).flip_val(PDP), 0x0A0B0C0D);
+
+    let bytes = [0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01];
+    assert_eq!(0x0102030405060708_u64.flip_val(PDP).to_ne_bytes(),
+               [0x02, 0x01, 0x04, 0x03, 0x06, 0x05, 0x08, 0x07]);
+    assert_eq!(0x0102030405060708_u64.flip_val(WS).to_ne_bytes(),
+               [0x04, 0x03, 0x02, 0x01, 0x08, 0x07, 0x06, 0x05]);
+    assert_eq!(0x0102030405060708_u64.flip_val(LE).to_ne_bytes(), bytes);
+
+    // A value which is not larger than a word is in little-endian.
+    assert_eq!(0x1234_u16.flip_val(PDP), 0x1234_u16.flip_val(LE));
+    assert_eq!(0x01020304_u32.flip_val(WS), 0x01020304_u32.flip_val(LE));
+    assert_eq!(1.5_f32.flip_val(WS).to_bits(), 1.5_f32.flip_val(LE).to_bits());
+    assert_eq!(0x12_u8.flip_val(PDP), 0x12);
+
+    // So is a value whose size is not a multiple of a word.
+    let value = U24::from_u32(0x123456);
+    assert_eq!(value.flip_val(PDP), value.flip_val(LE));
+
+    // The conversion is an involution.
+    let mut value = 0x0A0B0C0D_u32;
+    value.flip_var(PDP);
+    value.flip_var(PDP);
+    assert_eq!(value, 0x0A0B0C0D);
+}
+
+#[test]
+fn manual_flip() {
+    // A manual implementation is converted as in little-endian by
+    // default.
+    assert_eq!(Plain(0x0A0B0C0D).flip_val(PDP), Plain(0x0A0B0C0D).flip_val(LE));
+    assert_eq!(Mixed(0x0A0B0C0D).flip_val(PDP).0.to_ne_bytes(), PDP_BYTES);
+
+    let mut value = Mixed(0x0A0B0C0D);
+    value.flip_var(WS);
+    assert_eq!(value, Mixed(0x0A0B0C0D).flip_val(LE));
+}
+
+#[test]
+fn fpa_double() {
+    let value = 1.0_f64 / 3.0;
+    let le = value.to_le_bytes();
+    let expected = [le[4], le[5], le[6], le[7], le[0], le[1], le[2], le[3]];
+
+    let mut bytes = [0_u8; 8];
+    bytes.decastf(&value, WS).unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(bytes.encastf::<f64>(WS).unwrap(), value);
+
+    let input: &[u8] = &[0x00, 0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00];
+    assert_eq!(input.encastf::<f64>(WS).unwrap(), 1.0);
+}
+
+#[test]
+fn mem_encastf() {
+    assert_eq!(PDP_VALUE, 0x0A0B0C0D);
+    assert_eq!(PDP_BYTES.encastf::<u32>(PDP).unwrap(), 0x0A0B0C0D);
+
+    let bytes: [u8; 10] = [
+        0x0B, 0x0A, 0x0D, 0x0C, 0x34, 0x12, 0x56, 0x78, 0xFF, 0xFF,
+    ];
+    let record: Record = bytes.encastf(PDP).unwrap();
+    assert_eq!(record, Record {
+        val1: 0x0A0B0C0D,
+        val2: 0x1234,
+        val3: [0x56, 0x78],
+    });
+
+    let pair: Pair = bytes.encastf(PDP).unwrap();
+    assert_eq!(pair, Pair(0x0A0B0C0D, 0x1234, 0x7856));
+
+    let mut output = [0_u8; 8];
+    output.decastf(&record, PDP).unwrap();
+    assert_eq!(output, bytes[.. 8]);
+}
+
+#[test]
+fn mem_struct_word_swapped() {
+    let record = FpaRecord { val: -2.5, count: 0x01020304, flags: 7 };
+
+    let mut bytes = [0_u8; 16];
+    bytes.decastf(&record, WS).unwrap();
+
+    let le = (-2.5_f64).to_le_bytes();
+    assert_eq!(bytes[.. 4], le[4 ..]);
+    assert_eq!(bytes[4 .. 8], le[.. 4]);
+    assert_eq!(bytes[8 .. 12], [0x04, 0x03, 0x02, 0x01]);
+    assert_eq!(bytes[12 ..], [0x07, 0x00, 0x00, 0x00]);
+
+    assert_eq!(bytes.encastf::<FpaRecord>(WS).unwrap(), record);
+}
+
+#[test]
+fn mem_slice() {
+    assert_eq!(PDP_ARRAY, [0x0B, 0x0A, 0x0D, 0x0C, 0x02, 0x01, 0x04, 0x03]);
+    assert_eq!(PDP_SHORT, [0x34, 0x12]);
+
+    let mut values = [0_u32; 2];
+    PDP_ARRAY.encastsf(&mut values, PDP).unwrap();
+    assert_eq!(values, [0x0A0B0C0D, 0x01020304]);
+
+    let mut bytes = [0_u8; 8];
+    bytes.decastsf(&values, PDP).unwrap();
+    assert_eq!(bytes, PDP_ARRAY);
+
+    let values = [1.0_f64, -0.5];
+    let mut bytes = [0_u8; 16];
+    bytes.decastsf(&values, WS).unwrap();
+    assert_eq!(bytes[.. 8], [0x00, 0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00]);
+    assert_eq!(bytes[8 ..], [0x00, 0x00, 0xE0, 0xBF, 0x00, 0x00, 0x00, 0x00]);
+
+    let mut output = [0_f64; 2];
+    bytes.encastsf(&mut output, WS).unwrap();
+    assert_eq!(output, values);
+
+    let records: Vec<Record> = [PDP_ARRAY; 2].concat().encastvf(2, PDP).unwrap();
+    assert_eq!(records[1].val1, 0x0A0B0C0D);
+    assert_eq!(records[1].val2, 0x0102);
+}
+
+#[test]
+fn io() {
+    let mut input = Cursor::new([PDP_BYTES, PDP_BYTES].concat());
+    assert_eq!(input.encastf::<u32>(PDP).unwrap(), 0x0A0B0C0D);
+
+    let mut values = [0_u32; 1];
+    input.encastsf(&mut values, PDP).unwrap();
+    assert_eq!(values, [0x0A0B0C0D]);
+
+    let mut output = Cursor::new(Vec::new());
+    output.decastf(&0x0A0B0C0D_u32, PDP).unwrap();
+    output.decastsf(&[1.0_f64], WS).unwrap();
+    assert_eq!(output.get_ref()[.. 4], PDP_BYTES);
+    assert_eq!(output.get_ref()[4 ..],
+               [0x00, 0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn flip_reader() {
+    let input = [PDP_BYTES, [0x02, 0x01, 0x04, 0x03]].concat();
+
+    let mut output = Vec::new();
+    let mut reader = FlipReader::<_, u32>::new(&input[..], PDP, BE);
+    io::copy(&mut reader, &mut output).unwrap();
+    assert_eq!(output, [0x0A, 0x0B, 0x0C, 0x0D, 0x01, 0x02, 0x03, 0x04]);
+}
+
+#[test]
+fn flip_writer() {
+    let input = [0x0A, 0x0B, 0x0C, 0x0D, 0x01, 0x02, 0x03, 0x04];
+
+    let mut writer = FlipWriter::<_, u32>::new(Vec::new(), BE, PDP);
+    io::copy(&mut &input[..], &mut writer).unwrap();
+    let output = writer.into_inner();
+    assert_eq!(output[.. 4], PDP_BYTES);
+    assert_eq!(output[4 ..], [0x02, 0x01, 0x04, 0x03]);
+}
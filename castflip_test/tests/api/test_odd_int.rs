@@ -0,0 +1,126 @@
+use std::io::Cursor;
+
+use castflip::{
+    BE, Cast, DecastMem, EncastIO, EncastMem, Flip, I24, I48, LE, U24, U48,
+};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Packet {
+    kind:   u8,
+    len:    U24,
+    mac:    U48,
+    stamp:  I48,
+    sample: I24,
+}
+
+const BYTES_BE: [u8; 19] = [
+    0x01, 0x00, 0x01, 0x02, 0x00, 0x1B, 0x63, 0x84,
+    0x45, 0xE6, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE,
+    0x80, 0x00, 0x00,
+];
+
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<U24>(), 3);
+    assert_eq!(size_of::<I24>(), 3);
+    assert_eq!(size_of::<U48>(), 6);
+    assert_eq!(size_of::<I48>(), 6);
+    assert_eq!(align_of::<I48>(), 1);
+    assert_eq!(size_of::<Packet>(), 19);
+}
+
+#[test]
+fn conversion() {
+    assert_eq!(U24::from_u32(0x12_345678).to_u32(), 0x345678);
+    assert_eq!(U24::MIN.to_u32(), 0);
+    assert_eq!(U24::MAX.to_u32(), 0xFF_FFFF);
+
+    assert_eq!(I24::from_i32(-1).to_i32(), -1);
+    assert_eq!(I24::from_i32(0x80_0000).to_i32(), -0x80_0000);
+    assert_eq!(I24::MIN.to_i32(), -0x80_0000);
+    assert_eq!(I24::MAX.to_i32(), 0x7F_FFFF);
+
+    assert_eq!(U48::from_u64(u64::MAX).to_u64(), 0xFFFF_FFFF_FFFF);
+    assert_eq!(I48::from_i64(-0x8000_0000_0000).to_i64(), -0x8000_0000_0000);
+    assert_eq!(I48::from_i64(0x8000_0000_0000).to_i64(), -0x8000_0000_0000);
+    assert_eq!(I48::MAX.to_i64(), 0x7FFF_FFFF_FFFF);
+    assert_eq!(i64::from(I48::from_i64(-5)), -5);
+
+    let value = U24::from_u32(0x123456);
+    assert_eq!(value.to_be_bytes(), [0x12, 0x34, 0x56]);
+    assert_eq!(value.to_le_bytes(), [0x56, 0x34, 0x12]);
+    assert_eq!(value.to_ne_bytes(), 0x123456_u32.to_ne_bytes()[
+        if cfg!(target_endian = "little") { 0 .. 3 } else { 1 .. 4 }]);
+    assert_eq!(U24::from_le_bytes([0x56, 0x34, 0x12]), value);
+    assert_eq!(U24::from_ne_bytes(value.to_ne_bytes()), value);
+
+    assert!(I24::from_i32(-1) < I24::from_i32(0));
+    assert!(U24::from_u32(0x100) > U24::from_u32(0xFF));
+    assert_eq!(format!("{}", I24::from_i32(-42)), "-42");
+    assert_eq!(format!("{:?}", U48::from_u64(42)), "42");
+}
+
+#[test]
+fn flip() {
+    let value = I24::from_i32(-2);
+    assert_eq!(value.flip_val(BE).to_be_bytes(), value.to_ne_bytes());
+    assert_eq!(value.flip_val_swapped().flip_val_swapped(), value);
+
+    let pkt: Packet = BYTES_BE.encastf(BE).unwrap();
+    assert_eq!(pkt.kind, 1);
+    assert_eq!(pkt.len.to_u32(), 0x102);
+    assert_eq!(pkt.mac.to_u64(), 0x001B_6384_45E6);
+    assert_eq!(pkt.stamp.to_i64(), -2);
+    assert_eq!(pkt.sample.to_i32(), -0x80_0000);
+
+    let mut input = Cursor::new(BYTES_BE);
+    assert_eq!(input.encastf::<Packet>(BE).unwrap(), pkt);
+
+    let mut bytes = [0_u8; 19];
+    assert_eq!(bytes.decastf(&pkt, BE), Some(19));
+    assert_eq!(bytes, BYTES_BE);
+
+    let mut bytes_le = [0_u8; 19];
+    bytes_le.decastf(&pkt, LE).unwrap();
+    assert_eq!(bytes_le.encastf::<Packet>(LE), Some(pkt));
+    assert_eq!(bytes_le[1 .. 4], [0x02, 0x01, 0x00]);
+}
+
+#[test]
+fn slice() {
+    // 24-bit PCM samples in little-endian.
+    let bytes: [u8; 15] = [
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0x7F, 0x00, 0x00, 0x80,
+    ];
+    let expected = [0, 1, -1, 0x7F_FFFF, -0x80_0000];
+
+    let mut samples = [I24::default(); 5];
+    assert_eq!(bytes.encastsf(&mut samples, LE), Some(15));
+
+    let mut buffer = [0_i32; 5];
+    I24::to_i32_slice(&samples, &mut buffer);
+    assert_eq!(buffer, expected);
+
+    let mut samples2 = [I24::default(); 5];
+    I24::from_i32_slice(&buffer, &mut samples2);
+    assert_eq!(samples2, samples);
+
+    let mut bytes2 = [0_u8; 15];
+    bytes2.decastsf(&samples2, LE).unwrap();
+    assert_eq!(bytes2, bytes);
+
+    let mut values = [0_u64; 2];
+    U48::to_u64_slice(&[U48::MAX, U48::MIN], &mut values);
+    assert_eq!(values, [0xFFFF_FFFF_FFFF, 0]);
+}
+
+#[test]
+#[should_panic]
+fn slice_length_mismatch() {
+    let mut buffer = [0_i32; 2];
+    I24::to_i32_slice(&[I24::default(); 3], &mut buffer);
+}
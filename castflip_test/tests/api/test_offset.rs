@@ -0,0 +1,99 @@
+use castflip::{BE, Cast, DecastMem, EncastMem, Flip, LE, Offset, SE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Entry {
+    kind:   u32,
+    value:  u32,
+}
+
+// A header pointing to a table of entries and a string.
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Header {
+    table:  Offset<Entry>,
+    count:  u32,
+    name:   Offset<[u8; 4], u16>,
+    next:   Offset<u16, i16>,       // Relative to its own position
+}
+
+const BYTES: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,     // table
+    0x00, 0x00, 0x00, 0x02,                             // count
+    0x00, 0x1C,                                         // name
+    0xFF, 0xFE,                                         // next (-2)
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x0A,     // entry 0
+    0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x0B,     // entry 1
+];
+const NEXT_POS: usize = 14;
+
+
+#[test]
+fn layout() {
+    assert_eq!(size_of::<Offset<Entry>>(), 8);
+    assert_eq!(size_of::<Offset<Entry, u16>>(), 2);
+    assert_eq!(size_of::<Header>(), 16);
+
+    let hdr: Header = BYTES.encastf(BE).unwrap();
+    assert_eq!(hdr.table, Offset::new(0x10));
+    assert_eq!(hdr.table.get(), 0x10);
+    assert_eq!(hdr.count, 2);
+    assert_eq!(hdr.name.get(), 0x1C);
+    assert_eq!(hdr.next.get(), -2);
+
+    let mut bytes = [0_u8; 16];
+    assert_eq!(bytes[..].decastf(&hdr, BE), Some(16));
+    assert_eq!(bytes, BYTES[.. 16]);
+
+    let swapped: Header = BYTES.encastf(LE).unwrap();
+    assert_eq!(swapped.table.get(), 0x10_u64.swap_bytes());
+    assert_eq!(swapped.flip_val(SE), hdr);
+}
+
+#[test]
+fn resolve() {
+    let hdr: Header = BYTES.encastf(BE).unwrap();
+
+    let table = hdr.table.resolve_slice(&BYTES, hdr.count as usize, BE);
+    assert_eq!(table, Ok(vec![Entry { kind: 1, value: 0x0A },
+                              Entry { kind: 2, value: 0x0B }]));
+    assert_eq!(hdr.table.resolve(&BYTES, BE),
+               Ok(Entry { kind: 1, value: 0x0A }));
+    assert_eq!(hdr.name.resolve(&BYTES, BE), Ok([0x00, 0x00, 0x00, 0x0B]));
+    assert_eq!(hdr.table.resolve_slice(&BYTES, 0, BE), Ok(vec![]));
+
+    // The next field points to the name field before it.
+    assert_eq!(hdr.next.resolve_relative(&BYTES, NEXT_POS, BE), Ok(0x001C));
+    assert_eq!(hdr.next.resolve_slice_relative(&BYTES, NEXT_POS, 2, BE),
+               Ok(vec![0x001C, 0xFFFE]));
+}
+
+#[test]
+fn out_of_range() {
+    let hdr: Header = BYTES.encastf(BE).unwrap();
+
+    // Too many entries.
+    let err = hdr.table.resolve_slice(&BYTES, 3, BE).unwrap_err();
+    assert_eq!(err.offset(), 0x10);
+    assert_eq!(err.size(), 24);
+    assert_eq!(err.buf_len(), 32);
+    assert_eq!(err.to_string(), "24 bytes at offset 16 are out of 32 bytes");
+
+    // The pointee straddles the end of the buffer.
+    assert!(Offset::<u32, u16>::new(29).resolve(&BYTES, BE).is_err());
+    assert!(Offset::<u32, u16>::new(28).resolve(&BYTES, BE).is_ok());
+
+    // A negative offset before the start of the buffer.
+    let err = hdr.next.resolve_relative(&BYTES, 1, BE).unwrap_err();
+    assert_eq!(err.offset(), -1);
+
+    // The size of the values overflows.
+    let err = hdr.table.resolve_slice(&BYTES, usize::MAX, BE).unwrap_err();
+    assert_eq!(err.size(), usize::MAX);
+
+    // The offset is larger than any address.
+    let far = Offset::<u8>::new(u64::MAX);
+    assert_eq!(far.resolve(&BYTES, BE).unwrap_err().offset(),
+               u64::MAX as i128);
+}
@@ -0,0 +1,113 @@
+use core::mem::size_of;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use castflip::{
+    BE, DecastMem, Flip, LE, NE, PAR_CHUNK_SIZE, PAR_THRESHOLD, ParDecastMem,
+    ParEncastMem, ParFlip, SE,
+};
+
+
+// The numbers of elements below and above the threshold.  The larger
+// one is not a multiple of the chunk size so that the last chunk is
+// shorter than the others.
+const SMALL: usize = 0x101;
+const LARGE: usize = PAR_THRESHOLD / size_of::<u32>() * 3 + 0x101;
+
+
+fn gen_vals(len: usize) -> Vec<u32> {
+    (0 .. len).map(|i| (i as u32).wrapping_mul(0x9E37_79B9)).collect()
+}
+
+
+#[test]
+fn par_flip_var() {
+    for len in [0, SMALL, LARGE] {
+        let ne_vals = gen_vals(len);
+        let se_vals: Vec<u32> = ne_vals.iter().map(|v| v.swap_bytes()).collect();
+
+        let mut ne_output = ne_vals.clone();
+        let mut se_output = ne_vals.clone();
+
+        ne_output.par_flip_var(NE);
+        se_output.par_flip_var(SE);
+
+        assert_eq!(ne_output, ne_vals);
+        assert_eq!(se_output, se_vals);
+    }
+}
+
+#[test]
+fn par_encastvf() {
+    for len in [0, SMALL, LARGE] {
+        let ne_vals = gen_vals(len);
+
+        for endian in [NE, SE, LE, BE] {
+            let mut bytes = vec![0_u8; size_of::<u32>() * len + 3];
+            bytes.decastsf(&ne_vals, endian).unwrap();
+
+            let output = bytes.par_encastvf::<u32>(len, endian).unwrap();
+            assert_eq!(output, ne_vals);
+
+            // Not enough source bytes.
+            assert_eq!(bytes[.. size_of::<u32>() * len]
+                       .par_encastvf::<u32>(len + 1, endian), None);
+        }
+    }
+}
+
+#[test]
+fn par_decastvf() {
+    for len in [0, SMALL, LARGE] {
+        let ne_vals = gen_vals(len);
+
+        for endian in [NE, SE, LE, BE] {
+            let mut expected = vec![0_u8; size_of::<u32>() * len + 3];
+            expected.decastsf(&ne_vals, endian).unwrap();
+
+            let mut output = vec![0_u8; size_of::<u32>() * len + 3];
+            let size = output.par_decastvf(&ne_vals, endian).unwrap();
+            assert_eq!(size, size_of::<u32>() * len);
+            assert_eq!(output, expected);
+
+            // Not enough space for the resulting bytes.
+            if len > 0 {
+                assert_eq!(output[.. size - 1].par_decastvf(&ne_vals, endian),
+                           None);
+            }
+        }
+    }
+}
+
+// Is the number of calls to `Flip::flip_slice_swapped` of `Counted`.
+static NSLICES: AtomicUsize = AtomicUsize::new(0);
+
+// A type which records the calls to `Flip::flip_slice_swapped`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Counted(u32);
+
+impl Flip for Counted {
+    fn flip_val_swapped(&self) -> Self {
+        Self(self.0.swap_bytes())
+    }
+
+    fn flip_slice_swapped(slice: &mut [Self]) {
+        NSLICES.fetch_add(1, Ordering::Relaxed);
+        for elem in slice {
+            elem.flip_var_swapped();
+        }
+    }
+}
+
+#[test]
+fn par_flip_chunks() {
+    let mut vals: Vec<Counted> =
+        gen_vals(LARGE).into_iter().map(Counted).collect();
+    let expected: Vec<Counted> =
+        vals.iter().map(|val| val.flip_val_swapped()).collect();
+
+    // Each chunk is flipped by a single call.
+    vals.par_flip_var(SE);
+    assert_eq!(vals, expected);
+    let nchunks = LARGE.div_ceil(PAR_CHUNK_SIZE / size_of::<Counted>());
+    assert_eq!(NSLICES.load(Ordering::Relaxed), nchunks);
+}
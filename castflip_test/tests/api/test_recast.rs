@@ -0,0 +1,66 @@
+use castflip::{BE, Cast, EncastMem, Flip, LE, NE, SE, recast, recastf};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Guid {
+    data1:  u32,
+    data2:  u16,
+    data3:  u16,
+    data4:  [u8; 8],
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct HeaderV1 {
+    magic:  u32,
+    len:    u32,
+}
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct HeaderV2 {
+    magic:  u32,
+    len:    u16,
+    flags:  u16,
+}
+
+const BYTES: [u8; 16] = [
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+    0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF,
+];
+
+
+#[test]
+fn same_size() {
+    let guid: Guid = recast(BYTES);
+    assert_eq!(guid, BYTES.encast::<Guid>().unwrap());
+    assert_eq!(recast::<Guid, [u8; 16]>(guid), BYTES);
+
+    let words: [u32; 4] = recast(BYTES);
+    let value: u128 = recast(words);
+    assert_eq!(value, u128::from_ne_bytes(BYTES));
+    assert_eq!(recast::<u128, [u32; 4]>(value), words);
+
+    assert_eq!(recast::<f64, u64>(1.5), 1.5_f64.to_bits());
+    assert_eq!(recast::<[u32; 0], [u8; 0]>([]), []);
+}
+
+#[test]
+fn flip_before_recast() {
+    let raw: Guid = recast(BYTES);
+
+    for endian in [BE, LE, NE, SE] {
+        let guid: Guid = recastf(raw, endian);
+        assert_eq!(guid, BYTES.encastf::<Guid>(endian).unwrap());
+
+        let bytes: [u8; 16] = recastf(raw, endian);
+        assert_eq!(bytes[8 ..], BYTES[8 ..]);
+    }
+
+    // The endianness is flipped with the source type.
+    let v1: HeaderV1 = BYTES.encast().unwrap();
+    let v2: HeaderV2 = recastf(v1, BE);
+    assert_eq!(v2.magic, 0x00112233);
+    assert_eq!(recast::<HeaderV2, HeaderV1>(v2).len, 0x44556677);
+}
@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+
+use castflip::{BE, Cast, DecastSplit, EncastSplit, Flip, LE};
+
+
+#[repr(C)]
+#[derive(Cast, Clone, Copy, Debug, Eq, Flip, PartialEq)]
+struct Record {
+    id:     u32,
+    len:    u16,
+    flags:  [u8; 2],
+}
+
+const BYTES: [u8; 16] = [
+    0x01, 0x00, 0x00, 0x00, 0x10, 0x00, 0xAA, 0xBB,
+    0x02, 0x00, 0x00, 0x00, 0x20, 0x00, 0xCC, 0xDD,
+];
+
+const REC1: Record = Record { id: 1, len: 0x10, flags: [0xAA, 0xBB] };
+const REC2: Record = Record { id: 2, len: 0x20, flags: [0xCC, 0xDD] };
+
+
+// Makes a ring buffer whose contents wrap around after `split` bytes.
+fn wrapped_deque(split: usize) -> VecDeque<u8> {
+    let mut deque = VecDeque::with_capacity(BYTES.len());
+    let cap = deque.capacity();
+
+    deque.extend(std::iter::repeat_n(0xFF, cap - split));
+    while deque.pop_front().is_some() {}
+    deque.extend(BYTES);
+
+    assert_eq!(deque.as_slices().0.len(), split);
+    deque
+}
+
+
+#[test]
+fn encast_every_split() {
+    for split in 0 ..= BYTES.len() {
+        let src = (&BYTES[.. split], &BYTES[split ..]);
+
+        assert_eq!(src.encastf::<Record>(LE), Some(REC1));
+        assert_eq!(src.encast::<[u8; 3]>(), Some([0x01, 0x00, 0x00]));
+
+        let mut recs = [REC2; 2];
+        recs[0].id = 0xEEEE;
+        assert_eq!(src.encastsf(&mut recs, LE), Some(16));
+        assert_eq!(recs, [REC1, REC2]);
+
+        let vec = src.encastvf::<u32>(4, BE).unwrap();
+        assert_eq!(vec, [0x01000000, 0x1000AABB, 0x02000000, 0x2000CCDD]);
+        assert_eq!(src.encastv::<u16>(8).unwrap()[2],
+                   u16::from_ne_bytes([0x10, 0x00]));
+    }
+}
+
+#[test]
+fn encast_too_short() {
+    let src: (&[u8], &[u8]) = (&BYTES[.. 3], &BYTES[3 .. 6]);
+
+    assert_eq!(src.encastf::<Record>(LE), None);
+    assert_eq!(src.encastvf::<u16>(4, LE), None);
+
+    // The slice is unchanged.
+    let mut slice = [0xEEEE_u16; 4];
+    assert_eq!(src.encasts(&mut slice), None);
+    assert_eq!(slice, [0xEEEE; 4]);
+
+    assert_eq!(src.encastsf::<u16>(&mut slice[.. 3], BE), Some(6));
+    assert_eq!(slice, [0x0100, 0x0000, 0x1000, 0xEEEE]);
+}
+
+#[test]
+fn vec_deque() {
+    for split in [1, 5, 8, 15, 16] {
+        let deque = wrapped_deque(split);
+
+        assert_eq!(deque.encastf::<Record>(LE), Some(REC1));
+        assert_eq!(deque.encastvf::<Record>(2, LE).unwrap(), [REC1, REC2]);
+        assert_eq!(deque.encastvf::<Record>(3, LE), None);
+
+        let mut slice = [0_u64; 2];
+        assert_eq!(deque.encastsf(&mut slice, BE), Some(16));
+        assert_eq!(slice, [0x01000000_1000AABB, 0x02000000_2000CCDD]);
+        assert_eq!(deque.encasts(&mut slice[.. 1]), Some(8));
+        assert_eq!(deque.encast::<u8>(), Some(0x01));
+        assert_eq!(deque.encastv::<u8>(2).unwrap(), [0x01, 0x00]);
+
+        // Nothing is consumed.
+        assert_eq!(deque.len(), 16);
+    }
+}
+
+#[test]
+fn decast_every_split() {
+    for split in 0 ..= BYTES.len() {
+        let mut buf = [0_u8; 16];
+        let (first, second) = buf.split_at_mut(split);
+        assert_eq!((first, second).decastf(&REC1, LE), Some(8));
+        assert_eq!(buf[.. 8], BYTES[.. 8]);
+
+        let mut buf = [0_u8; 16];
+        let (first, second) = buf.split_at_mut(split);
+        assert_eq!((first, second).decastsf(&[REC1, REC2], LE), Some(16));
+        assert_eq!(buf, BYTES);
+
+        let mut buf = [0_u8; 16];
+        let (first, second) = buf.split_at_mut(split);
+        assert_eq!((first, second).decastsf(&[0x01000000_u32, 0x1000AABB,
+                                              0x02000000, 0x2000CCDD], BE),
+                   Some(16));
+        assert_eq!(buf, BYTES);
+
+        let mut buf = [0_u8; 16];
+        let (first, second) = buf.split_at_mut(split);
+        assert_eq!((first, second).decasts(&BYTES), Some(16));
+        assert_eq!(buf, BYTES);
+
+        let mut buf = [0_u8; 16];
+        let (first, second) = buf.split_at_mut(split);
+        assert_eq!((first, second).decast(&0xEE_u8), Some(1));
+        assert_eq!(buf[0], 0xEE);
+    }
+}
+
+#[test]
+fn decast_large() {
+    let values: Vec<u32> = (0 .. 1000).collect();
+
+    let mut first = vec![0_u8; 1001];
+    let mut second = vec![0_u8; 2999];
+    let size = (&mut first[..], &mut second[..]).decastsf(&values, BE);
+    assert_eq!(size, Some(4000));
+
+    let src = (&first[..], &second[..]);
+    assert_eq!(src.encastvf::<u32>(1000, BE).unwrap(), values);
+}
+
+#[test]
+fn decast_vec_deque() {
+    let mut deque = wrapped_deque(5);
+
+    assert_eq!(deque.as_mut_slices().decastf(&0x12345678_u64, BE), Some(8));
+    assert_eq!(deque.encastf::<u64>(BE), Some(0x12345678));
+}
+
+#[test]
+fn decast_too_short() {
+    let mut first = [0_u8; 3];
+    let mut second = [0_u8; 3];
+
+    let mut dest = (&mut first[..], &mut second[..]);
+    assert_eq!(dest.decastf(&REC1, LE), None);
+    assert_eq!(dest.decastsf(&[1_u16; 4], BE), None);
+    assert_eq!(dest.decastsf(&[[1_u8; 3]; 3], BE), None);
+
+    // The destinations are unchanged.
+    assert_eq!(first, [0; 3]);
+    assert_eq!(second, [0; 3]);
+}
@@ -0,0 +1,172 @@
+use std::io::{Cursor, ErrorKind};
+
+use castflip::varint::{
+    DecodeVarInt, DecodeVarIntIO, EncodeVarInt, EncodeVarIntIO, VarIntError,
+};
+
+
+// (value, bytes) pairs of the examples in RFC 9000 (Section A.1).
+const QUIC: [(u64, &[u8]); 4] = [
+    (37, &[0x25]),
+    (15293, &[0x7b, 0xbd]),
+    (494878333, &[0x9d, 0x7f, 0x3e, 0x7d]),
+    (151288809941952652,
+     &[0xc2, 0x19, 0x7c, 0x5e, 0xff, 0x14, 0xe8, 0x8c]),
+];
+
+
+#[test]
+fn uleb128() {
+    let bytes: [u8; 3] = [0xe5, 0x8e, 0x26];
+    assert_eq!(bytes.decode_uleb128::<u32>(), Ok((624485, 3)));
+    assert_eq!(bytes.decode_uleb128::<i32>(), Ok((624485, 3)));
+    assert_eq!(bytes.decode_uleb128::<u16>(), Err(VarIntError::Overflow));
+    assert_eq!(bytes[.. 2].decode_uleb128::<u32>(),
+               Err(VarIntError::Truncated));
+
+    let mut buf = [0_u8; 19];
+    assert_eq!(buf.encode_uleb128(624485_u32), Ok(3));
+    assert_eq!(buf[.. 3], bytes);
+    assert_eq!(buf.encode_uleb128(0_u8), Ok(1));
+    assert_eq!(buf[0], 0x00);
+    assert_eq!(buf.encode_uleb128(-1_i8), Err(VarIntError::Overflow));
+
+    // The overflow is detected for each target width.
+    assert_eq!([0xff, 0x01].decode_uleb128::<u8>(), Ok((255, 2)));
+    assert_eq!([0x80, 0x02].decode_uleb128::<u8>(),
+               Err(VarIntError::Overflow));
+    assert_eq!([0xff, 0x00].decode_uleb128::<i8>(), Ok((127, 2)));
+    assert_eq!([0x80, 0x01].decode_uleb128::<i8>(),
+               Err(VarIntError::Overflow));
+
+    // Padded encodings are accepted.
+    assert_eq!([0x81, 0x80, 0x80, 0x00].decode_uleb128::<u8>(), Ok((1, 4)));
+
+    // The longest encoding is 19 bytes.
+    let size = buf.encode_uleb128(u128::MAX).unwrap();
+    assert_eq!(size, 19);
+    assert_eq!(buf[18], 0x03);
+    assert_eq!(buf.decode_uleb128::<u128>(), Ok((u128::MAX, 19)));
+    assert_eq!(buf.decode_uleb128::<u64>(), Err(VarIntError::Overflow));
+    buf[18] = 0x04;
+    assert_eq!(buf.decode_uleb128::<u128>(), Err(VarIntError::Overflow));
+    assert_eq!([0x80; 20].decode_uleb128::<u128>(),
+               Err(VarIntError::Overflow));
+}
+
+#[test]
+fn sleb128() {
+    let bytes: [u8; 3] = [0xc0, 0xbb, 0x78];
+    assert_eq!(bytes.decode_sleb128::<i32>(), Ok((-123456, 3)));
+    assert_eq!(bytes.decode_sleb128::<i64>(), Ok((-123456, 3)));
+    assert_eq!(bytes.decode_sleb128::<i16>(), Err(VarIntError::Overflow));
+    assert_eq!(bytes.decode_sleb128::<u32>(), Err(VarIntError::Overflow));
+
+    let mut buf = [0_u8; 19];
+    assert_eq!(buf.encode_sleb128(-123456_i32), Ok(3));
+    assert_eq!(buf[.. 3], bytes);
+    assert_eq!(buf.encode_sleb128(63_u8), Ok(1));
+    assert_eq!(buf[0], 0x3f);
+    assert_eq!(buf.encode_sleb128(64_u8), Ok(2));
+    assert_eq!(buf[.. 2], [0xc0, 0x00]);
+    assert_eq!(buf.encode_sleb128(-64_i8), Ok(1));
+    assert_eq!(buf[0], 0x40);
+
+    // The overflow is detected for each target width.
+    assert_eq!([0x80, 0x7f].decode_sleb128::<i8>(), Ok((-128, 2)));
+    assert_eq!([0xff, 0x7e].decode_sleb128::<i8>(),
+               Err(VarIntError::Overflow));
+    assert_eq!([0xff, 0x00].decode_sleb128::<i8>(), Ok((127, 2)));
+    assert_eq!([0x80, 0x01].decode_sleb128::<i8>(),
+               Err(VarIntError::Overflow));
+    assert_eq!([0x80, 0x01].decode_sleb128::<u8>(), Ok((128, 2)));
+
+    // The extreme values round-trip.
+    for value in [i128::MIN, i128::MAX, -1, 0] {
+        let size = buf.encode_sleb128(value).unwrap();
+        assert_eq!(buf.decode_sleb128::<i128>(), Ok((value, size)));
+    }
+    let size = buf.encode_sleb128(u128::MAX).unwrap();
+    assert_eq!(size, 19);
+    assert_eq!(buf.decode_sleb128::<u128>(), Ok((u128::MAX, 19)));
+    assert_eq!(buf.decode_sleb128::<i128>(), Err(VarIntError::Overflow));
+}
+
+#[test]
+fn zigzag() {
+    // (value, encoded) pairs of the examples of Protocol Buffers.
+    let pairs: [(i64, u64); 6] = [
+        (0, 0), (-1, 1), (1, 2), (-2, 3),
+        (0x7fff_ffff, 0xffff_fffe), (-0x8000_0000, 0xffff_ffff),
+    ];
+
+    let mut buf = [0_u8; 19];
+    for (value, encoded) in pairs {
+        let size = buf.encode_zigzag(value).unwrap();
+        assert_eq!(buf.decode_uleb128::<u64>(), Ok((encoded, size)));
+        assert_eq!(buf.decode_zigzag::<i64>(), Ok((value, size)));
+        assert_eq!(buf.decode_zigzag::<i32>(), Ok((value as i32, size)));
+    }
+
+    assert_eq!([0x03].decode_zigzag::<u8>(), Err(VarIntError::Overflow));
+    assert_eq!([0xff, 0x01].decode_zigzag::<i8>(), Ok((-128, 2)));
+    assert_eq!([0x80, 0x02].decode_zigzag::<i8>(),
+               Err(VarIntError::Overflow));
+
+    for value in [i128::MIN, i128::MAX] {
+        let size = buf.encode_zigzag(value).unwrap();
+        assert_eq!(buf.decode_zigzag::<i128>(), Ok((value, size)));
+    }
+    assert_eq!(buf.encode_zigzag(u128::MAX), Err(VarIntError::Overflow));
+}
+
+#[test]
+fn quic() {
+    let mut buf = [0_u8; 8];
+    for (value, bytes) in QUIC {
+        assert_eq!(bytes.decode_quic::<u64>(), Ok((value, bytes.len())));
+        assert_eq!(buf.encode_quic(value), Ok(bytes.len()));
+        assert_eq!(&buf[.. bytes.len()], bytes);
+    }
+
+    // A value may be encoded in a longer form.
+    assert_eq!([0x40, 0x25].decode_quic::<u8>(), Ok((37, 2)));
+
+    assert_eq!([0x7b, 0xbd].decode_quic::<u8>(), Err(VarIntError::Overflow));
+    assert_eq!([0x7b].decode_quic::<u16>(), Err(VarIntError::Truncated));
+    assert_eq!([0xff; 8].decode_quic::<u64>(), Ok(((1 << 62) - 1, 8)));
+    assert_eq!(buf.encode_quic(1_u64 << 62), Err(VarIntError::Overflow));
+    assert_eq!(buf.encode_quic(-1_i64), Err(VarIntError::Overflow));
+    assert_eq!(buf[.. 1].encode_quic(64_u32), Err(VarIntError::Truncated));
+}
+
+#[test]
+fn io() {
+    let mut output = Vec::new();
+    assert_eq!(output.encode_uleb128(624485_u32).unwrap(), 3);
+    assert_eq!(output.encode_sleb128(-123456_i32).unwrap(), 3);
+    assert_eq!(output.encode_zigzag(-2_i16).unwrap(), 1);
+    for (value, bytes) in QUIC {
+        assert_eq!(output.encode_quic(value).unwrap(), bytes.len());
+    }
+
+    let error = output.encode_quic(-1_i8).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert_eq!(output.len(), 3 + 3 + 1 + 15);
+
+    let mut input = Cursor::new(output);
+    assert_eq!(input.decode_uleb128::<u32>().unwrap(), 624485);
+    assert_eq!(input.decode_sleb128::<i32>().unwrap(), -123456);
+    assert_eq!(input.decode_zigzag::<i16>().unwrap(), -2);
+    for (value, _) in QUIC {
+        assert_eq!(input.decode_quic::<u64>().unwrap(), value);
+    }
+    let error = input.decode_uleb128::<u32>().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+
+    let mut input = Cursor::new([0x80, 0x02]);
+    let error = input.decode_uleb128::<u8>().unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert_eq!(error.into_inner().unwrap().downcast_ref(),
+               Some(&VarIntError::Overflow));
+}
@@ -1,3 +1,8 @@
+#![allow(
+    clippy::len_zero,
+    clippy::needless_return,
+)]
+
 //
 // To allow tests to print to the console:
 //
@@ -1,3 +1,5 @@
+#![allow(clippy::clone_on_copy)]
+
 use core::mem::size_of;
 use std::io::Cursor;
 
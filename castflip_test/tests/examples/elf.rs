@@ -84,7 +84,7 @@ impl ElfInfo {
     }
 
     fn write(&self, bytes: &mut [u8]) -> Option<usize> {
-        Some(bytes.decastf(&self.hdr, self.endian)?)
+        bytes.decastf(&self.hdr, self.endian)
     }
 }
 